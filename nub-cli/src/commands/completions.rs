@@ -0,0 +1,55 @@
+use std::{collections::BTreeMap, io};
+
+use clap::{Args, Command, CommandFactory, builder::PossibleValue};
+use clap_complete::Shell;
+
+use crate::{
+    commands::{Cli, license},
+    errors::CliError,
+    template::TemplateManager,
+};
+
+/// Arguments for the completions command.
+#[derive(Args)]
+pub(crate) struct CompletionsCommand {
+    #[arg(help = "The shell to generate the completion script for")]
+    shell: Shell,
+}
+
+impl CompletionsCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let mut command = completion_command();
+        clap_complete::generate(self.shell, &mut command, "nub", &mut io::stdout());
+        Ok(())
+    }
+}
+
+/// The command line with the embedded templates and the licenses as possible values.
+///
+/// Nub validates these arguments itself, so they are only listed for the shells to
+/// complete and the parser used to run commands is left as is.
+fn completion_command() -> Command {
+    let mut templates: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for template in TemplateManager::new().templates() {
+        templates
+            .entry(template.name)
+            .or_default()
+            .push(template.language.to_string());
+    }
+    let templates: Vec<PossibleValue> = templates
+        .into_iter()
+        .map(|(name, languages)| PossibleValue::new(name).help(languages.join(", ")))
+        .collect();
+
+    Cli::command()
+        .mut_subcommand("project", |project| {
+            project.mut_subcommand("create", |create| {
+                create.mut_arg("name", |arg| arg.value_parser(templates))
+            })
+        })
+        .mut_subcommand("license", |license| {
+            license.mut_subcommand("use", |command| {
+                command.mut_arg("kind", |arg| arg.value_parser(license::completion_values()))
+            })
+        })
+}
@@ -0,0 +1,251 @@
+use std::env;
+
+use clap::{Args, Subcommand};
+use toml::{Table, Value};
+
+use crate::{
+    config::{self, Config, LOCAL_CONFIG_FILE},
+    errors::CliError,
+};
+
+#[derive(Args)]
+pub(crate) struct ConfigCommand {
+    #[command(subcommand)]
+    command: SubCommands,
+}
+
+impl ConfigCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        match &self.command {
+            SubCommands::Get(cmd) => cmd.run(),
+            SubCommands::Set(cmd) => cmd.run(),
+            SubCommands::List(cmd) => cmd.run(),
+        }
+    }
+}
+
+/// Available subcommands for configuration operations.
+#[derive(Subcommand)]
+enum SubCommands {
+    /// Print a configuration value.
+    Get(GetSubCommand),
+
+    /// Set a configuration value.
+    Set(SetSubCommand),
+
+    /// List all configuration values.
+    List(ListSubCommand),
+}
+
+/// Arguments for the get subcommand.
+#[derive(Args)]
+struct GetSubCommand {
+    #[arg(help = "The configuration key (e.g. user.name, defaults.license)")]
+    key: String,
+}
+
+impl GetSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let table = config::load_table()?;
+        match config::get(&table, &self.key) {
+            Some(Value::String(value)) => println!("{value}"),
+            Some(value) => println!("{value}"),
+            None => match config::git_fallback(&self.key) {
+                Some(value) => println!("{value}"),
+                // Printed to stderr so scripts reading the value get an empty string
+                None if config::is_known_key(&self.key) => eprintln!("{} is not set", self.key),
+                None => return Err(CliError::UnknownConfigKey(self.key.clone())),
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Arguments for the set subcommand.
+#[derive(Args)]
+struct SetSubCommand {
+    #[arg(help = "The configuration key (e.g. user.name, defaults.license)")]
+    key: String,
+
+    #[arg(help = "The value to set")]
+    value: String,
+
+    #[arg(
+        long,
+        help = "Write to the repository .nub.toml instead of the global config"
+    )]
+    local: bool,
+}
+
+impl SetSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let path = if self.local {
+            match config::local_path() {
+                Some(path) => path,
+                None => env::current_dir()?.join(LOCAL_CONFIG_FILE),
+            }
+        } else {
+            config::global_path().ok_or(CliError::ConfigDirNotFound)?
+        };
+
+        let table = self.apply(&config::read_table(&path)?)?;
+        config::write_table(&path, &table)?;
+
+        println!("> {} = {} ({})", self.key, self.value, path.display());
+        Ok(())
+    }
+
+    /// Returns the table with the value set, as a string unless the key expects another
+    /// type, e.g. `true` or `["fmt", "lint"]`.
+    fn apply(&self, table: &Table) -> Result<Table, CliError> {
+        match self.with_value(table, Value::String(self.value.clone())) {
+            Ok(table) => Ok(table),
+            Err(err) => match parse_literal(&self.value) {
+                Some(value) => self.with_value(table, value),
+                None => Err(err),
+            },
+        }
+    }
+
+    /// Sets the value in a copy of the table, rejecting unknown keys and invalid values.
+    fn with_value(&self, table: &Table, value: Value) -> Result<Table, CliError> {
+        let mut table = table.clone();
+        config::set(&mut table, &self.key, value)?;
+        Config::from_table(table.clone())?;
+        Ok(table)
+    }
+}
+
+/// Parses a TOML literal such as `true`, `3` or `["fmt", "lint"]`.
+fn parse_literal(value: &str) -> Option<Value> {
+    format!("value = {value}")
+        .parse::<Table>()
+        .ok()?
+        .remove("value")
+}
+
+/// Arguments for the list subcommand.
+#[derive(Args)]
+struct ListSubCommand {}
+
+impl ListSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let table = config::load_table()?;
+        let entries = config::flatten(&table);
+
+        for key in ["user.name", "user.email"] {
+            if config::get(&table, key).is_none()
+                && let Some(value) = config::git_fallback(key)
+            {
+                println!("{key} = {value:?} (from git)");
+            }
+        }
+        for (key, value) in entries {
+            println!("{key} = {value}");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(key: &str, value: &str) -> Result<Table, CliError> {
+        let command = SetSubCommand {
+            key: key.to_string(),
+            value: value.to_string(),
+            local: false,
+        };
+        command.apply(&Table::new())
+    }
+
+    #[test]
+    fn parses_literals() {
+        assert_eq!(parse_literal("true"), Some(Value::Boolean(true)));
+        assert_eq!(parse_literal("3"), Some(Value::Integer(3)));
+        assert_eq!(
+            parse_literal(r#"["bun", "install"]"#),
+            Some(Value::Array(vec![
+                Value::String("bun".to_string()),
+                Value::String("install".to_string()),
+            ]))
+        );
+        assert_eq!(parse_literal("bun install"), None);
+    }
+
+    #[test]
+    fn sets_values_of_the_type_the_key_expects() {
+        let table = set("user.name", "true").unwrap();
+        assert_eq!(
+            config::get(&table, "user.name"),
+            Some(&Value::String("true".to_string()))
+        );
+
+        let table = set("hooks.install", r#"["pnpm", "install"]"#).unwrap();
+        let config = Config::from_table(table).unwrap();
+        assert_eq!(
+            config.hooks.install,
+            Some(vec!["pnpm".to_string(), "install".to_string()])
+        );
+
+        let table = set("tasks.test", "cargo nextest run").unwrap();
+        assert!(Config::from_table(table).is_ok());
+    }
+
+    #[test]
+    fn recognizes_keys_whether_set_or_not() {
+        let cases = [
+            ("user.email", true),
+            ("defaults.package_manager", true),
+            ("review.model", true),
+            ("templates.go", true),
+            ("templates.cobol", false),
+            ("tasks.test", true),
+            ("tasks.", false),
+            ("user.nickname", false),
+            ("user", false),
+        ];
+        for (key, expected) in cases {
+            assert_eq!(config::is_known_key(key), expected, "{key}");
+        }
+    }
+
+    #[test]
+    fn every_known_key_can_be_set() {
+        let cases = [
+            ("user.name", "Ann"),
+            ("user.organization", "Acme"),
+            ("user.email", "ann@acme.dev"),
+            ("defaults.license", "MIT"),
+            ("defaults.language", "rust"),
+            ("defaults.go_module_prefix", "github.com/acme"),
+            ("defaults.package_manager", "pnpm"),
+            ("hooks.generate", "[]"),
+            ("hooks.install", "[]"),
+            ("hooks.format", "[]"),
+            ("review.base_url", "http://localhost:11434/v1"),
+            ("review.model", "llama3"),
+        ];
+        for (key, value) in cases {
+            assert!(config::is_known_key(key), "{key}");
+            assert!(set(key, value).is_ok(), "{key}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_values_and_unknown_keys() {
+        assert!(matches!(
+            set("hooks.install", "pnpm install"),
+            Err(CliError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            set("defaults.language", "cobol"),
+            Err(CliError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            set("user.nickname", "ann"),
+            Err(CliError::InvalidConfig(_))
+        ));
+    }
+}
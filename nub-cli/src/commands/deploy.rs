@@ -0,0 +1,123 @@
+use std::env;
+
+use clap::{Args, Subcommand};
+
+use crate::{
+    deploy::{self, DeployOptions, DeployTarget, DeploymentRecord, ProcessExecutor},
+    detect,
+    errors::CliError,
+};
+
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true)]
+pub(crate) struct DeployCommand {
+    #[command(subcommand)]
+    command: Option<SubCommands>,
+
+    #[command(flatten)]
+    push: PushArgs,
+}
+
+impl DeployCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        match &self.command {
+            Some(SubCommands::Init(cmd)) => cmd.run(),
+            None => self.push.run(),
+        }
+    }
+}
+
+/// Arguments for deploying the project with the platform CLI.
+#[derive(Args)]
+struct PushArgs {
+    #[arg(
+        short,
+        long,
+        help = "The platform to deploy to, detected from existing configuration files by default"
+    )]
+    target: Option<DeployTarget>,
+
+    #[arg(
+        long,
+        help = "The image to push for Docker deployments, including its registry (e.g. ghcr.io/acme/app)"
+    )]
+    image: Option<String>,
+
+    #[arg(long, help = "Promote the deployment to production on Vercel")]
+    prod: bool,
+}
+
+impl PushArgs {
+    pub fn run(&self) -> Result<(), CliError> {
+        let project = detect::detect_nearest(&env::current_dir()?)?;
+        let target = self
+            .target
+            .or_else(|| DeployTarget::detect(&project.root))
+            .ok_or(CliError::NoDeployTarget)?;
+        let options = DeployOptions {
+            image: self.image.clone(),
+            production: self.prod,
+        };
+
+        println!("Deploying {} to {target}\n", project.name);
+        let result = target
+            .deployer()
+            .deploy(&project, &options, &ProcessExecutor);
+
+        let url = result
+            .as_ref()
+            .ok()
+            .and_then(|deployment| deployment.url.clone());
+        // The deployment outcome matters more than the history, which only gets a warning
+        if let Err(err) =
+            DeploymentRecord::new(target.to_string(), url, result.is_ok()).save(&project.root)
+        {
+            eprintln!("Warning: failed to record the deployment: {err}");
+        }
+
+        let deployment = result?;
+        match deployment.url {
+            Some(url) => println!("\nDeployed to {url}"),
+            None => println!("\nDeployed to {}", deployment.target),
+        }
+        Ok(())
+    }
+}
+
+/// Available subcommands for deploy operations.
+#[derive(Subcommand)]
+enum SubCommands {
+    /// Generate the configuration files needed to deploy the project.
+    Init(InitSubCommand),
+}
+
+/// Arguments for the init subcommand.
+#[derive(Args)]
+struct InitSubCommand {
+    #[arg(short, long, help = "The platform to deploy to")]
+    target: DeployTarget,
+
+    #[arg(long, help = "Overwrite existing configuration files")]
+    force: bool,
+}
+
+impl InitSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let project = detect::detect_nearest(&env::current_dir()?)?;
+        println!(
+            "> Detected {} project {}{}\n",
+            project.language,
+            project.name,
+            project
+                .template
+                .as_ref()
+                .map(|template| format!(" (template {template})"))
+                .unwrap_or_default()
+        );
+
+        let files = self.target.provider().init(&project)?;
+        deploy::write_files(&project.root, &files, self.force)?;
+        println!("\nReady to deploy to {}!", self.target);
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use std::env;
+
+use clap::{Args, Subcommand};
+
+use crate::{deploy, detect, docker, errors::CliError};
+
+#[derive(Args)]
+pub(crate) struct DockerCommand {
+    #[command(subcommand)]
+    command: SubCommands,
+}
+
+impl DockerCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        match &self.command {
+            SubCommands::Init(cmd) => cmd.run(),
+        }
+    }
+}
+
+/// Available subcommands for Docker operations.
+#[derive(Subcommand)]
+enum SubCommands {
+    /// Generate a production Dockerfile and .dockerignore for the project.
+    Init(InitSubCommand),
+}
+
+/// Arguments for the init subcommand.
+#[derive(Args)]
+struct InitSubCommand {
+    #[arg(long, help = "Overwrite existing Dockerfile and .dockerignore")]
+    force: bool,
+}
+
+impl InitSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let project = detect::detect_nearest(&env::current_dir()?)?;
+        println!("> Detected {} project {}\n", project.language, project.name);
+
+        let files = docker::docker_files(&project)?;
+        deploy::write_files(&project.root, &files, self.force)?;
+        println!(
+            "\nBuild the image with `docker build -t {} .`",
+            project.name
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use std::env;
+
+use clap::{Args, Subcommand};
+
+use crate::{
+    docs::{self, concepts},
+    errors::CliError,
+    template::Language,
+};
+
+/// Terminal width assumed when `COLUMNS` is not set.
+const DEFAULT_WIDTH: usize = 120;
+
+/// Narrowest column before snippets are stacked instead of shown side by side.
+const MIN_COLUMN_WIDTH: usize = 32;
+
+const COLUMN_GAP: &str = " | ";
+
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub(crate) struct DocsCommand {
+    #[command(subcommand)]
+    command: Option<SubCommands>,
+
+    #[command(flatten)]
+    lookup: LookupArgs,
+}
+
+impl DocsCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        match &self.command {
+            Some(SubCommands::Compare(cmd)) => cmd.run(),
+            None => self.lookup.run(),
+        }
+    }
+}
+
+/// Arguments for looking up an item in the local documentation.
+#[derive(Args)]
+struct LookupArgs {
+    #[arg(
+        required = true,
+        help = "The item to look up (e.g. HashMap, http.Get, serve)"
+    )]
+    query: Option<String>,
+
+    #[arg(short, long, help = "Only search the documentation of this language")]
+    lang: Option<Language>,
+
+    #[arg(long, default_value_t = 10, help = "The maximum number of results")]
+    limit: usize,
+}
+
+impl LookupArgs {
+    pub fn run(&self) -> Result<(), CliError> {
+        let query = self.query.as_deref().ok_or(CliError::InvalidInput)?;
+        let sources = docs::sources(self.lang);
+        let entries = docs::search(&sources, query, self.limit);
+
+        if entries.is_empty() {
+            println!("No documentation found for {query}\n");
+            println!("> Rust: run `rustup component add rust-docs` or `cargo doc` in your project");
+            println!("> Go: install Go and the modules you want to look up");
+            println!("> TypeScript: install your project dependencies");
+            return Ok(());
+        }
+
+        for entry in entries {
+            let kind = entry
+                .kind
+                .map(|kind| format!(" ({kind})"))
+                .unwrap_or_default();
+            println!("[{}] {}{kind}", entry.language, entry.path);
+            if let Some(signature) = entry.signature {
+                println!("  {signature}");
+            }
+            if let Some(summary) = entry.summary {
+                println!("  {summary}");
+            }
+            println!();
+        }
+        Ok(())
+    }
+}
+
+/// Available subcommands for docs operations.
+#[derive(Subcommand)]
+enum SubCommands {
+    /// Compare how a concept is written in each language.
+    Compare(CompareSubCommand),
+}
+
+#[derive(Args)]
+struct CompareSubCommand {
+    #[arg(
+        help = "The concept to compare (e.g. error-handling, async, json), lists them if omitted"
+    )]
+    concept: Option<String>,
+
+    #[arg(
+        short,
+        long = "lang",
+        help = "Only show these languages, can be repeated"
+    )]
+    langs: Vec<Language>,
+
+    #[arg(
+        long,
+        help = "Print snippets one after another instead of side by side"
+    )]
+    stacked: bool,
+}
+
+impl CompareSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let Some(query) = &self.concept else {
+            println!("Available concepts:");
+            for concept in concepts::concepts()? {
+                println!("> {} ({})", concept.id, concept.name);
+            }
+            return Ok(());
+        };
+
+        let concept = concepts::find(query)?;
+        let snippets: Vec<(Language, &str)> = concept
+            .snippets
+            .iter()
+            .filter(|(language, _)| self.langs.is_empty() || self.langs.contains(language))
+            .map(|(language, snippet)| (*language, snippet.trim_end()))
+            .collect();
+
+        println!("{}\n", concept.name);
+        if snippets.is_empty() {
+            println!("No snippets available for the selected languages");
+            return Ok(());
+        }
+
+        let width = env::var("COLUMNS")
+            .ok()
+            .and_then(|columns| columns.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_WIDTH);
+        let gaps = COLUMN_GAP.len() * (snippets.len() - 1);
+        let column_width = width.saturating_sub(gaps) / snippets.len();
+
+        if self.stacked || column_width < MIN_COLUMN_WIDTH {
+            for (language, snippet) in &snippets {
+                println!("[{language}]");
+                println!("{}\n", expand_tabs(snippet));
+            }
+        } else {
+            print_columns(&snippets, column_width);
+        }
+        Ok(())
+    }
+}
+
+/// Prints each snippet in its own column, wrapping lines longer than the column.
+fn print_columns(snippets: &[(Language, &str)], width: usize) {
+    let columns: Vec<Vec<String>> = snippets
+        .iter()
+        .map(|(language, snippet)| {
+            let mut lines = vec![format!("[{language}]"), "-".repeat(width)];
+            lines.extend(
+                expand_tabs(snippet)
+                    .lines()
+                    .flat_map(|line| wrap(line, width)),
+            );
+            lines
+        })
+        .collect();
+
+    let height = columns.iter().map(Vec::len).max().unwrap_or_default();
+    for row in 0..height {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                let cell = column.get(row).map(String::as_str).unwrap_or_default();
+                format!("{cell:<width$}")
+            })
+            .collect();
+        println!("{}", cells.join(COLUMN_GAP).trim_end());
+    }
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', "    ")
+}
+
+/// Splits a line into chunks of at most `width` characters.
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.is_empty() {
+        return vec![String::new()];
+    }
+    chars
+        .chunks(width)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+use clap::Args;
+
+use crate::{
+    errors::CliError,
+    template::{Language, TemplateManager},
+    toolchain::{self, Version},
+};
+
+/// Arguments for the doctor command.
+#[derive(Args)]
+pub(crate) struct DoctorCommand {
+    #[arg(short, long, help = "Only check the toolchain of this language")]
+    language: Option<Language>,
+
+    #[arg(short, long, help = "Check against the requirements of this template")]
+    template: Option<String>,
+}
+
+impl DoctorCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let manager = TemplateManager::new();
+
+        let mut languages: Vec<Language> = match self.language {
+            Some(language) => vec![language],
+            None => manager.languages(),
+        };
+        if let Some(name) = &self.template {
+            // A template name can be shared by several languages, e.g. `blank`
+            languages.retain(|language| {
+                manager
+                    .templates_for_language(*language)
+                    .iter()
+                    .any(|template| template.name == *name)
+            });
+            if languages.is_empty() {
+                return Err(CliError::UnknownTemplate(name.clone()));
+            }
+        }
+        if languages.is_empty() {
+            return Err(CliError::NoLanguagesAvailable);
+        }
+
+        let mut healthy = true;
+        for language in languages {
+            let requirements = self.requirements(&manager, language)?;
+            let reports = toolchain::check(language, &requirements);
+
+            println!("{language}");
+            for report in &reports {
+                report.print();
+            }
+            println!();
+
+            healthy &= reports
+                .iter()
+                .all(|report| !report.is_required() || report.is_satisfied());
+        }
+
+        if healthy {
+            println!("Everything looks good!");
+        } else if self.language.is_some() || self.template.is_some() {
+            return Err(CliError::ToolchainIncomplete);
+        } else {
+            // Few machines have every toolchain, only the ones asked for are required
+            println!("Some required tools are missing or outdated, see the hints above");
+        }
+        Ok(())
+    }
+
+    /// Collects minimum versions declared by the selected template, or the highest
+    /// minimum declared across every template of the language.
+    fn requirements(
+        &self,
+        manager: &TemplateManager,
+        language: Language,
+    ) -> Result<BTreeMap<String, Version>, CliError> {
+        let mut requirements: BTreeMap<String, Version> = BTreeMap::new();
+
+        for template in manager.templates_for_language(language) {
+            if self
+                .template
+                .as_ref()
+                .is_some_and(|name| *name != template.name)
+            {
+                continue;
+            }
+
+            let manifest = manager.manifest(&template.path())?;
+            for (tool, version) in manifest.requirements {
+                match requirements.get(&tool) {
+                    Some(current) if *current >= version => {}
+                    _ => {
+                        requirements.insert(tool, version);
+                    }
+                }
+            }
+        }
+        Ok(requirements)
+    }
+}
@@ -0,0 +1,79 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use clap::Args;
+
+use crate::{
+    detect::{self, Project},
+    errors::CliError,
+};
+
+/// Arguments for the info command.
+#[derive(Args)]
+pub(crate) struct InfoCommand {
+    #[arg(help = "The directory to inspect, defaults to the current directory")]
+    path: Option<PathBuf>,
+}
+
+impl InfoCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let current_dir = env::current_dir()?;
+        let dir = self
+            .path
+            .as_ref()
+            .map(|path| current_dir.join(path))
+            .unwrap_or(current_dir);
+
+        // Falls back to the enclosing project when run from one of its subdirectories
+        let mut projects = detect::find_projects(&dir);
+        if projects.is_empty() {
+            projects.push(detect::detect_nearest(&dir)?);
+        }
+
+        for (index, project) in projects.iter().enumerate() {
+            if index > 0 {
+                println!();
+            }
+            print_project(&dir, project);
+        }
+        Ok(())
+    }
+}
+
+fn print_project(dir: &Path, project: &Project) {
+    let path = match project.root.strip_prefix(dir) {
+        Ok(path) if path.as_os_str().is_empty() => ".".to_string(),
+        Ok(path) => path.display().to_string(),
+        Err(_) => project.root.display().to_string(),
+    };
+
+    println!("> {} ({path})", project.name);
+    println!("  {:<17}{}", "Language", project.language);
+    println!("  {:<17}{}", "Package manager", project.package_manager);
+    if let Some(workspace) = &project.workspace {
+        let members: Vec<String> = workspace
+            .members
+            .iter()
+            .map(|member| member.display().to_string())
+            .collect();
+        let members = if members.is_empty() {
+            "no members".to_string()
+        } else {
+            members.join(", ")
+        };
+        println!(
+            "  {:<17}{members} (from {})",
+            "Workspace", workspace.manifest
+        );
+    }
+    if let Some(license) = &project.license {
+        println!("  {:<17}{license}", "License");
+    }
+    println!(
+        "  {:<17}{}",
+        "Template",
+        project.template.as_deref().unwrap_or("none detected")
+    );
+}
@@ -1,7 +1,9 @@
+use std::env;
+
 use chrono::Datelike;
-use clap::{Args, Subcommand, ValueEnum};
+use clap::{Args, Subcommand, ValueEnum, builder::PossibleValue};
 
-use crate::errors::CliError;
+use crate::{config::Config, detect, errors::CliError};
 
 #[derive(Args)]
 pub(crate) struct LicenseCommand {
@@ -132,10 +134,16 @@ impl ListSubCommand {
 /// Arguments for the create subcommand.
 #[derive(Args)]
 struct UseSubCommand {
-    #[arg(help = "The license type to use (e.g. mit, apache)")]
-    kind: LicenseKind,
+    #[arg(
+        ignore_case = true,
+        help = "The license type to use (e.g. mit, apache or Apache-2.0), defaults to the one declared by the project or the configured license"
+    )]
+    kind: Option<LicenseKind>,
 
-    #[arg(long, help = "The name or organization for the license")]
+    #[arg(
+        long,
+        help = "The name or organization for the license (defaults to the configured author)"
+    )]
     name: Option<String>,
 
     #[arg(
@@ -148,14 +156,28 @@ struct UseSubCommand {
 
 impl UseSubCommand {
     pub fn run(&self) -> Result<(), CliError> {
+        let config = Config::load()?;
+        // The manifest of the current project says more than the user-wide default
+        let declared = detect::detect_nearest(&env::current_dir()?)
+            .ok()
+            .and_then(|project| project.license)
+            .and_then(|license| LicenseKind::from_spdx(&license));
+        let kind = match (self.kind.or(declared), &config.defaults.license) {
+            (Some(kind), _) => kind,
+            (None, Some(license)) => LicenseKind::from_str(license, true).map_err(|_| {
+                CliError::InvalidConfig(format!("defaults.license: unknown license {license}"))
+            })?,
+            (None, None) => return Err(CliError::MissingLicense),
+        };
         let name = self
             .name
             .clone()
+            .or_else(|| config.copyright_holder())
             .unwrap_or("<YOUR NAME/ORGANIZATION>".to_string());
         if name.trim().is_empty() {
             return Err(CliError::InvalidInput);
         }
-        let license = self.kind.create(name, self.year);
+        let license = kind.create(name, self.year);
         println!("{license}");
         Ok(())
     }
@@ -164,15 +186,65 @@ impl UseSubCommand {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 enum LicenseKind {
     Mit,
+    #[value(alias = "apache-2.0")]
     Apache,
+    #[value(alias = "gpl-3.0")]
     Gpl,
+    #[value(alias = "lgpl-3.0")]
     Lgpl,
+    #[value(alias = "mpl-2.0")]
     Mpl,
+    #[value(alias = "bsd-3-clause")]
     Bsd,
     Unlicense,
 }
 
+/// The license names completed by shells, along with the SPDX ids they also accept.
+pub(crate) fn completion_values() -> Vec<PossibleValue> {
+    let mut values = Vec::new();
+    for kind in LicenseKind::value_variants() {
+        let Some(value) = kind.to_possible_value() else {
+            continue;
+        };
+        let spdx = kind.spdx();
+        let distinct = !value.get_name().eq_ignore_ascii_case(spdx);
+        values.push(value.help(spdx));
+        if distinct {
+            values.push(PossibleValue::new(spdx));
+        }
+    }
+    values
+}
+
 impl LicenseKind {
+    fn spdx(&self) -> &'static str {
+        match self {
+            LicenseKind::Mit => "MIT",
+            LicenseKind::Apache => "Apache-2.0",
+            LicenseKind::Gpl => "GPL-3.0",
+            LicenseKind::Lgpl => "LGPL-3.0",
+            LicenseKind::Mpl => "MPL-2.0",
+            LicenseKind::Bsd => "BSD-3-Clause",
+            LicenseKind::Unlicense => "Unlicense",
+        }
+    }
+
+    /// Reads an SPDX expression such as `MIT OR Apache-2.0`, keeping its first license.
+    fn from_spdx(expression: &str) -> Option<Self> {
+        let id = expression
+            .split(" OR ")
+            .next()?
+            .trim_matches(|c: char| c == '(' || c == ')' || c.is_whitespace());
+        let id = id
+            .strip_suffix("-only")
+            .or_else(|| id.strip_suffix("-or-later"))
+            .unwrap_or(id);
+        LicenseKind::value_variants()
+            .iter()
+            .find(|kind| kind.spdx().eq_ignore_ascii_case(id))
+            .copied()
+    }
+
     pub fn create(&self, name: String, year: i32) -> String {
         match self {
             LicenseKind::Mit => format!(
@@ -0,0 +1,30 @@
+use std::{fs, io, path::PathBuf};
+
+use clap::{Args, CommandFactory};
+
+use crate::{commands::Cli, errors::CliError};
+
+/// Arguments for the man command.
+#[derive(Args)]
+pub(crate) struct ManCommand {
+    #[arg(
+        long,
+        help = "Write a page per command to this directory instead of printing the nub page"
+    )]
+    out_dir: Option<PathBuf>,
+}
+
+impl ManCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let command = Cli::command();
+        match &self.out_dir {
+            Some(dir) => {
+                fs::create_dir_all(dir)?;
+                clap_mangen::generate_to(command, dir)?;
+                println!("> Wrote the man pages to {}", dir.display());
+            }
+            None => clap_mangen::Man::new(command).render(&mut io::stdout())?,
+        }
+        Ok(())
+    }
+}
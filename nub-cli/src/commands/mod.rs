@@ -1,28 +1,73 @@
 use clap::{Parser, Subcommand};
+use completions::CompletionsCommand;
+use config::ConfigCommand;
+use deploy::DeployCommand;
+use docker::DockerCommand;
+use docs::DocsCommand;
+use doctor::DoctorCommand;
+use info::InfoCommand;
 use license::LicenseCommand;
+use man::ManCommand;
 use project::ProjectCommand;
+use review::ReviewCommand;
+use run::RunCommand;
+use snippet::SnippetCommand;
+use tooling::ToolingCommand;
 
 use crate::{commands::template::TemplateCommand, emoji::HUG, errors::CliError};
 
+mod completions;
+mod config;
+mod deploy;
+mod docker;
+mod docs;
+mod doctor;
+mod info;
 mod license;
+mod man;
 mod project;
+mod review;
+mod run;
+mod snippet;
 mod template;
+mod tooling;
 
 #[derive(Parser)]
-#[command(version, about, long_about = None, arg_required_else_help(true))]
+#[command(name = "nub", version, about, long_about = None, arg_required_else_help(true))]
 pub struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
 impl Cli {
-    pub fn run() -> Result<(), CliError> {
-        let cli = Cli::parse();
-        println!("{HUG}\n");
-        match &cli.command {
+    /// Whether the output is meant for people, rather than a completion script or a man page.
+    pub fn is_interactive(&self) -> bool {
+        !matches!(
+            self.command,
+            Some(Commands::Completions(_)) | Some(Commands::Man(_))
+        )
+    }
+
+    pub fn run(&self) -> Result<(), CliError> {
+        if self.is_interactive() {
+            println!("{HUG}\n");
+        }
+        match &self.command {
+            Some(Commands::Completions(cmd)) => cmd.run(),
+            Some(Commands::Config(cmd)) => cmd.run(),
+            Some(Commands::Deploy(cmd)) => cmd.run(),
+            Some(Commands::Docker(cmd)) => cmd.run(),
+            Some(Commands::Docs(cmd)) => cmd.run(),
+            Some(Commands::Doctor(cmd)) => cmd.run(),
+            Some(Commands::Info(cmd)) => cmd.run(),
             Some(Commands::License(cmd)) => cmd.run(),
+            Some(Commands::Man(cmd)) => cmd.run(),
             Some(Commands::Project(cmd)) => cmd.run(),
+            Some(Commands::Review(cmd)) => cmd.run(),
+            Some(Commands::Run(cmd)) => cmd.run(),
+            Some(Commands::Snippet(cmd)) => cmd.run(),
             Some(Commands::Template(cmd)) => cmd.run(),
+            Some(Commands::Tooling(cmd)) => cmd.run(),
             None => Ok(()),
         }
     }
@@ -30,12 +75,60 @@ impl Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Print the completion script of a shell
+    Completions(CompletionsCommand),
+
+    /// Manage user preferences and author identity
+    Config(ConfigCommand),
+
+    /// Deploy the project to Fly.io, Cloudflare, Vercel or a container registry
+    Deploy(DeployCommand),
+
+    /// Generate production Dockerfiles
+    Docker(DockerCommand),
+
+    /// Look up documentation offline and compare concepts across languages
+    Docs(DocsCommand),
+
+    /// Check that the toolchains used by templates are installed
+    Doctor(DoctorCommand),
+
+    /// Show the language, package manager, workspace and template of existing projects
+    Info(InfoCommand),
+
     /// Use a project license
     License(LicenseCommand),
 
-    /// Create a new project in Rust, Go or TypeScript
+    /// Generate the man pages
+    Man(ManCommand),
+
+    /// Create a new project in Rust, Go, TypeScript, Python, Zig or C++
     Project(ProjectCommand),
 
+    /// Review uncommitted changes or files with an AI model
+    Review(ReviewCommand),
+
+    /// Run build, test, lint, fmt or dev tasks with the project toolchain
+    Run(RunCommand),
+
+    /// Store, search and insert reusable code snippets
+    Snippet(SnippetCommand),
+
     /// Display templates information
     Template(TemplateCommand),
+
+    /// Set up linters, formatters, git hooks and tasks for the project
+    Tooling(ToolingCommand),
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn verifies_the_command_line_definition() {
+        Cli::command().debug_assert();
+    }
 }
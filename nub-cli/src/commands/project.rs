@@ -1,11 +1,28 @@
-use std::env;
+use std::{
+    collections::BTreeMap,
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
-use clap::{Args, Subcommand};
+use clap::{
+    Args, Subcommand,
+    builder::{PossibleValuesParser, TypedValueParser},
+};
 use inquire::Select;
 
 use crate::{
+    config::Config,
+    deploy,
+    detect::{self, JAVASCRIPT_MANAGERS, PackageManager},
     errors::CliError,
-    template::{Language, Template, TemplateManager},
+    hooks::{self, HookConfig, HookOptions},
+    provenance::{PROVENANCE_FILE, Provenance, SNAPSHOT_FILE, TemplateSnapshot},
+    template::{Language, Template, TemplateManager, TemplateVariables},
+    toolchain::{self, Version},
+    tooling::{self, ToolingOptions},
+    update::{self, Outcome},
+    validation,
 };
 
 #[derive(Args)]
@@ -18,6 +35,7 @@ impl ProjectCommand {
     pub fn run(&self) -> Result<(), CliError> {
         match &self.command {
             SubCommands::Create(cmd) => cmd.run(),
+            SubCommands::Update(cmd) => cmd.run(),
         }
     }
 }
@@ -27,6 +45,9 @@ impl ProjectCommand {
 enum SubCommands {
     /// Create a new project from a template.
     Create(CreateSubCommand),
+
+    /// Merge the changes made to the template since the project was created.
+    Update(UpdateSubCommand),
 }
 
 /// Arguments for the create subcommand.
@@ -37,6 +58,48 @@ struct CreateSubCommand {
 
     #[arg(short, long, help = "The programming language to use")]
     language: Option<Language>,
+
+    #[arg(
+        long,
+        help = "The package name of the project, defaults to the directory name"
+    )]
+    project_name: Option<String>,
+
+    #[arg(
+        long,
+        help = "The Go module path (e.g. github.com/acme/app), defaults to the configured prefix followed by the project name"
+    )]
+    module: Option<String>,
+
+    #[arg(
+        long = "pm",
+        value_parser = PossibleValuesParser::new(JAVASCRIPT_MANAGERS)
+            .try_map(|name| PackageManager::from_name(&name).ok_or("unknown package manager")),
+        help = "The package manager of TypeScript projects, defaults to the one of the template"
+    )]
+    package_manager: Option<PackageManager>,
+
+    #[arg(long, help = "Skip initializing a git repository")]
+    no_git: bool,
+
+    #[arg(long, help = "Skip installing dependencies")]
+    no_install: bool,
+
+    #[arg(long, help = "Skip formatting the generated project")]
+    no_format: bool,
+
+    #[arg(
+        long,
+        help = "Add linter, formatter, git hooks and task runner configurations, as `nub tooling init` does"
+    )]
+    tooling: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["name", "language"],
+        help = "Reproduce the project recorded in a .nub/project.toml file, with the same template and answers"
+    )]
+    from: Option<PathBuf>,
 }
 
 impl CreateSubCommand {
@@ -44,25 +107,60 @@ impl CreateSubCommand {
         let target_dir = env::current_dir()?;
         self.ensure_empty_directory(&target_dir)?;
 
-        if self.name.is_none() || self.language.is_none() {
+        let recorded = self.from.as_deref().map(Provenance::read).transpose()?;
+        if recorded.is_none() && (self.name.is_none() || self.language.is_none()) {
             println!("Tell Nub what type of project you want\n");
         }
 
         let manager = TemplateManager::new();
+        let config = Config::load()?;
 
-        let language = self.choose_language(&manager)?;
-        let template = self.choose_template(&manager, language)?;
-        let template_dir = format!(
-            "{}/{}",
-            language.to_string().to_lowercase(),
-            template.name.to_lowercase(),
-        );
-        manager.copy_template(&template_dir, &target_dir)?;
+        let template = match &recorded {
+            Some(recorded) => self.recorded_template(&manager, recorded)?,
+            None => {
+                let language = self.choose_language(&manager, &config)?;
+                self.choose_template(&manager, &config, language)?
+            }
+        };
+        let language = template.language;
+        let template_dir = template.path();
+        let manifest = manager.manifest(&template_dir)?;
+        self.warn_missing_tools(language, &manifest.requirements);
+        let variables = self.variables(&target_dir, &config, language, recorded.as_ref())?;
+        validation::validate_project(language, &variables)?;
+        manager.copy_template(&template_dir, &target_dir, &variables)?;
+        if !manifest.deploy.is_empty() {
+            let project = detect::detect(&target_dir)?;
+            for target in &manifest.deploy {
+                deploy::write_files(&target_dir, &target.provider().init(&project)?, false)?;
+            }
+        }
+        if self.tooling {
+            tooling::init(&target_dir, language, ToolingOptions::default(), false)?;
+        }
         println!("\nProject created!");
+
+        let options = HookOptions {
+            git: !self.no_git && !target_dir.join(".git").exists(),
+            install: !self.no_install,
+            format: !self.no_format,
+        };
+        let mut hook_config = manifest.hooks.merge(config.hooks);
+        if let Some(manager) = variables.package_manager {
+            hook_config = hook_config.merge(HookConfig {
+                install: Some(vec![manager.to_string(), "install".to_string()]),
+                ..HookConfig::default()
+            });
+        }
+        hooks::run_hooks(&hook_config.hooks(language, options), &target_dir);
         Ok(())
     }
 
-    fn choose_language(&self, manager: &TemplateManager) -> Result<Language, CliError> {
+    fn choose_language(
+        &self,
+        manager: &TemplateManager,
+        config: &Config,
+    ) -> Result<Language, CliError> {
         let languages = manager.languages();
         if languages.is_empty() {
             return Err(CliError::NoLanguagesAvailable);
@@ -70,17 +168,22 @@ impl CreateSubCommand {
 
         let selection = match self.language {
             Some(language) => {
-                if !languages.contains(&language.to_string()) {
+                if !languages.contains(&language) {
                     return Err(CliError::InvalidLanguage(language.to_string()));
                 }
                 println!("> Language: {language}\n");
                 language
             }
             None => {
-                let selection = Select::new("Select a programming language", languages).prompt()?;
-                selection
-                    .parse()
-                    .map_err(|_| CliError::InvalidLanguage(selection))?
+                // Preselect the preferred language from the user configuration
+                let cursor = config
+                    .defaults
+                    .language
+                    .and_then(|preferred| languages.iter().position(|lang| *lang == preferred))
+                    .unwrap_or_default();
+                Select::new("Select a programming language", languages)
+                    .with_starting_cursor(cursor)
+                    .prompt()?
             }
         };
         Ok(selection)
@@ -89,6 +192,7 @@ impl CreateSubCommand {
     fn choose_template(
         &self,
         manager: &TemplateManager,
+        config: &Config,
         language: Language,
     ) -> Result<Template, CliError> {
         let templates = manager.templates_for_language(language);
@@ -112,7 +216,15 @@ impl CreateSubCommand {
             Some(t) => Ok(t),
             None => {
                 let names: Vec<_> = templates.iter().map(|t| t.clone().name).collect();
-                let selection = Select::new("Select a template", names).prompt()?;
+                // Preselect the default template of the language from the user configuration
+                let cursor = config
+                    .templates
+                    .get(&language)
+                    .and_then(|preferred| names.iter().position(|name| name == preferred))
+                    .unwrap_or_default();
+                let selection = Select::new("Select a template", names)
+                    .with_starting_cursor(cursor)
+                    .prompt()?;
                 Ok(Template {
                     name: selection,
                     language,
@@ -121,6 +233,102 @@ impl CreateSubCommand {
         }
     }
 
+    /// The template of a recorded project, warning when it changed since the project was created.
+    fn recorded_template(
+        &self,
+        manager: &TemplateManager,
+        recorded: &Provenance,
+    ) -> Result<Template, CliError> {
+        let template = recorded
+            .template()
+            .filter(|template| {
+                manager
+                    .templates_for_language(template.language)
+                    .iter()
+                    .any(|available| available.name == template.name)
+            })
+            .ok_or_else(|| CliError::TemplateNotFound(recorded.template.clone()))?;
+        println!("> Template: {} ({})\n", template.name, template.language);
+
+        if manager.template_hash(&template.path())? != recorded.template_hash {
+            println!(
+                "Warning: the template changed since the project was created with nub {}, the result will differ\n",
+                recorded.nub_version
+            );
+        }
+        Ok(template)
+    }
+
+    /// The answers from the command line, then from the recorded project, then the defaults.
+    fn variables(
+        &self,
+        target_dir: &Path,
+        config: &Config,
+        language: Language,
+        recorded: Option<&Provenance>,
+    ) -> Result<TemplateVariables, CliError> {
+        let recorded = recorded.map(|recorded| &recorded.variables);
+        let name = match self
+            .project_name
+            .as_ref()
+            .or(recorded.map(|variables| &variables.name))
+        {
+            Some(name) => name.clone(),
+            None => target_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or(CliError::InvalidName)?
+                .to_string(),
+        };
+
+        // A recorded module path only holds for the recorded name
+        let module = self.module.as_ref().or(recorded
+            .filter(|_| self.project_name.is_none())
+            .map(|variables| &variables.go_module));
+        let go_module = match (module, &config.defaults.go_module_prefix) {
+            (Some(module), _) => module.clone(),
+            (None, Some(prefix)) => format!("{}/{name}", prefix.trim_end_matches('/')),
+            (None, None) => name.clone(),
+        };
+
+        // The flag is rejected for other languages, the configured default ignored
+        let package_manager = match self.package_manager {
+            Some(_) if language != Language::Typescript => {
+                return Err(CliError::PackageManagerNotApplicable(language.to_string()));
+            }
+            Some(manager) => Some(manager),
+            None if language != Language::Typescript => None,
+            None => recorded
+                .and_then(|variables| variables.package_manager)
+                .or(config.defaults.package_manager),
+        };
+        if let Some(manager) = package_manager.filter(|manager| !manager.is_javascript()) {
+            return Err(CliError::UnsupportedPackageManager(manager.to_string()));
+        }
+
+        Ok(TemplateVariables {
+            name,
+            go_module,
+            package_manager,
+        })
+    }
+
+    fn warn_missing_tools(&self, language: Language, requirements: &BTreeMap<String, Version>) {
+        let missing: Vec<_> = toolchain::check(language, requirements)
+            .into_iter()
+            .filter(|report| report.is_required() && !report.is_satisfied())
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+
+        println!("Warning: this template needs tools that are missing or outdated\n");
+        for report in &missing {
+            report.print();
+        }
+        println!("\nRun `nub doctor --language {language}` for more details\n");
+    }
+
     fn ensure_empty_directory(&self, dir: &std::path::Path) -> Result<(), CliError> {
         let entries: Vec<_> = std::fs::read_dir(dir)
             .map_err(CliError::Io)?
@@ -134,3 +342,91 @@ impl CreateSubCommand {
         Ok(())
     }
 }
+
+/// Arguments for the update subcommand.
+#[derive(Args)]
+struct UpdateSubCommand {
+    #[arg(long, help = "Show what would change without writing any file")]
+    dry_run: bool,
+
+    #[arg(long, help = "Update even when the project has uncommitted changes")]
+    force: bool,
+}
+
+impl UpdateSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let current_dir = env::current_dir()?;
+        let root = current_dir
+            .ancestors()
+            .find(|dir| dir.join(PROVENANCE_FILE).is_file())
+            .ok_or_else(|| CliError::NoProvenance(current_dir.display().to_string()))?;
+        let mut provenance = Provenance::read(&root.join(PROVENANCE_FILE))?;
+        let template = provenance
+            .template()
+            .ok_or_else(|| CliError::TemplateNotFound(provenance.template.clone()))?;
+
+        let manager = TemplateManager::new();
+        let template_hash = manager.template_hash(&template.path())?;
+        if template_hash == provenance.template_hash {
+            println!(
+                "> {} is up to date with the {} template",
+                provenance.variables.name, template.name
+            );
+            return Ok(());
+        }
+
+        let base = TemplateSnapshot::load(root)?.ok_or_else(|| {
+            CliError::FileNotFound(root.join(SNAPSHOT_FILE).display().to_string())
+        })?;
+        // The merge rewrites files in place, a clean tree keeps it easy to review and revert
+        if !self.dry_run && !self.force && has_uncommitted_changes(root) {
+            return Err(CliError::UncommittedChanges(root.display().to_string()));
+        }
+
+        println!(
+            "> Updating {} to the {} template of nub {}\n",
+            provenance.variables.name,
+            template.name,
+            env!("CARGO_PKG_VERSION")
+        );
+        let rendered = manager.render_template(&template.path(), &provenance.variables)?;
+        let changes = update::plan(root, &base, &rendered)?;
+        for change in &changes {
+            println!("> {}", change.describe());
+        }
+
+        if !self.dry_run {
+            update::apply(root, &changes)?;
+            TemplateSnapshot::new(&rendered).save(root)?;
+            provenance.mark_updated(template_hash);
+            provenance.save(root)?;
+        }
+
+        let conflicts = changes
+            .iter()
+            .filter(|change| change.outcome == Outcome::Conflict)
+            .count();
+        match (changes.is_empty(), conflicts, self.dry_run) {
+            (true, _, _) => println!("No template change affects the project files"),
+            (false, _, true) => println!("\nDry run, no file was written"),
+            (false, 0, false) => println!("\nReview the changes with `git diff` before committing"),
+            (false, 1, false) => {
+                println!(
+                    "\nResolve the conflicts in 1 file, then review the changes with `git diff`"
+                )
+            }
+            (false, conflicts, false) => println!(
+                "\nResolve the conflicts in {conflicts} files, then review the changes with `git diff`"
+            ),
+        }
+        Ok(())
+    }
+}
+
+fn has_uncommitted_changes(root: &Path) -> bool {
+    Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(root)
+        .output()
+        .is_ok_and(|output| output.status.success() && !output.stdout.is_empty())
+}
@@ -0,0 +1,92 @@
+use std::{env, path::PathBuf};
+
+use clap::Args;
+
+use crate::{
+    config::{self, Config},
+    errors::CliError,
+    review::{
+        self, DEFAULT_BASE_URL, DEFAULT_MODEL, InputKind, ModelProvider, OpenAiCompatible,
+        ReviewInput,
+    },
+};
+
+/// Arguments for the review command.
+#[derive(Args)]
+pub(crate) struct ReviewCommand {
+    #[arg(help = "Files or directories to review, the uncommitted git changes by default")]
+    paths: Vec<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Base URL of an OpenAI-compatible API, defaults to review.base_url of the global configuration or a local Ollama"
+    )]
+    base_url: Option<String>,
+
+    #[arg(short, long, help = "The model to use, defaults to review.model")]
+    model: Option<String>,
+}
+
+impl ReviewCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let config = Config::load()?;
+        // A repository's .nub.toml must not redirect the API key to a server of its choosing
+        let global = match config::global_path() {
+            Some(path) => Config::from_table(config::read_table(&path)?)?,
+            None => Config::default(),
+        };
+        let base_url = self
+            .base_url
+            .clone()
+            .or(global.review.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let model = self
+            .model
+            .clone()
+            .or(config.review.model)
+            .unwrap_or_else(|| DEFAULT_MODEL.to_string());
+        // Keys stay out of configuration files, which are often committed
+        let api_key = env::var("NUB_API_KEY")
+            .or_else(|_| env::var("OPENAI_API_KEY"))
+            .ok();
+
+        let input = if self.paths.is_empty() {
+            ReviewInput::from_git_diff()?
+        } else {
+            ReviewInput::from_paths(&self.paths)?
+        };
+
+        let subject = match input.kind {
+            InputKind::Diff => "uncommitted changes",
+            InputKind::Files => "files",
+        };
+        println!("> Reviewing {subject} with {model}");
+        if input.truncated {
+            println!(
+                "> The input is too large and was truncated, review fewer files for full coverage"
+            );
+        }
+
+        let provider = OpenAiCompatible::new(&base_url, &model, api_key);
+        let response = provider.complete(&input.messages())?;
+        let findings = review::parse_findings(&response);
+
+        if findings.is_empty() {
+            println!("\nNo issues found");
+            return Ok(());
+        }
+
+        println!();
+        for finding in &findings {
+            let severity = finding.severity.as_deref().unwrap_or("note");
+            match (&finding.path, finding.line) {
+                (Some(path), Some(line)) => {
+                    println!("{path}:{line}: {severity}: {}", finding.message)
+                }
+                _ => println!("{severity}: {}", finding.message),
+            }
+        }
+        println!("\n{} findings", findings.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+    process::Child,
+};
+
+use clap::Args;
+
+use crate::{
+    config::{self, Config, LOCAL_CONFIG_FILE},
+    detect::{self, Project},
+    errors::CliError,
+    tasks::{self, MISSING_PROGRAM_CODE, TaskCommand},
+};
+
+/// Arguments for the run command.
+#[derive(Args)]
+pub(crate) struct RunCommand {
+    #[arg(
+        help = "The task to run: build, test, lint, fmt, dev or one from the [tasks] table of .nub.toml"
+    )]
+    task: String,
+}
+
+/// A task command resolved for a directory, labelled for the summary.
+struct Run {
+    label: String,
+    dir: PathBuf,
+    command: TaskCommand,
+}
+
+impl RunCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let runs = self.plan(&env::current_dir()?)?;
+
+        // Development servers keep running, so they are started side by side
+        let codes = if self.task == "dev" {
+            run_concurrently(&runs)
+        } else {
+            run_sequentially(&runs)
+        };
+        self.finish(&runs, &codes)
+    }
+
+    /// Resolves the commands running the task from `current_dir`.
+    fn plan(&self, current_dir: &Path) -> Result<Vec<Run>, CliError> {
+        // An override in the closest .nub.toml covers the whole tree on its own
+        if let Some(run) = self.override_run(current_dir)? {
+            return Ok(vec![run]);
+        }
+
+        // Below the project root, e.g. in `src/`, the enclosing project is the one to run
+        let mut projects = detect::find_projects(current_dir);
+        if projects.is_empty() {
+            projects.push(detect::detect_nearest(current_dir)?);
+        }
+
+        let mut runs = Vec::new();
+        for project in &projects {
+            let label = label(current_dir, project);
+            match self.command(current_dir, project)? {
+                Some(command) => runs.push(Run {
+                    label,
+                    dir: project.root.clone(),
+                    command,
+                }),
+                None => println!("> Skipped {label}, no {} task", self.task),
+            }
+        }
+        if runs.is_empty() {
+            let mut languages: Vec<String> = projects
+                .iter()
+                .map(|project| project.language.to_string())
+                .collect();
+            languages.dedup();
+            return Err(CliError::UnknownTask(
+                self.task.clone(),
+                languages.join(" or "),
+            ));
+        }
+        Ok(runs)
+    }
+
+    /// The override from the closest `.nub.toml`, run from its directory since its commands
+    /// are relative to it.
+    ///
+    /// The global configuration is not consulted, its commands could not suit every language.
+    fn override_run(&self, current_dir: &Path) -> Result<Option<Run>, CliError> {
+        let Some(path) = config::local_path_from(current_dir) else {
+            return Ok(None);
+        };
+        let Some(dir) = path.parent().map(Path::to_path_buf) else {
+            return Ok(None);
+        };
+        let table = config::read_table(&path)?;
+        let Some(command) = Config::from_table(table)?.tasks.remove(&self.task) else {
+            return Ok(None);
+        };
+        let label = if dir == current_dir {
+            ".".to_string()
+        } else {
+            dir.display().to_string()
+        };
+        Ok(Some(Run {
+            label,
+            dir,
+            command,
+        }))
+    }
+
+    /// The override from the project's own configuration file, the language default otherwise.
+    fn command(
+        &self,
+        current_dir: &Path,
+        project: &Project,
+    ) -> Result<Option<TaskCommand>, CliError> {
+        if project.root != current_dir {
+            let table = config::read_table(&project.root.join(LOCAL_CONFIG_FILE))?;
+            if let Some(command) = Config::from_table(table)?.tasks.remove(&self.task) {
+                return Ok(Some(command));
+            }
+        }
+        Ok(tasks::default_command(project, &self.task))
+    }
+
+    /// Prints a summary for monorepos and fails with the first non-zero exit code.
+    fn finish(&self, runs: &[Run], codes: &[i32]) -> Result<(), CliError> {
+        if runs.len() > 1 {
+            println!();
+            for (run, code) in runs.iter().zip(codes) {
+                match code {
+                    0 => println!("> {}: ok", run.label),
+                    code => println!("> {}: failed with exit code {code}", run.label),
+                }
+            }
+        }
+
+        match codes.iter().find(|code| **code != 0) {
+            Some(code) => Err(CliError::TaskFailed(self.task.clone(), *code)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn run_sequentially(runs: &[Run]) -> Vec<i32> {
+    runs.iter()
+        .map(|run| {
+            announce(run);
+            wait(run, run.command.spawn(&run.dir))
+        })
+        .collect()
+}
+
+fn run_concurrently(runs: &[Run]) -> Vec<i32> {
+    let children: Vec<_> = runs
+        .iter()
+        .map(|run| {
+            announce(run);
+            run.command.spawn(&run.dir)
+        })
+        .collect();
+    runs.iter()
+        .zip(children)
+        .map(|(run, child)| wait(run, child))
+        .collect()
+}
+
+fn announce(run: &Run) {
+    println!("> {}: {}", run.label, run.command);
+}
+
+/// Waits for the task, reporting a missing program the way shells do.
+fn wait(run: &Run, child: io::Result<Child>) -> i32 {
+    let status = child.and_then(|mut child| child.wait());
+    match status {
+        // Terminated by a signal when there is no code
+        Ok(status) => status.code().unwrap_or(1),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            eprintln!(
+                "> {}: `{}` is not installed",
+                run.label,
+                program(&run.command)
+            );
+            MISSING_PROGRAM_CODE
+        }
+        Err(err) => {
+            eprintln!("> {}: {err}", run.label);
+            1
+        }
+    }
+}
+
+fn program(command: &TaskCommand) -> &str {
+    match command {
+        TaskCommand::Shell(_) => "sh",
+        TaskCommand::Args(args) => args.first().map(String::as_str).unwrap_or_default(),
+    }
+}
+
+/// The project directory relative to where nub runs, with its language.
+fn label(current_dir: &Path, project: &Project) -> String {
+    let dir = project
+        .root
+        .strip_prefix(current_dir)
+        .ok()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map(|dir| dir.display().to_string())
+        .unwrap_or_else(|| ".".to_string());
+    format!("{dir} ({})", project.language)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn run_command(task: &str) -> RunCommand {
+        RunCommand {
+            task: task.to_string(),
+        }
+    }
+
+    fn commands(runs: &[Run]) -> Vec<(String, PathBuf)> {
+        runs.iter()
+            .map(|run| (run.command.to_string(), run.dir.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn global_tasks_do_not_override_language_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let global = dir.path().join("config");
+        fs::create_dir_all(global.join("nub")).unwrap();
+        fs::write(
+            global.join("nub/config.toml"),
+            "[tasks]\nbuild = \"cargo build\"\n",
+        )
+        .unwrap();
+        // SAFETY: no other test reads or writes XDG_CONFIG_HOME
+        unsafe { env::set_var("XDG_CONFIG_HOME", &global) };
+
+        let project_dir = dir.path().join("api");
+        fs::create_dir_all(&project_dir).unwrap();
+        fs::write(
+            project_dir.join("go.mod"),
+            "module example.com/api\n\ngo 1.24\n",
+        )
+        .unwrap();
+
+        let runs = run_command("build").plan(&project_dir).unwrap();
+        let project = detect::detect(&project_dir).unwrap();
+        let expected = tasks::default_command(&project, "build").unwrap();
+        assert_eq!(
+            commands(&runs),
+            [(expected.to_string(), project_dir.clone())]
+        );
+    }
+
+    #[test]
+    fn local_overrides_run_from_their_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join(LOCAL_CONFIG_FILE),
+            "[tasks]\nlint = \"make lint\"\n",
+        )
+        .unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]\nname = \"demo\"\n").unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+
+        let runs = run_command("lint").plan(&root.join("src")).unwrap();
+        assert_eq!(
+            commands(&runs),
+            [("make lint".to_string(), root.to_path_buf())]
+        );
+    }
+
+    #[test]
+    fn tasks_run_in_the_enclosing_project_below_its_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("Cargo.toml"), "[package]\nname = \"demo\"\n").unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+
+        let runs = run_command("test").plan(&root.join("src")).unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].dir, root);
+        assert!(runs[0].command.to_string().starts_with("cargo test"));
+    }
+}
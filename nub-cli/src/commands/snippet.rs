@@ -0,0 +1,406 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{self, IsTerminal, Read},
+    path::PathBuf,
+};
+
+use clap::{Args, Subcommand};
+use inquire::Text;
+
+use crate::{
+    deploy,
+    errors::CliError,
+    snippet::{Editor, Snippet, SnippetSource, SnippetStore},
+    template::Language,
+};
+
+#[derive(Args)]
+pub(crate) struct SnippetCommand {
+    #[command(subcommand)]
+    command: SubCommands,
+}
+
+impl SnippetCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        match &self.command {
+            SubCommands::Add(cmd) => cmd.run(),
+            SubCommands::List(cmd) => cmd.run(),
+            SubCommands::Show(cmd) => cmd.run(),
+            SubCommands::Rm(cmd) => cmd.run(),
+            SubCommands::Search(cmd) => cmd.run(),
+            SubCommands::Insert(cmd) => cmd.run(),
+            SubCommands::Export(cmd) => cmd.run(),
+        }
+    }
+}
+
+/// Available subcommands for snippet operations.
+#[derive(Subcommand)]
+enum SubCommands {
+    /// Save a snippet read from a file or standard input.
+    Add(AddSubCommand),
+
+    /// List built-in and saved snippets.
+    List(ListSubCommand),
+
+    /// Print a snippet and its placeholders.
+    Show(ShowSubCommand),
+
+    /// Remove a saved snippet.
+    Rm(RmSubCommand),
+
+    /// Search snippets by name, tag, description or content.
+    Search(SearchSubCommand),
+
+    /// Render a snippet into a file.
+    Insert(InsertSubCommand),
+
+    /// Export snippets to an editor's native snippet format.
+    Export(ExportSubCommand),
+}
+
+/// Arguments for the add subcommand.
+#[derive(Args)]
+struct AddSubCommand {
+    #[arg(help = "The snippet name (e.g. http-handler)")]
+    name: String,
+
+    #[arg(
+        short,
+        long,
+        help = "The snippet language, guessed from --file when omitted"
+    )]
+    lang: Option<Language>,
+
+    #[arg(
+        short,
+        long,
+        help = "Read the body from this file instead of standard input"
+    )]
+    file: Option<PathBuf>,
+
+    #[arg(short, long, default_value = "", help = "A short description")]
+    description: String,
+
+    #[arg(short, long = "tag", help = "A tag used by search, can be repeated")]
+    tags: Vec<String>,
+
+    #[arg(long, help = "Overwrite an existing snippet with the same name")]
+    force: bool,
+}
+
+impl AddSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        if self.name.is_empty()
+            || !self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(CliError::InvalidName);
+        }
+
+        let language = self
+            .lang
+            .or_else(|| self.file.as_deref().and_then(Language::from_path))
+            .ok_or(CliError::InvalidInput)?;
+        let body = match &self.file {
+            Some(file) => fs::read_to_string(file)?,
+            None => {
+                if io::stdin().is_terminal() {
+                    println!("Type the snippet body, then press Ctrl-D\n");
+                }
+                let mut body = String::new();
+                io::stdin().read_to_string(&mut body)?;
+                body
+            }
+        };
+        if body.trim().is_empty() {
+            return Err(CliError::InvalidInput);
+        }
+
+        let snippet = Snippet {
+            name: self.name.clone(),
+            language,
+            description: self.description.clone(),
+            tags: self.tags.clone(),
+            body,
+            source: SnippetSource::User,
+        };
+        let path = SnippetStore::open().add(&snippet, self.force)?;
+        println!("> Saved {} to {}", snippet.name, path.display());
+        Ok(())
+    }
+}
+
+/// Arguments for the list subcommand.
+#[derive(Args)]
+struct ListSubCommand {
+    #[arg(short, long, help = "Only list snippets of this language")]
+    lang: Option<Language>,
+
+    #[arg(short, long, help = "Only list snippets with this tag")]
+    tag: Option<String>,
+}
+
+impl ListSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let snippets: Vec<Snippet> = SnippetStore::open()
+            .all()?
+            .into_iter()
+            .filter(|snippet| self.lang.is_none_or(|lang| snippet.language == lang))
+            .filter(|snippet| {
+                self.tag
+                    .as_ref()
+                    .is_none_or(|tag| snippet.tags.contains(tag))
+            })
+            .collect();
+
+        if snippets.is_empty() {
+            println!("No snippets found");
+            return Ok(());
+        }
+        for snippet in &snippets {
+            print_summary(snippet);
+        }
+        Ok(())
+    }
+}
+
+/// Arguments for the show subcommand.
+#[derive(Args)]
+struct ShowSubCommand {
+    #[arg(help = "The snippet name")]
+    name: String,
+
+    #[arg(
+        short,
+        long,
+        help = "The snippet language, when several share the name"
+    )]
+    lang: Option<Language>,
+}
+
+impl ShowSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let snippet = SnippetStore::open().find(&self.name, self.lang)?;
+        print_summary(&snippet);
+        if !snippet.tags.is_empty() {
+            println!("  tags: {}", snippet.tags.join(", "));
+        }
+
+        let placeholders = snippet.placeholders();
+        if !placeholders.is_empty() {
+            println!("  placeholders:");
+            for (name, default) in placeholders {
+                match default {
+                    Some(default) => println!("    {name} (default: {default})"),
+                    None => println!("    {name}"),
+                }
+            }
+        }
+        println!("\n{}", snippet.body.trim_end());
+        Ok(())
+    }
+}
+
+/// Arguments for the rm subcommand.
+#[derive(Args)]
+struct RmSubCommand {
+    #[arg(help = "The snippet name")]
+    name: String,
+
+    #[arg(
+        short,
+        long,
+        help = "The snippet language, when several share the name"
+    )]
+    lang: Option<Language>,
+}
+
+impl RmSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let snippet = SnippetStore::open().remove(&self.name, self.lang)?;
+        println!("> Removed {} ({})", snippet.name, snippet.language);
+        Ok(())
+    }
+}
+
+/// Arguments for the search subcommand.
+#[derive(Args)]
+struct SearchSubCommand {
+    #[arg(help = "The text to look for")]
+    query: String,
+
+    #[arg(short, long, help = "Only search snippets of this language")]
+    lang: Option<Language>,
+}
+
+impl SearchSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let snippets: Vec<Snippet> = SnippetStore::open()
+            .search(&self.query)?
+            .into_iter()
+            .filter(|snippet| self.lang.is_none_or(|lang| snippet.language == lang))
+            .collect();
+
+        if snippets.is_empty() {
+            println!("No snippets found for {}", self.query);
+            return Ok(());
+        }
+        for snippet in &snippets {
+            print_summary(snippet);
+        }
+        Ok(())
+    }
+}
+
+/// Arguments for the insert subcommand.
+#[derive(Args)]
+struct InsertSubCommand {
+    #[arg(help = "The snippet name")]
+    name: String,
+
+    #[arg(long, help = "The file to append the snippet to, created if missing")]
+    into: PathBuf,
+
+    #[arg(
+        short,
+        long,
+        help = "The snippet language, guessed from --into when several share the name"
+    )]
+    lang: Option<Language>,
+
+    #[arg(
+        long = "set",
+        value_parser = parse_assignment,
+        help = "A placeholder value as name=value, can be repeated"
+    )]
+    values: Vec<(String, String)>,
+
+    #[arg(long, help = "Use placeholder defaults instead of prompting")]
+    defaults: bool,
+}
+
+impl InsertSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let store = SnippetStore::open();
+        let language = self.lang.or_else(|| Language::from_path(&self.into));
+        let snippet = match store.find(&self.name, language) {
+            // The file extension is only a hint, unlike an explicit --lang
+            Err(CliError::SnippetNotFound(_)) if self.lang.is_none() && language.is_some() => {
+                store.find(&self.name, None)?
+            }
+            result => result?,
+        };
+
+        let mut values: BTreeMap<String, String> = self.values.iter().cloned().collect();
+        if !self.defaults {
+            for (name, default) in snippet.placeholders() {
+                if values.contains_key(name) {
+                    continue;
+                }
+                let mut prompt = Text::new(name);
+                if let Some(default) = default {
+                    prompt = prompt.with_default(default);
+                }
+                values.insert(name.to_string(), prompt.prompt()?);
+            }
+        }
+        let rendered = snippet.render(&values);
+
+        let existing = fs::read_to_string(&self.into).unwrap_or_default();
+        let content = if existing.trim().is_empty() {
+            rendered
+        } else {
+            let separator = if existing.ends_with('\n') {
+                "\n"
+            } else {
+                "\n\n"
+            };
+            format!("{existing}{separator}{rendered}")
+        };
+        if let Some(parent) = self.into.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.into, content)?;
+
+        let action = if existing.is_empty() {
+            "Created"
+        } else {
+            "Appended to"
+        };
+        println!("> {action} {} with {}", self.into.display(), snippet.name);
+        Ok(())
+    }
+}
+
+/// Arguments for the export subcommand.
+#[derive(Args)]
+struct ExportSubCommand {
+    #[arg(short, long, help = "The editor to export snippets for")]
+    editor: Editor,
+
+    #[arg(
+        short,
+        long,
+        help = "The directory to write to, the current directory by default"
+    )]
+    output: Option<PathBuf>,
+
+    #[arg(short, long, help = "Only export snippets of this language")]
+    lang: Option<Language>,
+
+    #[arg(long, help = "Overwrite previously exported files")]
+    force: bool,
+}
+
+impl ExportSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let snippets: Vec<Snippet> = SnippetStore::open()
+            .all()?
+            .into_iter()
+            .filter(|snippet| self.lang.is_none_or(|lang| snippet.language == lang))
+            .collect();
+        if snippets.is_empty() {
+            println!("No snippets to export");
+            return Ok(());
+        }
+
+        let output = match &self.output {
+            Some(output) => output.clone(),
+            None => env::current_dir()?,
+        };
+        println!(
+            "> Exporting {} snippets for {}\n",
+            snippets.len(),
+            self.editor
+        );
+        deploy::write_files(&output, &self.editor.export(&snippets), self.force)?;
+        println!("\n{}", self.editor.install_hint());
+        Ok(())
+    }
+}
+
+fn print_summary(snippet: &Snippet) {
+    let builtin = match snippet.source {
+        SnippetSource::Builtin => " (built-in)",
+        SnippetSource::User => "",
+    };
+    if snippet.description.is_empty() {
+        println!("[{}] {}{builtin}", snippet.language, snippet.name);
+    } else {
+        println!(
+            "[{}] {} - {}{builtin}",
+            snippet.language, snippet.name, snippet.description
+        );
+    }
+}
+
+fn parse_assignment(value: &str) -> Result<(String, String), String> {
+    value
+        .split_once('=')
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected name=value, got {value}"))
+}
@@ -0,0 +1,66 @@
+use std::env;
+
+use clap::{Args, Subcommand};
+
+use crate::{
+    detect,
+    errors::CliError,
+    tooling::{self, GitHooks, TaskRunner, ToolingOptions},
+};
+
+#[derive(Args)]
+pub(crate) struct ToolingCommand {
+    #[command(subcommand)]
+    command: SubCommands,
+}
+
+impl ToolingCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        match &self.command {
+            SubCommands::Init(cmd) => cmd.run(),
+        }
+    }
+}
+
+/// Available subcommands for tooling operations.
+#[derive(Subcommand)]
+enum SubCommands {
+    /// Write linter, formatter, editor, git hooks and task runner configurations.
+    Init(InitSubCommand),
+}
+
+/// Arguments for the init subcommand.
+#[derive(Args)]
+struct InitSubCommand {
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        help = "The git hooks manager to configure"
+    )]
+    hooks: GitHooks,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        help = "The task runner to write tasks for"
+    )]
+    tasks: TaskRunner,
+
+    #[arg(long, help = "Overwrite existing configuration files")]
+    force: bool,
+}
+
+impl InitSubCommand {
+    pub fn run(&self) -> Result<(), CliError> {
+        let project = detect::detect_nearest(&env::current_dir()?)?;
+        println!("> Detected {} project {}\n", project.language, project.name);
+
+        let options = ToolingOptions {
+            hooks: self.hooks,
+            tasks: self.tasks,
+        };
+        tooling::init(&project.root, project.language, options, self.force)
+    }
+}
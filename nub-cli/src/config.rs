@@ -0,0 +1,242 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use serde::Deserialize;
+use toml::{Table, Value};
+
+use crate::{
+    detect::PackageManager, errors::CliError, hooks::HookConfig, tasks::TaskCommand,
+    template::Language,
+};
+
+/// Per-repository configuration file, looked up from the current directory upwards.
+pub const LOCAL_CONFIG_FILE: &str = ".nub.toml";
+
+/// Dotted keys of the values in [`Config`], the `templates` and `tasks` tables aside.
+const KEYS: &[&str] = &[
+    "user.name",
+    "user.organization",
+    "user.email",
+    "defaults.license",
+    "defaults.language",
+    "defaults.go_module_prefix",
+    "defaults.package_manager",
+    "hooks.generate",
+    "hooks.install",
+    "hooks.format",
+    "review.base_url",
+    "review.model",
+];
+
+/// User preferences merged from the global and per-repository configuration files.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub user: UserConfig,
+    pub defaults: DefaultsConfig,
+    /// Default template per language, e.g. `rust = "axum-basic"`.
+    pub templates: BTreeMap<Language, String>,
+    pub hooks: HookConfig,
+    pub review: ReviewConfig,
+    /// Commands run by `nub run` instead of the language defaults, e.g. `test = "cargo nextest run"`.
+    ///
+    /// Only honoured in `.nub.toml`, a global command would apply to every language.
+    pub tasks: BTreeMap<String, TaskCommand>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UserConfig {
+    pub name: Option<String>,
+    pub organization: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DefaultsConfig {
+    pub license: Option<String>,
+    pub language: Option<Language>,
+    /// Prefix prepended to the project name to build Go module paths, e.g. `github.com/acme/`.
+    pub go_module_prefix: Option<String>,
+    /// Package manager of new TypeScript projects, e.g. `pnpm`.
+    pub package_manager: Option<PackageManager>,
+}
+
+/// Model used by `nub review`, any OpenAI-compatible server works.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReviewConfig {
+    /// Base URL of the API, e.g. `http://localhost:11434/v1` for Ollama.
+    ///
+    /// Only read from the global configuration since the API key is sent to it.
+    pub base_url: Option<String>,
+    pub model: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, CliError> {
+        Self::from_table(load_table()?)
+    }
+
+    pub fn from_table(table: Table) -> Result<Self, CliError> {
+        table
+            .try_into()
+            .map_err(|err: toml::de::Error| CliError::InvalidConfig(err.message().to_string()))
+    }
+
+    /// The author name, falling back to git `user.name`.
+    pub fn author(&self) -> Option<String> {
+        self.user.name.clone().or_else(|| git_config("user.name"))
+    }
+
+    /// The copyright holder used in licenses: the organization if any, the author otherwise.
+    pub fn copyright_holder(&self) -> Option<String> {
+        self.user.organization.clone().or_else(|| self.author())
+    }
+}
+
+/// Directory holding the global configuration and user data, usually `~/.config/nub`.
+pub fn global_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("nub"))
+}
+
+/// Path of the global configuration file, usually `~/.config/nub/config.toml`.
+pub fn global_path() -> Option<PathBuf> {
+    global_dir().map(|dir| dir.join("config.toml"))
+}
+
+/// Path of the closest per-repository configuration file, if any.
+pub fn local_path() -> Option<PathBuf> {
+    local_path_from(&env::current_dir().ok()?)
+}
+
+/// Path of the closest per-repository configuration file from `dir` upwards, if any.
+pub fn local_path_from(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(LOCAL_CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+/// Loads the global configuration overridden by the per-repository one.
+pub fn load_table() -> Result<Table, CliError> {
+    let mut table = Table::new();
+    for path in [global_path(), local_path()].into_iter().flatten() {
+        merge(&mut table, read_table(&path)?);
+    }
+    Ok(table)
+}
+
+pub fn read_table(path: &Path) -> Result<Table, CliError> {
+    if !path.exists() {
+        return Ok(Table::new());
+    }
+    let content = fs::read_to_string(path)?;
+    content.parse().map_err(|err: toml::de::Error| {
+        CliError::InvalidConfig(format!("{}: {}", path.display(), err.message()))
+    })
+}
+
+pub fn write_table(path: &Path, table: &Table) -> Result<(), CliError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let content = toml::to_string(table).map_err(|err| CliError::InvalidConfig(err.to_string()))?;
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Looks up a dotted key such as `user.name`.
+pub fn get<'a>(table: &'a Table, key: &str) -> Option<&'a Value> {
+    let (parents, leaf) = split_key(key);
+    let mut current = table;
+    for part in parents {
+        current = current.get(part)?.as_table()?;
+    }
+    current.get(leaf)
+}
+
+/// Whether a dotted key names a configuration value, set or not, e.g. `templates.rust`.
+pub fn is_known_key(key: &str) -> bool {
+    if let Some(language) = key.strip_prefix("templates.") {
+        return Language::try_from(language.to_string()).is_ok();
+    }
+    KEYS.contains(&key)
+        || key
+            .strip_prefix("tasks.")
+            .is_some_and(|task| !task.is_empty())
+}
+
+/// Sets a dotted key such as `user.name`, creating intermediate tables as needed.
+pub fn set(table: &mut Table, key: &str, value: Value) -> Result<(), CliError> {
+    let (parents, leaf) = split_key(key);
+    let mut current = table;
+    for part in parents {
+        current = current
+            .entry(part)
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| CliError::UnknownConfigKey(key.to_string()))?;
+    }
+    current.insert(leaf.to_string(), value);
+    Ok(())
+}
+
+/// Flattens nested tables into dotted keys, in alphabetical order.
+pub fn flatten(table: &Table) -> Vec<(String, &Value)> {
+    let mut entries = Vec::new();
+    for (key, value) in table {
+        match value {
+            Value::Table(inner) => {
+                for (inner_key, inner_value) in flatten(inner) {
+                    entries.push((format!("{key}.{inner_key}"), inner_value));
+                }
+            }
+            _ => entries.push((key.clone(), value)),
+        }
+    }
+    entries
+}
+
+/// Values read from git when missing from the configuration.
+pub fn git_fallback(key: &str) -> Option<String> {
+    match key {
+        "user.name" | "user.email" => git_config(key),
+        _ => None,
+    }
+}
+
+fn split_key(key: &str) -> (Vec<&str>, &str) {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let leaf = parts.pop().unwrap_or(key);
+    (parts, leaf)
+}
+
+fn merge(base: &mut Table, other: Table) {
+    for (key, value) in other {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(base_inner)), Value::Table(other_inner)) => {
+                merge(base_inner, other_inner)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn git_config(key: &str) -> Option<String> {
+    let output = Command::new("git").args(["config", key]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let value = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
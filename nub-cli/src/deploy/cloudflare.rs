@@ -0,0 +1,57 @@
+use chrono::Local;
+
+use crate::{detect::Project, errors::CliError, template::Language};
+
+use super::{DeployOptions, DeployTarget, Deployer, GeneratedFile, Provider, command, find_url};
+
+/// Cloudflare Workers, which run JavaScript and TypeScript entry points.
+pub struct Cloudflare;
+
+impl Provider for Cloudflare {
+    fn target(&self) -> DeployTarget {
+        DeployTarget::Cloudflare
+    }
+
+    fn supports(&self, language: Language) -> bool {
+        language == Language::Typescript
+    }
+
+    fn config_files(&self, project: &Project) -> Result<Vec<GeneratedFile>, CliError> {
+        let name = &project.name;
+        let main = project.typescript_entry();
+        let compatibility_date = Local::now().format("%Y-%m-%d");
+        let wrangler_toml = format!(
+            r#"name = "{name}"
+main = "{main}"
+compatibility_date = "{compatibility_date}"
+
+[observability]
+enabled = true
+"#
+        );
+
+        Ok(vec![GeneratedFile::new("wrangler.toml", wrangler_toml)])
+    }
+}
+
+impl Deployer for Cloudflare {
+    fn required_files(&self) -> &'static [&'static str] {
+        &["wrangler.toml"]
+    }
+
+    fn login_command(&self) -> &'static str {
+        "wrangler login"
+    }
+
+    fn commands(
+        &self,
+        _project: &Project,
+        _options: &DeployOptions,
+    ) -> Result<Vec<Vec<String>>, CliError> {
+        Ok(vec![command(&["wrangler", "deploy"])])
+    }
+
+    fn parse_url(&self, output: &str) -> Option<String> {
+        find_url(output, ".workers.dev")
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{detect::Project, docker, errors::CliError, template::Language};
+
+use super::{DeployOptions, DeployTarget, Deployer, GeneratedFile, Provider, command};
+
+/// Container image built from a multi-stage Dockerfile, runnable on any container platform.
+pub struct Docker;
+
+impl Provider for Docker {
+    fn target(&self) -> DeployTarget {
+        DeployTarget::Docker
+    }
+
+    fn supports(&self, _language: Language) -> bool {
+        true
+    }
+
+    fn config_files(&self, project: &Project) -> Result<Vec<GeneratedFile>, CliError> {
+        docker::docker_files(project)
+    }
+}
+
+impl Deployer for Docker {
+    fn required_files(&self) -> &'static [&'static str] {
+        &["Dockerfile"]
+    }
+
+    fn login_command(&self) -> &'static str {
+        "docker login"
+    }
+
+    fn commands(
+        &self,
+        project: &Project,
+        options: &DeployOptions,
+    ) -> Result<Vec<Vec<String>>, CliError> {
+        // A bare name would be pushed to Docker Hub's official images, which always fails
+        let image = options
+            .image
+            .as_deref()
+            .filter(|image| image.contains('/'))
+            .ok_or_else(|| CliError::MissingImageRegistry(project.name.clone()))?;
+        Ok(vec![
+            command(&["docker", "build", "--tag", image, "."]),
+            command(&["docker", "push", image]),
+        ])
+    }
+
+    /// Reads the repository from `The push refers to repository [docker.io/acme/app]`.
+    fn parse_url(&self, output: &str) -> Option<String> {
+        output
+            .lines()
+            .find_map(|line| line.strip_prefix("The push refers to repository ["))
+            .and_then(|rest| rest.strip_suffix(']'))
+            .map(String::from)
+    }
+}
@@ -0,0 +1,81 @@
+use std::{
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+    process::{Command, Stdio},
+    sync::mpsc,
+    thread,
+};
+
+use crate::errors::CliError;
+
+/// Output of a finished process, stdout and stderr interleaved.
+#[derive(Debug, Clone, Default)]
+pub struct ExecOutput {
+    pub success: bool,
+    pub output: String,
+}
+
+/// Runs external programs on behalf of a deployer.
+///
+/// Abstracted so the provider CLIs can be replaced by canned output.
+pub trait Executor {
+    fn run(&self, command: &[String], dir: &Path) -> Result<ExecOutput, CliError>;
+}
+
+/// Spawns real processes and streams their output to the terminal as it arrives.
+pub struct ProcessExecutor;
+
+impl Executor for ProcessExecutor {
+    fn run(&self, command: &[String], dir: &Path) -> Result<ExecOutput, CliError> {
+        let (program, args) = command.split_first().ok_or(CliError::InvalidInput)?;
+        println!("> {}", command.join(" "));
+
+        let mut child = Command::new(program)
+            .args(args)
+            .current_dir(dir)
+            .stdin(Stdio::inherit())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| match err.kind() {
+                io::ErrorKind::NotFound => CliError::MissingCli(program.clone()),
+                _ => CliError::Io(err),
+            })?;
+
+        let (sender, receiver) = mpsc::channel();
+        let readers: Vec<_> = [
+            child
+                .stdout
+                .take()
+                .map(|s| Box::new(s) as Box<dyn Read + Send>),
+            child
+                .stderr
+                .take()
+                .map(|s| Box::new(s) as Box<dyn Read + Send>),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|stream| {
+            let sender = sender.clone();
+            thread::spawn(move || {
+                for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                    println!("  {line}");
+                    let _ = sender.send(line);
+                }
+            })
+        })
+        .collect();
+        drop(sender);
+
+        let lines: Vec<String> = receiver.iter().collect();
+        for reader in readers {
+            let _ = reader.join();
+        }
+        let status = child.wait()?;
+
+        Ok(ExecOutput {
+            success: status.success(),
+            output: lines.join("\n"),
+        })
+    }
+}
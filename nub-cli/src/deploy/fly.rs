@@ -0,0 +1,102 @@
+use crate::{detect::Project, docker, errors::CliError, template::Language};
+
+use super::{DeployOptions, DeployTarget, Deployer, GeneratedFile, Provider, command, find_url};
+
+/// Fly.io machines, built from the project Dockerfile.
+pub struct Fly;
+
+impl Provider for Fly {
+    fn target(&self) -> DeployTarget {
+        DeployTarget::Fly
+    }
+
+    fn supports(&self, _language: Language) -> bool {
+        true
+    }
+
+    fn config_files(&self, project: &Project) -> Result<Vec<GeneratedFile>, CliError> {
+        let name = app_name(&project.name);
+        let port = project.http_port();
+        let fly_toml = format!(
+            r#"app = "{name}"
+primary_region = "iad"
+
+[build]
+  dockerfile = "Dockerfile"
+
+[http_service]
+  internal_port = {port}
+  force_https = true
+  auto_stop_machines = "stop"
+  auto_start_machines = true
+  min_machines_running = 0
+
+[[vm]]
+  memory = "256mb"
+  cpu_kind = "shared"
+  cpus = 1
+"#
+        );
+
+        let mut files = vec![GeneratedFile::new("fly.toml", fly_toml)];
+        files.extend(docker::docker_files(project)?);
+        Ok(files)
+    }
+}
+
+impl Deployer for Fly {
+    fn required_files(&self) -> &'static [&'static str] {
+        &["fly.toml", "Dockerfile"]
+    }
+
+    fn login_command(&self) -> &'static str {
+        "flyctl auth login"
+    }
+
+    fn commands(
+        &self,
+        _project: &Project,
+        _options: &DeployOptions,
+    ) -> Result<Vec<Vec<String>>, CliError> {
+        Ok(vec![command(&["flyctl", "deploy"])])
+    }
+
+    fn parse_url(&self, output: &str) -> Option<String> {
+        find_url(output, ".fly.dev")
+    }
+}
+
+/// Fly app names only contain lowercase letters, digits and dashes, e.g. `@acme/my_api`
+/// becomes `acme-my-api`.
+fn app_name(name: &str) -> String {
+    let name = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if name.is_empty() {
+        "app".to_string()
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn app_names_follow_fly_rules() {
+        let cases = [
+            ("demo", "demo"),
+            ("My_App", "my-app"),
+            ("@acme/api.server", "acme-api-server"),
+            ("--x--", "x"),
+            ("_", "app"),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(app_name(name), expected, "{name}");
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::CliError;
+
+/// Deploy history, relative to the project root.
+const HISTORY_FILE: &str = ".nub/deployments.json";
+
+/// An entry of the deploy history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentRecord {
+    pub target: String,
+    pub url: Option<String>,
+    pub success: bool,
+    /// RFC 3339 timestamp.
+    pub deployed_at: String,
+}
+
+impl DeploymentRecord {
+    pub fn new(target: String, url: Option<String>, success: bool) -> Self {
+        Self {
+            target,
+            url,
+            success,
+            deployed_at: chrono::Local::now().to_rfc3339(),
+        }
+    }
+
+    pub fn load_all(root: &Path) -> Result<Vec<DeploymentRecord>, CliError> {
+        let path = root.join(HISTORY_FILE);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&path)?;
+        serde_json::from_str(&content)
+            .map_err(|_| CliError::MalformedFile(path.display().to_string()))
+    }
+
+    /// Appends the record to the project deploy history.
+    pub fn save(self, root: &Path) -> Result<(), CliError> {
+        let mut records = Self::load_all(root)?;
+        records.push(self);
+
+        let path = root.join(HISTORY_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(&records)
+            .map_err(|_| CliError::MalformedFile(path.display().to_string()))?;
+        fs::write(&path, content + "\n")?;
+        Ok(())
+    }
+}
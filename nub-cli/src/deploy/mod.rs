@@ -0,0 +1,489 @@
+use std::{fs, path::Path};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::{detect::Project, errors::CliError, template::Language};
+
+mod cloudflare;
+mod docker;
+mod executor;
+mod fly;
+mod history;
+mod vercel;
+
+pub use cloudflare::Cloudflare;
+pub use docker::Docker;
+pub use executor::{Executor, ProcessExecutor};
+pub use fly::Fly;
+pub use history::DeploymentRecord;
+pub use vercel::Vercel;
+
+/// Platforms Nub can generate deployment configurations for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeployTarget {
+    Fly,
+    Cloudflare,
+    Vercel,
+    Docker,
+}
+
+impl std::fmt::Display for DeployTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fly => write!(f, "fly"),
+            Self::Cloudflare => write!(f, "cloudflare"),
+            Self::Vercel => write!(f, "vercel"),
+            Self::Docker => write!(f, "docker"),
+        }
+    }
+}
+
+impl DeployTarget {
+    pub fn provider(&self) -> Box<dyn Provider> {
+        self.deployer()
+    }
+
+    pub fn deployer(&self) -> Box<dyn Deployer> {
+        match self {
+            Self::Fly => Box::new(Fly),
+            Self::Cloudflare => Box::new(Cloudflare),
+            Self::Vercel => Box::new(Vercel),
+            Self::Docker => Box::new(Docker),
+        }
+    }
+
+    /// Finds the target whose configuration file exists in the project, preferring
+    /// platform-specific files over a plain Dockerfile.
+    pub fn detect(root: &Path) -> Option<DeployTarget> {
+        [Self::Fly, Self::Cloudflare, Self::Vercel, Self::Docker]
+            .into_iter()
+            .find(|target| {
+                let files = target.deployer().required_files();
+                !files.is_empty() && files.iter().all(|file| root.join(file).exists())
+            })
+            .or_else(|| {
+                root.join("vercel.json")
+                    .exists()
+                    .then_some(DeployTarget::Vercel)
+            })
+    }
+}
+
+/// A file generated in the project by `nub deploy init`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    /// Path relative to the project root.
+    pub path: String,
+    pub content: String,
+}
+
+impl GeneratedFile {
+    pub fn new(path: &str, content: String) -> Self {
+        Self {
+            path: path.to_string(),
+            content,
+        }
+    }
+}
+
+/// A deployment platform.
+///
+/// Generating configuration is pure so providers can be exercised without network access.
+pub trait Provider {
+    fn target(&self) -> DeployTarget;
+
+    /// Languages the platform can run.
+    fn supports(&self, language: Language) -> bool;
+
+    /// Renders the configuration files needed to deploy the project.
+    fn config_files(&self, project: &Project) -> Result<Vec<GeneratedFile>, CliError>;
+
+    /// Checks support for the project language before rendering its configuration.
+    fn init(&self, project: &Project) -> Result<Vec<GeneratedFile>, CliError> {
+        if !self.supports(project.language) {
+            return Err(CliError::UnsupportedDeployTarget(
+                self.target().to_string(),
+                project.language.to_string(),
+            ));
+        }
+        self.config_files(project)
+    }
+}
+
+/// Options for pushing a project with `nub deploy`.
+#[derive(Debug, Clone, Default)]
+pub struct DeployOptions {
+    /// Image reference for Docker deployments, including the registry to push to.
+    pub image: Option<String>,
+    /// Promotes the deployment to production on platforms with preview deployments.
+    pub production: bool,
+}
+
+/// A successful deployment.
+#[derive(Debug, Clone)]
+pub struct Deployment {
+    pub target: DeployTarget,
+    /// Public URL, or the pushed image reference for Docker.
+    pub url: Option<String>,
+}
+
+/// A platform Nub can push projects to by driving its CLI.
+pub trait Deployer: Provider {
+    /// Files that must exist before deploying, generated by `nub deploy init`.
+    fn required_files(&self) -> &'static [&'static str];
+
+    /// Command the user should run to authenticate with the platform.
+    fn login_command(&self) -> &'static str;
+
+    /// CLI invocations pushing the project, run in order.
+    fn commands(
+        &self,
+        project: &Project,
+        options: &DeployOptions,
+    ) -> Result<Vec<Vec<String>>, CliError>;
+
+    /// Extracts the deployed URL from the CLI output.
+    fn parse_url(&self, output: &str) -> Option<String>;
+
+    fn deploy(
+        &self,
+        project: &Project,
+        options: &DeployOptions,
+        executor: &dyn Executor,
+    ) -> Result<Deployment, CliError> {
+        let target = self.target();
+        if let Some(file) = self
+            .required_files()
+            .iter()
+            .find(|file| !project.root.join(file).exists())
+        {
+            return Err(CliError::MissingDeployConfig(
+                file.to_string(),
+                target.to_string(),
+            ));
+        }
+
+        let mut url = None;
+        for command in self.commands(project, options)? {
+            let result = executor.run(&command, &project.root)?;
+            if !result.success {
+                if is_auth_failure(&result.output) {
+                    return Err(CliError::DeployAuth(
+                        target.to_string(),
+                        self.login_command().to_string(),
+                    ));
+                }
+                return Err(CliError::DeployBuild(target.to_string(), command.join(" ")));
+            }
+            url = self.parse_url(&result.output).or(url);
+        }
+
+        Ok(Deployment { target, url })
+    }
+}
+
+fn is_auth_failure(output: &str) -> bool {
+    let output = output.to_lowercase();
+    [
+        "not logged in",
+        "not authenticated",
+        "unauthorized",
+        "authentication required",
+        "no access token",
+        "please login",
+        "please log in",
+        "denied: requested access",
+    ]
+    .iter()
+    .any(|pattern| output.contains(pattern))
+}
+
+/// Finds the first `https://` URL whose host ends with `suffix`.
+fn find_url(output: &str, suffix: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .filter_map(|word| word.find("https://").map(|start| &word[start..]))
+        .map(|url| url.trim_end_matches(|c: char| !c.is_alphanumeric() && c != '/'))
+        .find(|url| {
+            url.trim_start_matches("https://")
+                .split('/')
+                .next()
+                .is_some_and(|host| host.ends_with(suffix))
+        })
+        .map(String::from)
+}
+
+fn command(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+/// Writes the generated files, keeping existing ones unless `force` is set.
+pub fn write_files(root: &Path, files: &[GeneratedFile], force: bool) -> Result<(), CliError> {
+    for file in files {
+        let path = root.join(&file.path);
+        if path.exists() && !force {
+            println!("> Skipped {} (already exists)", file.path);
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &file.content)?;
+        println!("> Created {}", file.path);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::VecDeque, path::PathBuf};
+
+    use super::*;
+    use crate::{deploy::executor::ExecOutput, detect::PackageManager};
+
+    fn project(language: Language, template: Option<&str>) -> Project {
+        Project {
+            root: PathBuf::from("/nonexistent"),
+            language,
+            name: "demo".to_string(),
+            package_manager: PackageManager::Bun,
+            workspace: None,
+            license: None,
+            template: template.map(String::from),
+        }
+    }
+
+    fn file<'a>(files: &'a [GeneratedFile], path: &str) -> &'a GeneratedFile {
+        files
+            .iter()
+            .find(|file| file.path == path)
+            .unwrap_or_else(|| panic!("{path} not generated"))
+    }
+
+    #[test]
+    fn fly_config_names_the_app_and_port() {
+        let files = Fly
+            .init(&project(Language::Go, Some("chi-http-server")))
+            .unwrap();
+        let table: toml::Table = file(&files, "fly.toml").content.parse().unwrap();
+        assert_eq!(table["app"].as_str(), Some("demo"));
+        assert_eq!(
+            table["http_service"]["internal_port"].as_integer(),
+            Some(3000)
+        );
+        assert!(!file(&files, "Dockerfile").content.is_empty());
+    }
+
+    #[test]
+    fn vercel_config_pins_bun() {
+        let files = Vercel
+            .init(&project(Language::Typescript, Some("bun-blank")))
+            .unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&file(&files, "vercel.json").content).unwrap();
+        assert_eq!(json["bunVersion"], "1.x");
+    }
+
+    #[test]
+    fn cloudflare_config_points_at_the_entry() {
+        let files = Cloudflare
+            .init(&project(Language::Typescript, None))
+            .unwrap();
+        let table: toml::Table = file(&files, "wrangler.toml").content.parse().unwrap();
+        assert_eq!(table["name"].as_str(), Some("demo"));
+        assert_eq!(table["main"].as_str(), Some("index.ts"));
+        assert!(table["compatibility_date"].as_str().is_some());
+    }
+
+    #[test]
+    fn unsupported_languages_are_rejected() {
+        for provider in [DeployTarget::Vercel, DeployTarget::Cloudflare].map(|t| t.provider()) {
+            assert!(matches!(
+                provider.init(&project(Language::Rust, None)),
+                Err(CliError::UnsupportedDeployTarget(..))
+            ));
+        }
+    }
+
+    #[test]
+    fn ports_follow_the_template() {
+        assert_eq!(
+            project(Language::Go, Some("chi-http-server")).http_port(),
+            3000
+        );
+        assert_eq!(
+            project(Language::Rust, Some("axum-basic")).http_port(),
+            8080
+        );
+        assert_eq!(project(Language::Rust, None).http_port(), 8080);
+    }
+
+    /// Replays canned outputs and records the commands it was asked to run.
+    #[derive(Default)]
+    struct FakeExecutor {
+        outputs: RefCell<VecDeque<ExecOutput>>,
+        commands: RefCell<Vec<String>>,
+    }
+
+    impl FakeExecutor {
+        fn new(outputs: &[(bool, &str)]) -> Self {
+            Self {
+                outputs: RefCell::new(
+                    outputs
+                        .iter()
+                        .map(|(success, output)| ExecOutput {
+                            success: *success,
+                            output: output.to_string(),
+                        })
+                        .collect(),
+                ),
+                commands: RefCell::default(),
+            }
+        }
+    }
+
+    impl Executor for FakeExecutor {
+        fn run(&self, command: &[String], _dir: &Path) -> Result<ExecOutput, CliError> {
+            self.commands.borrow_mut().push(command.join(" "));
+            Ok(self.outputs.borrow_mut().pop_front().unwrap_or_default())
+        }
+    }
+
+    fn project_with_files(files: &[&str]) -> (tempfile::TempDir, Project) {
+        let dir = tempfile::tempdir().unwrap();
+        for file in files {
+            fs::write(dir.path().join(file), "").unwrap();
+        }
+        let project = Project {
+            root: dir.path().to_path_buf(),
+            ..project(Language::Typescript, None)
+        };
+        (dir, project)
+    }
+
+    #[test]
+    fn detects_auth_failures() {
+        let cases = [
+            ("Error: Not logged in, run `flyctl auth login`", true),
+            ("Error: You are not authenticated", true),
+            ("HTTP 401 Unauthorized", true),
+            ("denied: requested access to the resource is denied", true),
+            ("error: failed to compile", false),
+            ("", false),
+        ];
+        for (output, expected) in cases {
+            assert_eq!(is_auth_failure(output), expected, "{output}");
+        }
+    }
+
+    #[test]
+    fn finds_urls_by_host_suffix() {
+        let cases = [
+            (
+                "Visit your app at https://demo.fly.dev/",
+                ".fly.dev",
+                Some("https://demo.fly.dev/"),
+            ),
+            (
+                "(https://demo.fly.dev).",
+                ".fly.dev",
+                Some("https://demo.fly.dev"),
+            ),
+            (
+                "Inspect: https://vercel.com/x Preview: https://demo-abc.vercel.app",
+                ".vercel.app",
+                Some("https://demo-abc.vercel.app"),
+            ),
+            ("https://demo.fly.dev.evil.com", ".fly.dev", None),
+            ("no url here", ".fly.dev", None),
+        ];
+        for (output, suffix, expected) in cases {
+            assert_eq!(find_url(output, suffix).as_deref(), expected, "{output}");
+        }
+    }
+
+    #[test]
+    fn deploy_requires_the_config_files() {
+        let (_dir, project) = project_with_files(&["fly.toml"]);
+        let executor = FakeExecutor::new(&[]);
+        let err = Fly
+            .deploy(&project, &DeployOptions::default(), &executor)
+            .unwrap_err();
+        assert!(
+            matches!(&err, CliError::MissingDeployConfig(file, target) if file == "Dockerfile" && target == "fly")
+        );
+        assert!(executor.commands.borrow().is_empty());
+    }
+
+    #[test]
+    fn deploy_returns_the_parsed_url() {
+        let (_dir, project) = project_with_files(&["fly.toml", "Dockerfile"]);
+        let executor = FakeExecutor::new(&[(
+            true,
+            "Visit your newly deployed app at https://demo.fly.dev/",
+        )]);
+        let deployment = Fly
+            .deploy(&project, &DeployOptions::default(), &executor)
+            .unwrap();
+        assert_eq!(deployment.target, DeployTarget::Fly);
+        assert_eq!(deployment.url.as_deref(), Some("https://demo.fly.dev/"));
+        assert_eq!(*executor.commands.borrow(), ["flyctl deploy"]);
+    }
+
+    #[test]
+    fn deploy_maps_auth_failures() {
+        let (_dir, project) = project_with_files(&[]);
+        let executor =
+            FakeExecutor::new(&[(false, "Error: No existing credentials found. Please log in")]);
+        let err = Vercel
+            .deploy(&project, &DeployOptions::default(), &executor)
+            .unwrap_err();
+        assert!(
+            matches!(&err, CliError::DeployAuth(target, login) if target == "vercel" && login == "vercel login")
+        );
+    }
+
+    #[test]
+    fn deploy_maps_other_failures_to_build_errors() {
+        let (_dir, project) = project_with_files(&[]);
+        let executor = FakeExecutor::new(&[(false, "Error: build failed")]);
+        let options = DeployOptions {
+            production: true,
+            ..DeployOptions::default()
+        };
+        let err = Vercel.deploy(&project, &options, &executor).unwrap_err();
+        assert!(
+            matches!(&err, CliError::DeployBuild(target, command) if target == "vercel" && command == "vercel deploy --yes --prod")
+        );
+    }
+
+    #[test]
+    fn deploy_stops_at_the_first_failing_command() {
+        let (_dir, project) = project_with_files(&["Dockerfile"]);
+        let executor = FakeExecutor::new(&[(false, "error: build failed")]);
+        let options = DeployOptions {
+            image: Some("ghcr.io/acme/demo".to_string()),
+            ..DeployOptions::default()
+        };
+        let err = Docker.deploy(&project, &options, &executor).unwrap_err();
+        assert!(matches!(err, CliError::DeployBuild(..)));
+        assert_eq!(executor.commands.borrow().len(), 1);
+    }
+
+    #[test]
+    fn docker_deploys_need_a_registry() {
+        let (_dir, project) = project_with_files(&["Dockerfile"]);
+        for image in [None, Some("demo")] {
+            let executor = FakeExecutor::new(&[]);
+            let options = DeployOptions {
+                image: image.map(String::from),
+                ..DeployOptions::default()
+            };
+            let err = Docker.deploy(&project, &options, &executor).unwrap_err();
+            assert!(matches!(err, CliError::MissingImageRegistry(_)));
+            assert!(executor.commands.borrow().is_empty());
+        }
+    }
+}
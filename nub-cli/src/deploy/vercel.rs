@@ -0,0 +1,56 @@
+use crate::{detect::Project, errors::CliError, template::Language};
+
+use super::{DeployOptions, DeployTarget, Deployer, GeneratedFile, Provider, command, find_url};
+
+/// Vercel functions, running TypeScript on the Bun runtime.
+pub struct Vercel;
+
+impl Provider for Vercel {
+    fn target(&self) -> DeployTarget {
+        DeployTarget::Vercel
+    }
+
+    fn supports(&self, language: Language) -> bool {
+        language == Language::Typescript
+    }
+
+    fn config_files(&self, _project: &Project) -> Result<Vec<GeneratedFile>, CliError> {
+        let vercel_json = r#"{
+  "$schema": "https://openapi.vercel.sh/vercel.json",
+  "bunVersion": "1.x"
+}
+"#;
+
+        Ok(vec![GeneratedFile::new(
+            "vercel.json",
+            vercel_json.to_string(),
+        )])
+    }
+}
+
+impl Deployer for Vercel {
+    fn required_files(&self) -> &'static [&'static str] {
+        // Vercel deploys without configuration, vercel.json only pins the Bun runtime
+        &[]
+    }
+
+    fn login_command(&self) -> &'static str {
+        "vercel login"
+    }
+
+    fn commands(
+        &self,
+        _project: &Project,
+        options: &DeployOptions,
+    ) -> Result<Vec<Vec<String>>, CliError> {
+        let mut deploy = command(&["vercel", "deploy", "--yes"]);
+        if options.production {
+            deploy.push("--prod".to_string());
+        }
+        Ok(vec![deploy])
+    }
+
+    fn parse_url(&self, output: &str) -> Option<String> {
+        find_url(output, ".vercel.app")
+    }
+}
@@ -0,0 +1,679 @@
+mod package_manager;
+mod workspace;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub use package_manager::{JAVASCRIPT_MANAGERS, PackageManager, uses_bun_runtime};
+pub use workspace::Workspace;
+
+use crate::{
+    errors::CliError,
+    provenance::Provenance,
+    template::{self, Language},
+};
+
+/// An existing project found on disk.
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub root: PathBuf,
+    pub language: Language,
+    /// Package name, or the last element of the module path for Go.
+    pub name: String,
+    pub package_manager: PackageManager,
+    /// Members of the workspace declared at the project root, if any.
+    pub workspace: Option<Workspace>,
+    /// SPDX identifier declared in the manifest, e.g. `MIT`.
+    pub license: Option<String>,
+    /// The Nub template the project most likely originated from.
+    pub template: Option<String>,
+}
+
+/// Detects the project rooted at `dir` from its manifest file.
+///
+/// The template recorded when nub generated the project wins over the one guessed from
+/// its dependencies.
+pub fn detect(dir: &Path) -> Result<Project, CliError> {
+    let mut project = detect_manifest(dir)?;
+    if let Some(template) = Provenance::load(dir)
+        .ok()
+        .flatten()
+        .and_then(|provenance| provenance.template())
+    {
+        project.template = Some(template.name);
+    }
+    Ok(project)
+}
+
+fn detect_manifest(dir: &Path) -> Result<Project, CliError> {
+    let not_found = || CliError::ProjectNotFound(dir.display().to_string());
+
+    if let Some(manifest) = read(dir, "Cargo.toml") {
+        let table: toml::Table = manifest.parse().map_err(|_| not_found())?;
+        let package = table.get("package");
+        // Virtual workspace manifests have no package, they are named after their directory
+        let name = package
+            .and_then(|package| package.get("name"))
+            .and_then(|name| name.as_str())
+            .or_else(|| {
+                table
+                    .contains_key("workspace")
+                    .then(|| dir.file_name()?.to_str())
+                    .flatten()
+            })
+            .ok_or_else(not_found)?
+            .to_string();
+        let dependencies = table.get("dependencies").and_then(|deps| deps.as_table());
+        let depends_on = |krate: &str| dependencies.is_some_and(|deps| deps.contains_key(krate));
+        let proc_macro = table
+            .get("lib")
+            .and_then(|lib| lib.get("proc-macro"))
+            .and_then(|proc_macro| proc_macro.as_bool())
+            .unwrap_or(false);
+        let template = if package.is_none() {
+            Some("workspace")
+        } else if proc_macro {
+            Some("proc-macro")
+        } else if depends_on("wasm-bindgen") {
+            Some("wasm-bindgen")
+        } else if depends_on("axum") && depends_on("reqwest") {
+            Some("axum-openai")
+        } else if depends_on("axum") {
+            Some("axum-basic")
+        } else if depends_on("clap") {
+            Some("cli-clap")
+        } else {
+            None
+        };
+        return Ok(Project {
+            root: dir.to_path_buf(),
+            language: Language::Rust,
+            name,
+            package_manager: PackageManager::Cargo,
+            workspace: Workspace::cargo(dir, &table),
+            license: package
+                .and_then(|package| package.get("license"))
+                .and_then(|license| license.as_str())
+                .map(String::from),
+            template: template.map(String::from),
+        });
+    }
+
+    if let Some(manifest) = read(dir, "go.mod") {
+        let module = manifest
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("module "))
+            .ok_or(CliError::MalformedGoMod)?
+            .trim();
+        let name = module.rsplit('/').next().unwrap_or(module).to_string();
+        let template = if manifest.contains("connectrpc.com/connect") {
+            Some("connect-grpc")
+        } else if manifest.contains("github.com/spf13/cobra") {
+            Some("cobra-cli")
+        } else if !manifest.contains("github.com/go-chi/chi") {
+            None
+        } else if dir.join("rag").is_dir() {
+            Some("chi-rag")
+        } else {
+            Some("chi-http-server")
+        };
+        return Ok(Project {
+            root: dir.to_path_buf(),
+            language: Language::Go,
+            name,
+            package_manager: PackageManager::Go,
+            workspace: Workspace::go(dir),
+            license: None,
+            template: template.map(String::from),
+        });
+    }
+
+    // A Bun lockfile without a manifest still marks a TypeScript project
+    let manifest = read(dir, "package.json");
+    if manifest.is_some() || dir.join("bun.lockb").exists() {
+        let json: serde_json::Value = match manifest {
+            Some(manifest) => serde_json::from_str(&manifest).map_err(|_| not_found())?,
+            None => serde_json::Value::Null,
+        };
+        // Private workspace roots often have no name
+        let name = json
+            .get("name")
+            .and_then(|name| name.as_str())
+            .or_else(|| dir.file_name()?.to_str())
+            .ok_or_else(not_found)?
+            .to_string();
+        let package_manager = PackageManager::detect(dir, Language::Typescript, &json);
+        // Bun templates keep running on Bun when generated for another package manager
+        let template = if package_manager != PackageManager::Bun && !uses_bun_runtime(&json) {
+            None
+        } else if json.get("workspaces").is_some() {
+            Some("monorepo")
+        } else if json.pointer("/dependencies/hono").is_some() {
+            Some("hono-server")
+        } else if dir.join("src").join("agent.ts").exists() {
+            Some("bun-agent")
+        } else {
+            Some("bun-blank")
+        };
+        return Ok(Project {
+            root: dir.to_path_buf(),
+            language: Language::Typescript,
+            name,
+            package_manager,
+            workspace: Workspace::javascript(dir, &json),
+            license: json
+                .get("license")
+                .and_then(|license| license.as_str())
+                .map(String::from),
+            template: template.map(String::from),
+        });
+    }
+
+    if let Some(manifest) = read(dir, "pyproject.toml") {
+        let table: toml::Table = manifest.parse().map_err(|_| not_found())?;
+        let project = table.get("project");
+        // uv workspace roots may only declare `[tool.uv.workspace]`
+        let name = project
+            .and_then(|project| project.get("name"))
+            .and_then(|name| name.as_str())
+            .or_else(|| dir.file_name()?.to_str())
+            .ok_or_else(not_found)?
+            .to_string();
+        let package_manager =
+            PackageManager::detect(dir, Language::Python, &serde_json::Value::Null);
+        let template = (package_manager == PackageManager::Uv).then_some("blank");
+        // PEP 639 declares a plain SPDX string, older projects a `{ text = "..." }` table
+        let license = project
+            .and_then(|project| project.get("license"))
+            .and_then(|license| license.as_str().or_else(|| license.get("text")?.as_str()))
+            .map(String::from);
+        return Ok(Project {
+            root: dir.to_path_buf(),
+            language: Language::Python,
+            name,
+            package_manager,
+            workspace: Workspace::uv(dir, &table),
+            license,
+            template: template.map(String::from),
+        });
+    }
+
+    if let Some(manifest) = read(dir, "build.zig.zon") {
+        // `.name = .foo` since Zig 0.14, `.name = "foo"` before
+        let name = manifest
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(".name"))
+            .and_then(|rest| rest.trim_start().strip_prefix('='))
+            .map(|value| value.trim().trim_end_matches(',').trim_matches(['.', '"']))
+            .filter(|name| !name.is_empty())
+            .or_else(|| dir.file_name()?.to_str())
+            .ok_or_else(not_found)?
+            .to_string();
+        let license = manifest
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(".license"))
+            .and_then(|rest| rest.trim_start().strip_prefix('='))
+            .map(|value| {
+                value
+                    .trim()
+                    .trim_end_matches(',')
+                    .trim_matches('"')
+                    .to_string()
+            });
+        return Ok(Project {
+            root: dir.to_path_buf(),
+            language: Language::Zig,
+            name,
+            package_manager: PackageManager::Zig,
+            workspace: None,
+            license,
+            template: Some("blank".to_string()),
+        });
+    }
+
+    if let Some(manifest) = read(dir, "CMakeLists.txt") {
+        let name = manifest
+            .lines()
+            .find_map(|line| {
+                let line = line.trim();
+                let rest = line
+                    .strip_prefix("project(")
+                    .or_else(|| line.strip_prefix("project ("))?;
+                rest.split([' ', ')'])
+                    .next()
+                    .filter(|name| !name.is_empty())
+            })
+            .or_else(|| dir.file_name()?.to_str())
+            .ok_or_else(not_found)?
+            .to_string();
+        let template = (dir.join("tests").is_dir() && manifest.contains("enable_testing()"))
+            .then_some("blank");
+        return Ok(Project {
+            root: dir.to_path_buf(),
+            language: Language::Cpp,
+            name,
+            package_manager: PackageManager::Cmake,
+            workspace: None,
+            license: None,
+            template: template.map(String::from),
+        });
+    }
+
+    Err(not_found())
+}
+
+/// Detects the project containing `dir`, looking in its parent directories when needed.
+pub fn detect_nearest(dir: &Path) -> Result<Project, CliError> {
+    for ancestor in dir.ancestors() {
+        match detect(ancestor) {
+            Err(CliError::ProjectNotFound(_)) => continue,
+            result => return result,
+        }
+    }
+    Err(CliError::ProjectNotFound(dir.display().to_string()))
+}
+
+impl Project {
+    /// Port the server listens on, as hard-coded in the originating template.
+    pub fn http_port(&self) -> u16 {
+        match self.template.as_deref() {
+            Some("chi-http-server" | "chi-rag" | "bun-blank" | "bun-agent" | "hono-server") => 3000,
+            _ => 8080,
+        }
+    }
+
+    /// Entry point of a TypeScript project, read from the `module` or `main` field of `package.json`.
+    pub fn typescript_entry(&self) -> String {
+        read(&self.root, "package.json")
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|json| {
+                ["module", "main"]
+                    .iter()
+                    .find_map(|field| json.get(field)?.as_str().map(String::from))
+            })
+            .unwrap_or("index.ts".to_string())
+    }
+
+    /// Package holding the Go main function, the only command under `cmd/` or the module root.
+    pub fn go_main_package(&self) -> String {
+        let commands: Vec<_> = fs::read_dir(self.root.join("cmd"))
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().is_dir())
+                    .filter_map(|entry| entry.file_name().to_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        match commands.as_slice() {
+            [command] => format!("./cmd/{command}"),
+            _ => ".".to_string(),
+        }
+    }
+
+    /// Name of the binary built by Cargo, the first `[[bin]]` target or the package name.
+    ///
+    /// Virtual workspace roots have no package, so their binary is the only one built by
+    /// the workspace members.
+    pub fn rust_binary(&self) -> Result<String, CliError> {
+        let manifest: toml::Table = read(&self.root, "Cargo.toml")
+            .and_then(|content| content.parse().ok())
+            .unwrap_or_default();
+        if manifest.contains_key("package") || !manifest.contains_key("workspace") {
+            return Ok(first_bin(&manifest).unwrap_or(self.name.clone()));
+        }
+
+        let binaries: Vec<String> = self
+            .workspace
+            .iter()
+            .flat_map(|workspace| &workspace.members)
+            .filter_map(|member| {
+                let dir = self.root.join(member);
+                let manifest: toml::Table = read(&dir, "Cargo.toml")?.parse().ok()?;
+                let package = manifest.get("package")?.get("name")?.as_str()?;
+                first_bin(&manifest).or_else(|| {
+                    dir.join("src")
+                        .join("main.rs")
+                        .exists()
+                        .then(|| package.to_string())
+                })
+            })
+            .collect();
+        match binaries.as_slice() {
+            [binary] => Ok(binary.clone()),
+            [] => Err(CliError::NoWorkspaceBinary(self.name.clone())),
+            _ => Err(CliError::AmbiguousWorkspaceBinary(
+                self.name.clone(),
+                binaries.join(", "),
+            )),
+        }
+    }
+
+    /// Importable module of a Python project, the package name with dashes replaced.
+    pub fn python_module(&self) -> String {
+        template::python_module(&self.name)
+    }
+}
+
+/// Directories never searched for nested projects.
+const IGNORED_DIRS: &[&str] = &[
+    "target",
+    "node_modules",
+    "vendor",
+    "dist",
+    "build",
+    "zig-out",
+    "__pycache__",
+];
+
+/// How deep [`find_projects`] looks for nested projects.
+const MAX_DEPTH: usize = 3;
+
+/// Finds the projects in `root` and its subdirectories, for monorepos mixing languages.
+///
+/// Directories nested in a project of the same language are left out since its own
+/// tooling covers them, as with Cargo workspaces, Go packages or npm workspaces.
+pub fn find_projects(root: &Path) -> Vec<Project> {
+    let mut projects = Vec::new();
+    collect_projects(root, 0, &mut Vec::new(), &mut projects);
+    projects
+}
+
+fn collect_projects(
+    dir: &Path,
+    depth: usize,
+    enclosing: &mut Vec<Language>,
+    projects: &mut Vec<Project>,
+) {
+    let found = detect(dir)
+        .ok()
+        .filter(|project| !enclosing.contains(&project.language));
+    let pushed = found.as_ref().map(|project| project.language);
+    if let Some(project) = found {
+        projects.push(project);
+    }
+
+    if depth < MAX_DEPTH {
+        let mut subdirs: Vec<PathBuf> = fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+                    .filter(|entry| {
+                        entry.file_name().to_str().is_some_and(|name| {
+                            !name.starts_with('.') && !IGNORED_DIRS.contains(&name)
+                        })
+                    })
+                    .map(|entry| entry.path())
+                    .collect()
+            })
+            .unwrap_or_default();
+        subdirs.sort();
+
+        enclosing.extend(pushed);
+        for subdir in subdirs {
+            collect_projects(&subdir, depth + 1, enclosing, projects);
+        }
+        if pushed.is_some() {
+            enclosing.pop();
+        }
+    }
+}
+
+/// Name of the first `[[bin]]` target of a Cargo manifest.
+fn first_bin(manifest: &toml::Table) -> Option<String> {
+    manifest
+        .get("bin")?
+        .as_array()?
+        .first()?
+        .get("name")?
+        .as_str()
+        .map(String::from)
+}
+
+fn read(dir: &Path, file_name: &str) -> Option<String> {
+    fs::read_to_string(dir.join(file_name)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_files(dir: &Path, files: &[(&str, &str)]) {
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+    }
+
+    /// Files of a project, then its expected language, name, package manager and template.
+    type Case = (
+        &'static [(&'static str, &'static str)],
+        Language,
+        &'static str,
+        PackageManager,
+        Option<&'static str>,
+    );
+
+    #[test]
+    fn detects_each_manifest() {
+        let cases: &[Case] = &[
+            (
+                &[(
+                    "Cargo.toml",
+                    "[package]\nname = \"api\"\nlicense = \"MIT\"\n\n[dependencies]\naxum = \"0.8\"\n",
+                )],
+                Language::Rust,
+                "api",
+                PackageManager::Cargo,
+                Some("axum-basic"),
+            ),
+            (
+                &[(
+                    "Cargo.toml",
+                    "[package]\nname = \"tool\"\n\n[dependencies]\nclap = \"4\"\n",
+                )],
+                Language::Rust,
+                "tool",
+                PackageManager::Cargo,
+                Some("cli-clap"),
+            ),
+            (
+                &[("Cargo.toml", "[workspace]\nmembers = []\n")],
+                Language::Rust,
+                "demo",
+                PackageManager::Cargo,
+                Some("workspace"),
+            ),
+            (
+                &[(
+                    "go.mod",
+                    "module github.com/acme/server\n\nrequire github.com/go-chi/chi/v5 v5.2.2\n",
+                )],
+                Language::Go,
+                "server",
+                PackageManager::Go,
+                Some("chi-http-server"),
+            ),
+            (
+                &[("go.mod", "module example.com/plain\n")],
+                Language::Go,
+                "plain",
+                PackageManager::Go,
+                None,
+            ),
+            (
+                &[
+                    ("package.json", r#"{ "name": "web" }"#),
+                    ("pnpm-lock.yaml", ""),
+                ],
+                Language::Typescript,
+                "web",
+                PackageManager::Pnpm,
+                None,
+            ),
+            (
+                &[
+                    (
+                        "package.json",
+                        r#"{ "name": "server", "dependencies": { "hono": "^4" } }"#,
+                    ),
+                    ("bun.lock", ""),
+                ],
+                Language::Typescript,
+                "server",
+                PackageManager::Bun,
+                Some("hono-server"),
+            ),
+            (
+                &[("bun.lockb", "")],
+                Language::Typescript,
+                "demo",
+                PackageManager::Bun,
+                Some("bun-blank"),
+            ),
+            (
+                &[(
+                    "pyproject.toml",
+                    "[project]\nname = \"model\"\nlicense = { text = \"MIT\" }\n",
+                )],
+                Language::Python,
+                "model",
+                PackageManager::Uv,
+                Some("blank"),
+            ),
+            (
+                &[
+                    ("pyproject.toml", "[project]\nname = \"legacy\"\n"),
+                    ("requirements.txt", "requests\n"),
+                ],
+                Language::Python,
+                "legacy",
+                PackageManager::Pip,
+                None,
+            ),
+            (
+                &[("build.zig.zon", ".{\n    .name = .game,\n}\n")],
+                Language::Zig,
+                "game",
+                PackageManager::Zig,
+                Some("blank"),
+            ),
+            (
+                &[
+                    ("CMakeLists.txt", "project(engine CXX)\nenable_testing()\n"),
+                    ("tests/main.cpp", ""),
+                ],
+                Language::Cpp,
+                "engine",
+                PackageManager::Cmake,
+                Some("blank"),
+            ),
+        ];
+
+        for (files, language, name, package_manager, template) in cases {
+            let dir = tempfile::tempdir().unwrap();
+            let root = dir.path().join("demo");
+            write_files(&root, files);
+
+            let project = detect(&root).unwrap();
+            assert_eq!(project.language, *language, "{files:?}");
+            assert_eq!(project.name, *name, "{files:?}");
+            assert_eq!(project.package_manager, *package_manager, "{files:?}");
+            assert_eq!(project.template.as_deref(), *template, "{files:?}");
+        }
+    }
+
+    #[test]
+    fn reads_the_declared_license() {
+        let cases: &[(&str, &str)] = &[
+            (
+                "Cargo.toml",
+                "[package]\nname = \"api\"\nlicense = \"MIT\"\n",
+            ),
+            ("package.json", r#"{ "name": "web", "license": "MIT" }"#),
+            (
+                "pyproject.toml",
+                "[project]\nname = \"model\"\nlicense = \"MIT\"\n",
+            ),
+            (
+                "build.zig.zon",
+                ".{\n    .name = .game,\n    .license = \"MIT\",\n}\n",
+            ),
+        ];
+
+        for (manifest, content) in cases {
+            let dir = tempfile::tempdir().unwrap();
+            write_files(dir.path(), &[(manifest, content)]);
+            let project = detect(dir.path()).unwrap();
+            assert_eq!(project.license.as_deref(), Some("MIT"), "{manifest}");
+        }
+    }
+
+    #[test]
+    fn directories_without_a_manifest_are_not_projects() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            detect(dir.path()),
+            Err(CliError::ProjectNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn detect_nearest_looks_in_parent_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        write_files(
+            dir.path(),
+            &[
+                ("go.mod", "module example.com/api\n"),
+                ("internal/handlers/handlers.go", ""),
+            ],
+        );
+
+        let project = detect_nearest(&dir.path().join("internal/handlers")).unwrap();
+        assert_eq!(project.root, dir.path());
+        assert_eq!(project.name, "api");
+    }
+
+    #[test]
+    fn find_projects_stops_at_the_depth_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        write_files(
+            dir.path(),
+            &[
+                ("a/b/c/go.mod", "module example.com/deep\n"),
+                ("a/b/c/d/Cargo.toml", "[package]\nname = \"deeper\"\n"),
+                ("web/node_modules/dep/package.json", r#"{ "name": "dep" }"#),
+            ],
+        );
+
+        let names: Vec<String> = find_projects(dir.path())
+            .into_iter()
+            .map(|project| project.name)
+            .collect();
+        assert_eq!(names, ["deep"]);
+    }
+
+    #[test]
+    fn find_projects_skips_members_of_a_project_of_the_same_language() {
+        let dir = tempfile::tempdir().unwrap();
+        write_files(
+            dir.path(),
+            &[
+                ("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n"),
+                ("crates/core/Cargo.toml", "[package]\nname = \"core\"\n"),
+                ("web/package.json", r#"{ "name": "web" }"#),
+            ],
+        );
+
+        let languages: Vec<Language> = find_projects(dir.path())
+            .into_iter()
+            .map(|project| project.language)
+            .collect();
+        assert_eq!(languages, [Language::Rust, Language::Typescript]);
+    }
+}
@@ -0,0 +1,361 @@
+use std::{fmt, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::Language;
+
+/// Tool installing the dependencies of a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageManager {
+    Cargo,
+    Go,
+    Uv,
+    Pip,
+    Zig,
+    Cmake,
+    Bun,
+    Pnpm,
+    Yarn,
+    Npm,
+}
+
+/// Lockfiles written by the JavaScript package managers, checked in this order.
+const LOCKFILES: &[(&str, PackageManager)] = &[
+    ("bun.lock", PackageManager::Bun),
+    ("bun.lockb", PackageManager::Bun),
+    ("pnpm-lock.yaml", PackageManager::Pnpm),
+    ("yarn.lock", PackageManager::Yarn),
+    ("package-lock.json", PackageManager::Npm),
+];
+
+/// Names accepted by `--pm`.
+pub const JAVASCRIPT_MANAGERS: &[&str] = &["bun", "pnpm", "npm", "yarn"];
+
+impl PackageManager {
+    /// Detects the package manager of the project rooted at `dir`.
+    ///
+    /// JavaScript projects are identified by the `packageManager` field of `package.json`,
+    /// then by their lockfile, then by the Bun type definitions, defaulting to npm. Python
+    /// projects use uv when locked with it or declaring `[tool.uv]`, pip otherwise.
+    pub fn detect(dir: &Path, language: Language, package: &serde_json::Value) -> Self {
+        match language {
+            Language::Cpp => Self::Cmake,
+            Language::Go => Self::Go,
+            Language::Python => {
+                let uses_uv = dir.join("uv.lock").exists()
+                    || std::fs::read_to_string(dir.join("pyproject.toml"))
+                        .is_ok_and(|content| content.contains("[tool.uv"));
+                if uses_uv || !dir.join("requirements.txt").exists() {
+                    Self::Uv
+                } else {
+                    Self::Pip
+                }
+            }
+            Language::Rust => Self::Cargo,
+            Language::Zig => Self::Zig,
+            Language::Typescript => Self::declared(package)
+                .or_else(|| {
+                    LOCKFILES
+                        .iter()
+                        .find(|(lockfile, _)| dir.join(lockfile).exists())
+                        .map(|(_, manager)| *manager)
+                })
+                .unwrap_or_else(|| Self::fallback(package)),
+        }
+    }
+
+    /// The package manager of a `package.json` alone, as for templates which have no lockfile.
+    pub fn from_package_json(package: &serde_json::Value) -> Self {
+        Self::declared(package).unwrap_or_else(|| Self::fallback(package))
+    }
+
+    /// Reads the `packageManager` field, e.g. `pnpm@10.13.1`.
+    fn declared(package: &serde_json::Value) -> Option<Self> {
+        let field = package.get("packageManager")?.as_str()?;
+        Self::from_name(field.split('@').next()?)
+    }
+
+    fn fallback(package: &serde_json::Value) -> Self {
+        if uses_bun_runtime(package) {
+            Self::Bun
+        } else {
+            Self::Npm
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "bun" => Some(Self::Bun),
+            "pnpm" => Some(Self::Pnpm),
+            "yarn" => Some(Self::Yarn),
+            "npm" => Some(Self::Npm),
+            _ => None,
+        }
+    }
+
+    /// Whether the manager installs JavaScript dependencies, the only ones `--pm` accepts.
+    pub fn is_javascript(&self) -> bool {
+        matches!(self, Self::Bun | Self::Pnpm | Self::Yarn | Self::Npm)
+    }
+
+    /// Version written to the `packageManager` field of generated projects, which Corepack
+    /// requires to be exact. Only JavaScript managers have one.
+    pub fn pinned_version(&self) -> Option<&'static str> {
+        match self {
+            Self::Bun => Some("1.2.19"),
+            Self::Pnpm => Some("10.13.1"),
+            Self::Yarn => Some("4.9.2"),
+            Self::Npm => Some("11.4.2"),
+            Self::Cargo | Self::Go | Self::Uv | Self::Pip | Self::Zig | Self::Cmake => None,
+        }
+    }
+
+    /// Command running a binary from the dependencies, e.g. `npx` or `bunx`, for the
+    /// JavaScript and Python managers.
+    pub fn executor(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::Bun => Some(&["bunx"]),
+            Self::Pnpm => Some(&["pnpm", "exec"]),
+            Self::Yarn => Some(&["yarn"]),
+            Self::Npm => Some(&["npx"]),
+            Self::Uv => Some(&["uv", "run"]),
+            Self::Pip => Some(&["python3", "-m"]),
+            Self::Cargo | Self::Go | Self::Zig | Self::Cmake => None,
+        }
+    }
+
+    /// Command running `script` of the workspace members matching `filter`, `*` for all of them.
+    fn run_filtered(&self, filter: &str, script: &str) -> Option<String> {
+        let all = filter.trim_matches(['\'', '"']) == "*";
+        let command = match self {
+            Self::Bun => format!("bun run --filter {filter} {script}"),
+            Self::Pnpm if all => format!("pnpm -r run {script}"),
+            Self::Pnpm => format!("pnpm --filter {filter} run {script}"),
+            // Yarn skips the workspace running the command, so root scripts do not recurse
+            Self::Yarn if all => format!("yarn workspaces foreach --all run {script}"),
+            Self::Yarn => format!("yarn workspace {filter} run {script}"),
+            Self::Npm if all => format!("npm run {script} --workspaces --if-present"),
+            Self::Npm => format!("npm run {script} --workspace {filter}"),
+            Self::Cargo | Self::Go | Self::Uv | Self::Pip | Self::Zig | Self::Cmake => return None,
+        };
+        Some(command)
+    }
+
+    /// Rewrites a command line written for `from` to use this package manager instead.
+    ///
+    /// Installs, script runs and binaries run from the dependencies are rewritten, while
+    /// anything else, such as `bun test` running the Bun test runner, is left as is.
+    pub fn translate_command(&self, command: &str, from: PackageManager) -> String {
+        if *self == from {
+            return command.to_string();
+        }
+        command
+            .split(" && ")
+            .map(|segment| self.translate_segment(segment, from))
+            .collect::<Vec<_>>()
+            .join(" && ")
+    }
+
+    fn translate_segment(&self, segment: &str, from: PackageManager) -> String {
+        let indent = &segment[..segment.len() - segment.trim_start().len()];
+        let words: Vec<&str> = segment.split_whitespace().collect();
+        let from_name = from.to_string();
+        let to_name = self.to_string();
+        let rest = |skip: usize| {
+            words[skip..]
+                .iter()
+                .map(|word| format!(" {word}"))
+                .collect::<String>()
+        };
+
+        let translated = match words.as_slice() {
+            [name, "install", ..] if *name == from_name => format!("{to_name} install{}", rest(2)),
+            [name, "run", "--filter", filter, script, ..] if *name == from_name => {
+                match self.run_filtered(filter, script) {
+                    Some(run) => format!("{run}{}", rest(5)),
+                    None => return segment.to_string(),
+                }
+            }
+            [name, "run", script, ..] if *name == from_name => {
+                format!("{to_name} run {script}{}", rest(3))
+            }
+            // `bun test` runs the Bun test runner rather than the test script
+            [name, "test", ..] if *name == from_name && from != Self::Bun => {
+                format!("{to_name} run test{}", rest(2))
+            }
+            _ => match from.executor().zip(self.executor()) {
+                Some((from_executor, to_executor)) if words.starts_with(from_executor) => {
+                    format!("{}{}", to_executor.join(" "), rest(from_executor.len()))
+                }
+                _ => return segment.to_string(),
+            },
+        };
+        format!("{indent}{translated}")
+    }
+}
+
+/// Whether the project runs on Bun, whatever installs its dependencies.
+pub fn uses_bun_runtime(package: &serde_json::Value) -> bool {
+    package.pointer("/devDependencies/@types~1bun").is_some()
+}
+
+impl fmt::Display for PackageManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Cargo => "cargo",
+            Self::Go => "go",
+            Self::Uv => "uv",
+            Self::Pip => "pip",
+            Self::Zig => "zig",
+            Self::Cmake => "cmake",
+            Self::Bun => "bun",
+            Self::Pnpm => "pnpm",
+            Self::Yarn => "yarn",
+            Self::Npm => "npm",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn detects_javascript_managers() {
+        let bun_types = json!({ "devDependencies": { "@types/bun": "latest" } });
+        let cases = [
+            (
+                json!({ "packageManager": "yarn@4.9.2" }),
+                &["pnpm-lock.yaml"][..],
+                PackageManager::Yarn,
+            ),
+            (json!({}), &["bun.lock"][..], PackageManager::Bun),
+            (json!({}), &["bun.lockb"][..], PackageManager::Bun),
+            (json!({}), &["pnpm-lock.yaml"][..], PackageManager::Pnpm),
+            (json!({}), &["yarn.lock"][..], PackageManager::Yarn),
+            (json!({}), &["package-lock.json"][..], PackageManager::Npm),
+            (
+                json!({}),
+                &["bun.lock", "package-lock.json"][..],
+                PackageManager::Bun,
+            ),
+            (
+                bun_types.clone(),
+                &["package-lock.json"][..],
+                PackageManager::Npm,
+            ),
+            (bun_types, &[][..], PackageManager::Bun),
+            (json!({}), &[][..], PackageManager::Npm),
+        ];
+
+        for (package, lockfiles, expected) in cases {
+            let dir = tempfile::tempdir().unwrap();
+            for lockfile in lockfiles {
+                fs::write(dir.path().join(lockfile), "").unwrap();
+            }
+            assert_eq!(
+                PackageManager::detect(dir.path(), Language::Typescript, &package),
+                expected,
+                "{package} {lockfiles:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn detects_python_managers() {
+        let cases: [(&[(&str, &str)], PackageManager); 4] = [
+            (&[("pyproject.toml", "[project]\n")], PackageManager::Uv),
+            (
+                &[("requirements.txt", ""), ("uv.lock", "")],
+                PackageManager::Uv,
+            ),
+            (
+                &[("requirements.txt", ""), ("pyproject.toml", "[tool.uv]\n")],
+                PackageManager::Uv,
+            ),
+            (&[("requirements.txt", "")], PackageManager::Pip),
+        ];
+
+        for (files, expected) in cases {
+            let dir = tempfile::tempdir().unwrap();
+            for (path, content) in files {
+                fs::write(dir.path().join(path), content).unwrap();
+            }
+            assert_eq!(
+                PackageManager::detect(dir.path(), Language::Python, &serde_json::Value::Null),
+                expected,
+                "{files:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn only_javascript_managers_are_pinned() {
+        let cases = [
+            (PackageManager::Bun, Some("1.2.19")),
+            (PackageManager::Npm, Some("11.4.2")),
+            (PackageManager::Cargo, None),
+            (PackageManager::Uv, None),
+            (PackageManager::Cmake, None),
+        ];
+        for (manager, expected) in cases {
+            assert_eq!(manager.pinned_version(), expected, "{manager}");
+        }
+    }
+
+    #[test]
+    fn managers_without_dependency_binaries_have_no_executor() {
+        let cases: [(PackageManager, Option<&[&str]>); 5] = [
+            (PackageManager::Npm, Some(&["npx"])),
+            (PackageManager::Pnpm, Some(&["pnpm", "exec"])),
+            (PackageManager::Uv, Some(&["uv", "run"])),
+            (PackageManager::Go, None),
+            (PackageManager::Zig, None),
+        ];
+        for (manager, expected) in cases {
+            assert_eq!(manager.executor(), expected, "{manager}");
+        }
+    }
+
+    #[test]
+    fn translates_commands_between_managers() {
+        let cases = [
+            ("bun install", PackageManager::Pnpm, "pnpm install"),
+            (
+                "bun run build && bunx biome lint .",
+                PackageManager::Npm,
+                "npm run build && npx biome lint .",
+            ),
+            (
+                "bun run --filter '*' dev",
+                PackageManager::Pnpm,
+                "pnpm -r run dev",
+            ),
+            (
+                "bun run --filter web dev",
+                PackageManager::Yarn,
+                "yarn workspace web run dev",
+            ),
+            ("bun test", PackageManager::Npm, "bun test"),
+            ("bunx tsc", PackageManager::Cargo, "bunx tsc"),
+            (
+                "bun run --filter web dev",
+                PackageManager::Go,
+                "bun run --filter web dev",
+            ),
+        ];
+        for (command, to, expected) in cases {
+            assert_eq!(
+                to.translate_command(command, PackageManager::Bun),
+                expected,
+                "{command} {to}"
+            );
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::read;
+
+/// Members of a Cargo, Go, uv or JavaScript workspace, relative to its root.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// File declaring the members, e.g. `Cargo.toml` or `go.work`.
+    pub manifest: &'static str,
+    pub members: Vec<PathBuf>,
+}
+
+impl Workspace {
+    /// Reads the workspace declared in `Cargo.toml`.
+    pub fn cargo(dir: &Path, manifest: &toml::Table) -> Option<Self> {
+        let workspace = manifest.get("workspace")?;
+        let patterns = string_list(workspace.get("members"));
+        let excluded = string_list(workspace.get("exclude"));
+        Some(Self::expand(dir, "Cargo.toml", &patterns, &excluded))
+    }
+
+    /// Reads the `[tool.uv.workspace]` table of `pyproject.toml`.
+    pub fn uv(dir: &Path, manifest: &toml::Table) -> Option<Self> {
+        let workspace = manifest.get("tool")?.get("uv")?.get("workspace")?;
+        let patterns = string_list(workspace.get("members"));
+        let excluded = string_list(workspace.get("exclude"));
+        Some(Self::expand(dir, "pyproject.toml", &patterns, &excluded))
+    }
+
+    /// Reads the modules used by `go.work`, either one per `use` line or in a `use ( ... )` block.
+    pub fn go(dir: &Path) -> Option<Self> {
+        let content = read(dir, "go.work")?;
+        let mut patterns = Vec::new();
+        let mut in_block = false;
+        for line in content.lines().map(|line| line.trim()) {
+            if in_block {
+                match line {
+                    ")" => in_block = false,
+                    "" => {}
+                    path if !path.starts_with("//") => patterns.push(path.to_string()),
+                    _ => {}
+                }
+            } else if let Some(rest) = line.strip_prefix("use") {
+                match rest.trim() {
+                    "(" => in_block = true,
+                    path if !path.is_empty() => patterns.push(path.to_string()),
+                    _ => {}
+                }
+            }
+        }
+        Some(Self::expand(dir, "go.work", &patterns, &[]))
+    }
+
+    /// Reads the `workspaces` field of `package.json`, or `pnpm-workspace.yaml`.
+    pub fn javascript(dir: &Path, package: &serde_json::Value) -> Option<Self> {
+        if let Some(workspaces) = package.get("workspaces") {
+            // Yarn also accepts `{ "packages": [...] }`
+            let list = workspaces.get("packages").unwrap_or(workspaces);
+            let patterns: Vec<String> = list
+                .as_array()
+                .map(|patterns| {
+                    patterns
+                        .iter()
+                        .filter_map(|pattern| pattern.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default();
+            return Some(Self::expand(dir, "package.json", &patterns, &[]));
+        }
+
+        let content = read(dir, "pnpm-workspace.yaml")?;
+        let patterns: Vec<String> = content
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("- "))
+            .map(|pattern| pattern.trim().trim_matches(['"', '\'']).to_string())
+            .collect();
+        Some(Self::expand(dir, "pnpm-workspace.yaml", &patterns, &[]))
+    }
+
+    /// Resolves the member patterns, where `*` matches any single directory name.
+    fn expand(
+        dir: &Path,
+        manifest: &'static str,
+        patterns: &[String],
+        excluded: &[String],
+    ) -> Self {
+        let excluded: Vec<String> = excluded
+            .iter()
+            .map(String::as_str)
+            .chain(
+                patterns
+                    .iter()
+                    .filter_map(|pattern| pattern.strip_prefix('!')),
+            )
+            .map(normalize)
+            .collect();
+
+        let mut members = Vec::new();
+        for pattern in patterns.iter().filter(|pattern| !pattern.starts_with('!')) {
+            let mut candidates = vec![PathBuf::new()];
+            for part in normalize(pattern).split('/') {
+                candidates = candidates
+                    .into_iter()
+                    .flat_map(|candidate| match part {
+                        "*" | "**" => subdirs(&dir.join(&candidate))
+                            .into_iter()
+                            .map(|name| candidate.join(name))
+                            .collect(),
+                        part => vec![candidate.join(part)],
+                    })
+                    .collect();
+            }
+            members.extend(candidates.into_iter().filter(|candidate| {
+                dir.join(candidate).is_dir()
+                    && !excluded.contains(&candidate.to_string_lossy().replace('\\', "/"))
+            }));
+        }
+        members.sort();
+        members.dedup();
+
+        Self { manifest, members }
+    }
+}
+
+fn normalize(pattern: &str) -> String {
+    pattern
+        .trim_start_matches("./")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+fn string_list(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(|value| value.as_array())
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|value| value.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn subdirs(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+                .filter_map(|entry| entry.file_name().to_str().map(String::from))
+                .filter(|name| !name.starts_with('.') && name != "node_modules")
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_dirs(dir: &Path, dirs: &[&str]) {
+        for path in dirs {
+            fs::create_dir_all(dir.join(path)).unwrap();
+        }
+    }
+
+    fn members(workspace: Option<Workspace>) -> Vec<String> {
+        workspace
+            .unwrap()
+            .members
+            .iter()
+            .map(|member| member.to_string_lossy().replace('\\', "/"))
+            .collect()
+    }
+
+    #[test]
+    fn expands_cargo_members_without_the_excluded_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        make_dirs(
+            dir.path(),
+            &["crates/core", "crates/cli", "crates/old", "xtask"],
+        );
+        let manifest: toml::Table = r#"
+            [workspace]
+            members = ["crates/*", "./xtask/", "missing"]
+            exclude = ["crates/old"]
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            members(Workspace::cargo(dir.path(), &manifest)),
+            ["crates/cli", "crates/core", "xtask"]
+        );
+    }
+
+    #[test]
+    fn reads_uv_workspace_members() {
+        let dir = tempfile::tempdir().unwrap();
+        make_dirs(dir.path(), &["packages/lib"]);
+        let manifest: toml::Table = "[tool.uv.workspace]\nmembers = [\"packages/*\"]\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            members(Workspace::uv(dir.path(), &manifest)),
+            ["packages/lib"]
+        );
+        assert!(Workspace::uv(dir.path(), &toml::Table::new()).is_none());
+    }
+
+    #[test]
+    fn reads_go_work_use_lines_and_blocks() {
+        let dir = tempfile::tempdir().unwrap();
+        make_dirs(dir.path(), &["api", "tools", "web"]);
+        fs::write(
+            dir.path().join("go.work"),
+            "go 1.24\n\nuse ./web\n\nuse (\n\t./api\n\t// ./skipped\n\t./tools\n)\n",
+        )
+        .unwrap();
+
+        assert_eq!(members(Workspace::go(dir.path())), ["api", "tools", "web"]);
+    }
+
+    #[test]
+    fn reads_javascript_workspaces() {
+        let dir = tempfile::tempdir().unwrap();
+        make_dirs(
+            dir.path(),
+            &[
+                "apps/web",
+                "apps/legacy",
+                "packages/ui",
+                "packages/ui/node_modules",
+            ],
+        );
+        let cases = [
+            serde_json::json!({ "workspaces": ["apps/*", "!apps/legacy", "packages/*"] }),
+            serde_json::json!({
+                "workspaces": { "packages": ["apps/*", "!apps/legacy", "packages/*"] }
+            }),
+        ];
+
+        for package in cases {
+            assert_eq!(
+                members(Workspace::javascript(dir.path(), &package)),
+                ["apps/web", "packages/ui"],
+                "{package}"
+            );
+        }
+    }
+
+    #[test]
+    fn reads_pnpm_workspace_yaml() {
+        let dir = tempfile::tempdir().unwrap();
+        make_dirs(dir.path(), &["packages/api", "packages/ui"]);
+        fs::write(
+            dir.path().join("pnpm-workspace.yaml"),
+            "packages:\n  - \"packages/*\"\n",
+        )
+        .unwrap();
+
+        let workspace = Workspace::javascript(dir.path(), &serde_json::json!({}));
+        assert_eq!(workspace.as_ref().unwrap().manifest, "pnpm-workspace.yaml");
+        assert_eq!(members(workspace), ["packages/api", "packages/ui"]);
+    }
+
+    #[test]
+    fn projects_without_a_workspace_have_no_members() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Workspace::go(dir.path()).is_none());
+        assert!(Workspace::javascript(dir.path(), &serde_json::json!({ "name": "web" })).is_none());
+    }
+}
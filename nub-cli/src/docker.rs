@@ -0,0 +1,429 @@
+use std::fs;
+
+use crate::{
+    deploy::GeneratedFile,
+    detect::Project,
+    errors::CliError,
+    template::{Language, Template, TemplateManager},
+};
+
+/// Entries always excluded from the Docker build context.
+static DOCKERIGNORE_ENTRIES: &[&str] = &[".git", ".nub", "Dockerfile", ".dockerignore"];
+
+/// Renders the Dockerfile and `.dockerignore` of the project.
+pub fn docker_files(project: &Project) -> Result<Vec<GeneratedFile>, CliError> {
+    Ok(vec![
+        GeneratedFile::new("Dockerfile", dockerfile(project)?),
+        GeneratedFile::new(".dockerignore", dockerignore(project)),
+    ])
+}
+
+/// Renders a multi-stage Dockerfile producing a small production image.
+pub fn dockerfile(project: &Project) -> Result<String, CliError> {
+    let dockerfile = match project.language {
+        Language::Cpp => cpp_dockerfile(project),
+        Language::Go => go_dockerfile(project),
+        Language::Python => python_dockerfile(project),
+        Language::Rust => rust_dockerfile(project)?,
+        Language::Typescript => typescript_dockerfile(project),
+        Language::Zig => zig_dockerfile(project),
+    };
+    Ok(dockerfile)
+}
+
+/// Caches dependencies in their own layer with cargo-chef so only source changes trigger
+/// a full rebuild.
+fn rust_dockerfile(project: &Project) -> Result<String, CliError> {
+    let binary = project.rust_binary()?;
+    let port = project.http_port();
+    Ok(format!(
+        r#"FROM lukemathwalker/cargo-chef:latest-rust-1 AS chef
+WORKDIR /app
+
+FROM chef AS planner
+COPY . .
+RUN cargo chef prepare --recipe-path recipe.json
+
+FROM chef AS builder
+COPY --from=planner /app/recipe.json recipe.json
+RUN cargo chef cook --release --recipe-path recipe.json
+COPY . .
+RUN cargo build --release --bin {binary}
+
+FROM debian:bookworm-slim AS runtime
+RUN apt-get update \
+    && apt-get install -y --no-install-recommends ca-certificates \
+    && rm -rf /var/lib/apt/lists/*
+COPY --from=builder /app/target/release/{binary} /usr/local/bin/{binary}
+EXPOSE {port}
+ENTRYPOINT ["/usr/local/bin/{binary}"]
+"#
+    ))
+}
+
+/// Builds a static binary without cgo and runs it on distroless.
+fn go_dockerfile(project: &Project) -> String {
+    let port = project.http_port();
+    let go_version = go_version(project).unwrap_or("1".to_string());
+    let package = project.go_main_package();
+    format!(
+        r#"FROM golang:{go_version} AS builder
+WORKDIR /app
+COPY go.mod go.sum* ./
+RUN go mod download
+COPY . .
+RUN CGO_ENABLED=0 GOOS=linux go build -trimpath -ldflags="-s -w" -o /out/server {package}
+
+FROM gcr.io/distroless/static-debian12:nonroot
+COPY --from=builder /out/server /server
+EXPOSE {port}
+USER nonroot:nonroot
+ENTRYPOINT ["/server"]
+"#
+    )
+}
+
+/// Compiles the entry point into a standalone executable with `bun build --compile`.
+///
+/// The manifests of the workspace members are copied before installing, since Bun needs
+/// them to link the workspace.
+fn typescript_dockerfile(project: &Project) -> String {
+    let port = project.http_port();
+    let entry = project.typescript_entry();
+    let members: String = project
+        .workspace
+        .iter()
+        .flat_map(|workspace| &workspace.members)
+        .filter(|member| project.root.join(member).join("package.json").exists())
+        .map(|member| {
+            let member = member.to_string_lossy().replace('\\', "/");
+            format!("COPY {member}/package.json {member}/\n")
+        })
+        .collect();
+    format!(
+        r#"FROM oven/bun:1 AS builder
+WORKDIR /app
+COPY package.json bun.lock* ./
+{members}RUN bun install
+COPY . .
+RUN bun build --compile --minify --sourcemap {entry} --outfile server
+
+FROM gcr.io/distroless/base-debian12:nonroot
+WORKDIR /app
+COPY --from=builder /app/server /app/server
+EXPOSE {port}
+USER nonroot:nonroot
+ENTRYPOINT ["/app/server"]
+"#
+    )
+}
+
+/// Installs the locked dependencies with uv in their own layer and copies the virtual
+/// environment into a slim Python image.
+fn python_dockerfile(project: &Project) -> String {
+    let port = project.http_port();
+    let module = project.python_module();
+    format!(
+        r#"FROM ghcr.io/astral-sh/uv:python3.12-bookworm-slim AS builder
+ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy
+WORKDIR /app
+COPY pyproject.toml uv.lock* ./
+RUN uv sync --no-dev --no-install-project
+COPY . .
+RUN uv sync --no-dev
+
+FROM python:3.12-slim-bookworm
+WORKDIR /app
+COPY --from=builder /app /app
+ENV PATH="/app/.venv/bin:$PATH"
+EXPOSE {port}
+CMD ["python", "-m", "{module}"]
+"#
+    )
+}
+
+/// Cross-compiles a static musl binary and runs it on distroless.
+fn zig_dockerfile(project: &Project) -> String {
+    let port = project.http_port();
+    let binary = &project.name;
+    format!(
+        r#"FROM debian:bookworm-slim AS builder
+ARG ZIG_VERSION=0.14.1
+RUN apt-get update \
+    && apt-get install -y --no-install-recommends ca-certificates curl xz-utils \
+    && curl -fsSL https://ziglang.org/download/${{ZIG_VERSION}}/zig-x86_64-linux-${{ZIG_VERSION}}.tar.xz \
+    | tar -xJ -C /opt \
+    && ln -s /opt/zig-x86_64-linux-${{ZIG_VERSION}}/zig /usr/local/bin/zig
+WORKDIR /app
+COPY . .
+RUN zig build -Doptimize=ReleaseSafe -Dtarget=x86_64-linux-musl
+
+FROM gcr.io/distroless/static-debian12:nonroot
+COPY --from=builder /app/zig-out/bin/{binary} /{binary}
+EXPOSE {port}
+USER nonroot:nonroot
+ENTRYPOINT ["/{binary}"]
+"#
+    )
+}
+
+/// Builds a release binary with CMake on the same Debian release as the runtime image, so
+/// the C++ standard library matches.
+fn cpp_dockerfile(project: &Project) -> String {
+    let port = project.http_port();
+    let binary = &project.name;
+    format!(
+        r#"FROM debian:bookworm AS builder
+RUN apt-get update \
+    && apt-get install -y --no-install-recommends build-essential cmake ninja-build \
+    && rm -rf /var/lib/apt/lists/*
+WORKDIR /app
+COPY . .
+RUN cmake -S . -B build -G Ninja -DCMAKE_BUILD_TYPE=Release \
+    && cmake --build build --target {binary}
+
+FROM debian:bookworm-slim AS runtime
+COPY --from=builder /app/build/{binary} /usr/local/bin/{binary}
+EXPOSE {port}
+ENTRYPOINT ["/usr/local/bin/{binary}"]
+"#
+    )
+}
+
+/// Reads the `major.minor` Go version from `go.mod`.
+fn go_version(project: &Project) -> Option<String> {
+    let go_mod = fs::read_to_string(project.root.join("go.mod")).ok()?;
+    let version = go_mod
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("go "))?
+        .trim();
+    let parts: Vec<_> = version.split('.').take(2).collect();
+    Some(parts.join("."))
+}
+
+/// Derives `.dockerignore` from the originating template's `_gitignore`, or from the
+/// project `.gitignore` when the template is unknown.
+pub fn dockerignore(project: &Project) -> String {
+    let manager = TemplateManager::new();
+    let gitignore = project
+        .template
+        .as_ref()
+        .and_then(|name| {
+            let template = Template {
+                name: name.clone(),
+                language: project.language,
+            };
+            manager.template_file(&template.path(), "_gitignore")
+        })
+        .map(String::from)
+        .or_else(|| fs::read_to_string(project.root.join(".gitignore")).ok())
+        .unwrap_or_default();
+
+    let mut content = String::from("# Generated by Nub\n");
+    for entry in DOCKERIGNORE_ENTRIES {
+        content.push_str(entry);
+        content.push('\n');
+    }
+    if !gitignore.trim().is_empty() {
+        content.push_str("\n# From .gitignore\n");
+        content.push_str(gitignore.trim_end());
+        content.push('\n');
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::detect;
+
+    /// Paths and contents of the files of a project.
+    type Files = &'static [(&'static str, &'static str)];
+
+    fn project_with_files(files: &[(&str, &str)]) -> (tempfile::TempDir, Project) {
+        let dir = tempfile::tempdir().unwrap();
+        write_files(dir.path(), files);
+        let project = detect::detect(dir.path()).unwrap();
+        (dir, project)
+    }
+
+    fn write_files(dir: &Path, files: &[(&str, &str)]) {
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+    }
+
+    #[test]
+    fn renders_a_dockerfile_per_language() {
+        let cases: [(Files, &[&str]); 6] = [
+            (
+                &[(
+                    "Cargo.toml",
+                    "[package]\nname = \"api\"\n\n[[bin]]\nname = \"server\"\n\n[dependencies]\naxum = \"0.8\"\n",
+                )],
+                &[
+                    "RUN cargo build --release --bin server",
+                    "COPY --from=builder /app/target/release/server /usr/local/bin/server",
+                    "EXPOSE 8080",
+                ],
+            ),
+            (
+                &[
+                    (
+                        "go.mod",
+                        "module example.com/api\n\ngo 1.24.2\n\nrequire github.com/go-chi/chi/v5 v5.2.2\n",
+                    ),
+                    ("cmd/api/main.go", "package main\n"),
+                ],
+                &[
+                    "FROM golang:1.24 AS builder",
+                    "-o /out/server ./cmd/api",
+                    "EXPOSE 3000",
+                ],
+            ),
+            (
+                &[(
+                    "package.json",
+                    r#"{ "name": "web", "module": "src/index.ts", "devDependencies": { "@types/bun": "latest" } }"#,
+                )],
+                &[
+                    "COPY package.json bun.lock* ./\nRUN bun install",
+                    "bun build --compile --minify --sourcemap src/index.ts --outfile server",
+                    "EXPOSE 3000",
+                ],
+            ),
+            (
+                &[("pyproject.toml", "[project]\nname = \"my-app\"\n")],
+                &[
+                    "RUN uv sync --no-dev",
+                    "CMD [\"python\", \"-m\", \"my_app\"]",
+                ],
+            ),
+            (
+                &[("build.zig.zon", ".{\n    .name = .game,\n}\n")],
+                &["COPY --from=builder /app/zig-out/bin/game /game"],
+            ),
+            (
+                &[("CMakeLists.txt", "project(engine CXX)\n")],
+                &["cmake --build build --target engine"],
+            ),
+        ];
+
+        for (files, expected) in cases {
+            let (_dir, project) = project_with_files(files);
+            let dockerfile = dockerfile(&project).unwrap();
+            for line in expected {
+                assert!(dockerfile.contains(line), "{line} in\n{dockerfile}");
+            }
+        }
+    }
+
+    #[test]
+    fn virtual_workspaces_build_the_binary_of_their_members() {
+        let (_dir, project) = project_with_files(&[
+            ("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n"),
+            ("crates/core/Cargo.toml", "[package]\nname = \"core\"\n"),
+            ("crates/core/src/lib.rs", ""),
+            ("crates/server/Cargo.toml", "[package]\nname = \"server\"\n"),
+            ("crates/server/src/main.rs", "fn main() {}\n"),
+        ]);
+
+        let dockerfile = dockerfile(&project).unwrap();
+        assert!(dockerfile.contains("RUN cargo build --release --bin server"));
+        assert!(dockerfile.contains("ENTRYPOINT [\"/usr/local/bin/server\"]"));
+    }
+
+    #[test]
+    fn virtual_workspaces_need_a_single_binary() {
+        let (_dir, project) = project_with_files(&[
+            ("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n"),
+            ("crates/core/Cargo.toml", "[package]\nname = \"core\"\n"),
+            ("crates/core/src/lib.rs", ""),
+        ]);
+        assert!(matches!(
+            dockerfile(&project),
+            Err(CliError::NoWorkspaceBinary(_))
+        ));
+
+        write_files(
+            &project.root,
+            &[
+                (
+                    "crates/api/Cargo.toml",
+                    "[package]\nname = \"api\"\n\n[[bin]]\nname = \"api-server\"\n",
+                ),
+                ("crates/worker/Cargo.toml", "[package]\nname = \"worker\"\n"),
+                ("crates/worker/src/main.rs", "fn main() {}\n"),
+            ],
+        );
+        let project = detect::detect(&project.root).unwrap();
+        match dockerfile(&project) {
+            Err(CliError::AmbiguousWorkspaceBinary(_, binaries)) => {
+                assert_eq!(binaries, "api-server, worker");
+            }
+            result => panic!("unexpected {result:?}"),
+        }
+    }
+
+    #[test]
+    fn bun_workspaces_copy_the_member_manifests_before_installing() {
+        let (_dir, project) = project_with_files(&[
+            (
+                "package.json",
+                r#"{ "name": "mono", "workspaces": ["apps/*", "packages/*"] }"#,
+            ),
+            ("bun.lock", ""),
+            ("apps/web/package.json", r#"{ "name": "web" }"#),
+            ("packages/ui/package.json", r#"{ "name": "ui" }"#),
+            ("packages/scratch/notes.md", ""),
+        ]);
+
+        let dockerfile = dockerfile(&project).unwrap();
+        assert!(
+            dockerfile.contains(
+                "COPY package.json bun.lock* ./\n\
+                 COPY apps/web/package.json apps/web/\n\
+                 COPY packages/ui/package.json packages/ui/\n\
+                 RUN bun install\n"
+            ),
+            "{dockerfile}"
+        );
+    }
+
+    #[test]
+    fn dockerignore_comes_from_the_template_gitignore() {
+        let (_dir, project) = project_with_files(&[
+            (
+                "Cargo.toml",
+                "[package]\nname = \"api\"\n\n[dependencies]\naxum = \"0.8\"\n",
+            ),
+            (".gitignore", "custom/\n"),
+        ]);
+
+        let content = dockerignore(&project);
+        assert!(content.starts_with("# Generated by Nub\n.git\n.nub\nDockerfile\n.dockerignore\n"));
+        assert!(content.contains("# From .gitignore\n# Build outputs\ntarget/\n"));
+        assert!(!content.contains("custom/"));
+    }
+
+    #[test]
+    fn dockerignore_falls_back_to_the_project_gitignore() {
+        let cases = [
+            (Some("dist/\n"), "\n# From .gitignore\ndist/\n"),
+            (None, ".dockerignore\n"),
+        ];
+
+        for (gitignore, ending) in cases {
+            let mut files = vec![("go.mod", "module example.com/plain\n")];
+            files.extend(gitignore.map(|content| (".gitignore", content)));
+            let (_dir, project) = project_with_files(&files);
+
+            let content = dockerignore(&project);
+            assert!(content.ends_with(ending), "{content}");
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::{errors::CliError, template::Language};
+
+/// Concept snippets embedded at build time, see `concepts.toml`.
+static CONCEPTS: &str = include_str!("concepts.toml");
+
+/// A programming concept with an idiomatic snippet per language.
+#[derive(Debug, Deserialize)]
+pub struct Concept {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub snippets: BTreeMap<Language, String>,
+}
+
+impl Concept {
+    fn matches(&self, query: &str) -> bool {
+        let query = normalize(query);
+        normalize(&self.id) == query
+            || normalize(&self.name) == query
+            || self.aliases.iter().any(|alias| normalize(alias) == query)
+    }
+}
+
+#[derive(Deserialize)]
+struct ConceptFile {
+    concept: Vec<Concept>,
+}
+
+/// Returns every embedded concept in declaration order.
+pub fn concepts() -> Result<Vec<Concept>, CliError> {
+    let file: ConceptFile = toml::from_str(CONCEPTS)
+        .map_err(|_| CliError::MalformedFile("concepts.toml".to_string()))?;
+    Ok(file.concept)
+}
+
+/// Finds a concept by id, name or alias, ignoring case and separators.
+pub fn find(query: &str) -> Result<Concept, CliError> {
+    concepts()?
+        .into_iter()
+        .find(|concept| concept.matches(query))
+        .ok_or_else(|| CliError::UnknownConcept(query.to_string()))
+}
+
+/// Lowercases and drops spaces, dashes and underscores so `HTTP server` matches `http-server`.
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_embedded_concepts() {
+        let concepts = concepts().unwrap();
+        assert_eq!(concepts[0].id, "error-handling");
+        assert!(
+            concepts
+                .iter()
+                .all(|concept| !concept.name.is_empty() && !concept.snippets.is_empty())
+        );
+    }
+
+    #[test]
+    fn every_concept_covers_every_supported_language() {
+        let supported = [Language::Go, Language::Rust, Language::Typescript];
+        let concepts = concepts().unwrap();
+        let ids: Vec<&str> = concepts.iter().map(|concept| concept.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "error-handling",
+                "iteration",
+                "async",
+                "generics",
+                "string-formatting",
+                "http-server",
+                "json-decoding",
+            ]
+        );
+
+        for concept in &concepts {
+            let languages: Vec<Language> = concept.snippets.keys().copied().collect();
+            assert_eq!(languages, supported, "{}", concept.id);
+            for (language, snippet) in &concept.snippets {
+                assert!(!snippet.trim().is_empty(), "{} {language}", concept.id);
+            }
+        }
+    }
+
+    #[test]
+    fn finds_concepts_by_id_name_or_alias() {
+        let cases = [
+            "error-handling",
+            "Error handling",
+            "ERROR_HANDLING",
+            "result",
+        ];
+        for query in cases {
+            assert_eq!(find(query).unwrap().id, "error-handling", "{query}");
+        }
+        assert!(matches!(
+            find("teleportation"),
+            Err(CliError::UnknownConcept(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_concepts() {
+        let cases = [
+            "[[concept]]\nid = \"x\"\n",
+            "[[concept]]\nid = \"x\"\nname = \"X\"\n[concept.snippets]\ncobol = \"\"\n",
+        ];
+        for content in cases {
+            assert!(toml::from_str::<ConceptFile>(content).is_err(), "{content}");
+        }
+    }
+}
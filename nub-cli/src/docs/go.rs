@@ -0,0 +1,157 @@
+use std::process::Command;
+
+use crate::template::Language;
+
+use super::{DocEntry, DocSource, first_sentence, score};
+
+/// Documentation of the standard library and installed modules through `go doc`.
+pub struct GoDocs;
+
+impl DocSource for GoDocs {
+    fn language(&self) -> Language {
+        Language::Go
+    }
+
+    /// `go doc` resolves a single package or symbol, e.g. `http.Get` or `strings`.
+    fn search(&self, query: &str, _limit: usize) -> Vec<DocEntry> {
+        let Ok(output) = Command::new("go").args(["doc", query]).output() else {
+            return Vec::new();
+        };
+        if !output.status.success() {
+            return Vec::new();
+        }
+
+        let text = String::from_utf8_lossy(&output.stdout);
+        parse_go_doc(query, &text).into_iter().collect()
+    }
+}
+
+/// Parses `go doc` output: a `package` header, the declaration, then an indented description.
+fn parse_go_doc(query: &str, text: &str) -> Option<DocEntry> {
+    let mut lines = text.lines().peekable();
+
+    // package http // import "net/http"
+    let header = lines.next()?;
+    let import_path = header
+        .split("import \"")
+        .nth(1)
+        .map(|rest| rest.trim_end_matches('"').to_string())
+        .or_else(|| header.strip_prefix("package ").map(String::from))?;
+
+    while lines.peek().is_some_and(|line| line.trim().is_empty()) {
+        lines.next();
+    }
+
+    // Declarations may span several tab-indented lines, descriptions are indented by 4 spaces
+    let mut declaration = Vec::new();
+    while let Some(line) =
+        lines.next_if(|line| !line.trim().is_empty() && !line.starts_with("    "))
+    {
+        declaration.push(line);
+    }
+    let description: Vec<&str> = lines
+        .skip_while(|line| line.trim().is_empty())
+        .take_while(|line| line.starts_with("    "))
+        .map(str::trim)
+        .collect();
+
+    let symbol = query.rsplit('.').next().unwrap_or(query);
+    let is_package = declaration.is_empty() || !query.contains('.');
+    let (path, kind, signature, summary) = if is_package {
+        // Package documentation starts with its description instead of a declaration
+        let summary = declaration
+            .iter()
+            .map(|line| line.trim())
+            .collect::<Vec<_>>();
+        (import_path.clone(), "package", None, summary.join(" "))
+    } else {
+        let kind = declaration
+            .first()
+            .and_then(|line| line.split_whitespace().next())
+            .unwrap_or("func");
+        (
+            format!("{import_path}.{symbol}"),
+            kind,
+            Some(
+                declaration
+                    .iter()
+                    .map(|line| line.trim())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            description.join(" "),
+        )
+    };
+
+    let score = score(query, &path, symbol).max(50);
+    Some(DocEntry {
+        language: Language::Go,
+        path,
+        kind: Some(kind.to_string()),
+        signature,
+        summary: (!summary.is_empty()).then(|| first_sentence(&summary)),
+        score,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_function() {
+        let output = "package http // import \"net/http\"\n\n\
+            func Get(url string) (resp *Response, err error)\n    \
+            Get issues a GET to the specified URL. If the response is one of the\n    \
+            following redirect codes, Get follows the redirect.\n\n";
+
+        let entry = parse_go_doc("http.Get", output).unwrap();
+        assert_eq!(entry.path, "net/http.Get");
+        assert_eq!(entry.kind.as_deref(), Some("func"));
+        assert_eq!(
+            entry.signature.as_deref(),
+            Some("func Get(url string) (resp *Response, err error)")
+        );
+        assert_eq!(
+            entry.summary.as_deref(),
+            Some("Get issues a GET to the specified URL.")
+        );
+        assert_eq!(entry.score, 90);
+    }
+
+    #[test]
+    fn parses_a_multiline_type() {
+        let output = "package strings // import \"strings\"\n\n\
+            type Builder struct {\n\t// Has unexported fields.\n}\n    \
+            A Builder is used to efficiently build a string.\n";
+
+        let entry = parse_go_doc("strings.Builder", output).unwrap();
+        assert_eq!(entry.path, "strings.Builder");
+        assert_eq!(entry.kind.as_deref(), Some("type"));
+        assert_eq!(
+            entry.signature.as_deref(),
+            Some("type Builder struct { // Has unexported fields. }")
+        );
+    }
+
+    #[test]
+    fn parses_a_package() {
+        let output = "package strings // import \"strings\"\n\n\
+            Package strings implements simple functions. See the docs.\n\n\
+            func Clone(s string) string\n";
+
+        let entry = parse_go_doc("strings", output).unwrap();
+        assert_eq!(entry.path, "strings");
+        assert_eq!(entry.kind.as_deref(), Some("package"));
+        assert_eq!(entry.signature, None);
+        assert_eq!(
+            entry.summary.as_deref(),
+            Some("Package strings implements simple functions.")
+        );
+    }
+
+    #[test]
+    fn ignores_empty_output() {
+        assert!(parse_go_doc("http.Get", "").is_none());
+    }
+}
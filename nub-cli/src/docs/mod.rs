@@ -0,0 +1,233 @@
+use std::cmp::Reverse;
+
+use crate::template::Language;
+
+pub mod concepts;
+mod go;
+mod rust;
+mod typescript;
+
+pub use go::GoDocs;
+pub use rust::RustDocs;
+pub use typescript::TypescriptDocs;
+
+/// A documented item matching a query.
+#[derive(Debug, Clone)]
+pub struct DocEntry {
+    pub language: Language,
+    /// Fully qualified path, e.g. `std::vec::Vec` or `net/http.Get`.
+    pub path: String,
+    /// Item kind such as `struct`, `func` or `interface`.
+    pub kind: Option<String>,
+    pub signature: Option<String>,
+    pub summary: Option<String>,
+    pub score: u32,
+}
+
+/// Locally available documentation for a language.
+pub trait DocSource {
+    fn language(&self) -> Language;
+
+    /// Returns entries matching the query, at most `limit` of them with details loaded.
+    fn search(&self, query: &str, limit: usize) -> Vec<DocEntry>;
+}
+
+/// Returns the documentation sources of the given language, or of every language.
+pub fn sources(language: Option<Language>) -> Vec<Box<dyn DocSource>> {
+    let all: Vec<Box<dyn DocSource>> = vec![
+        Box::new(RustDocs::discover()),
+        Box::new(GoDocs),
+        Box::new(TypescriptDocs::discover()),
+    ];
+    all.into_iter()
+        .filter(|source| language.is_none_or(|language| source.language() == language))
+        .collect()
+}
+
+/// Searches every source and returns the best entries first.
+pub fn search(sources: &[Box<dyn DocSource>], query: &str, limit: usize) -> Vec<DocEntry> {
+    let mut entries: Vec<DocEntry> = sources
+        .iter()
+        .flat_map(|source| source.search(query, limit))
+        .collect();
+    entries.sort_by_key(|entry| (Reverse(entry.score), entry.path.len()));
+    entries.truncate(limit);
+    entries
+}
+
+/// Scores how well an item name matches the query, 0 meaning no match.
+///
+/// `path` is the fully qualified name, `name` its last segment.
+pub fn score(query: &str, path: &str, name: &str) -> u32 {
+    let query = query.to_lowercase();
+    let path = path.to_lowercase();
+    let name = name.to_lowercase();
+
+    // Queries such as `vec::Vec` or `http.Get` target the qualified path
+    let qualified = query.contains("::") || query.contains('.') || query.contains('/');
+    if qualified {
+        return match path {
+            p if p == query || p.ends_with(&format!("::{query}")) => 100,
+            p if p.ends_with(&query) => 90,
+            p if p.contains(&query) => 60,
+            _ => 0,
+        };
+    }
+
+    if name == query {
+        100
+    } else if name.starts_with(&query) {
+        75
+    } else if name.contains(&query) {
+        50
+    } else if is_subsequence(&query, &name) {
+        20
+    } else {
+        0
+    }
+}
+
+fn is_subsequence(query: &str, name: &str) -> bool {
+    let mut chars = name.chars();
+    query.chars().all(|q| chars.any(|c| c == q))
+}
+
+/// Strips HTML tags and decodes the common entities, collapsing whitespace.
+pub fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut tag: Option<String> = None;
+    for c in html.chars() {
+        match (&mut tag, c) {
+            (None, '<') => tag = Some(String::new()),
+            (Some(name), '>') => {
+                // Block elements separate words, inline ones such as links do not
+                let name = name.trim_start_matches('/');
+                if ["div", "p", "br", "li"]
+                    .iter()
+                    .any(|block| name == *block || name.starts_with(&format!("{block} ")))
+                {
+                    text.push(' ');
+                }
+                tag = None;
+            }
+            (Some(name), c) => name.push(c),
+            (None, c) => text.push(c),
+        }
+    }
+
+    let decoded = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&");
+    decoded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Keeps the first sentence of a documentation paragraph.
+pub fn first_sentence(text: &str) -> String {
+    let text = text.trim();
+    match text.find(". ") {
+        Some(end) => text[..=end].to_string(),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeSource(Vec<(&'static str, u32)>);
+
+    impl DocSource for FakeSource {
+        fn language(&self) -> Language {
+            Language::Rust
+        }
+
+        fn search(&self, _query: &str, _limit: usize) -> Vec<DocEntry> {
+            self.0
+                .iter()
+                .map(|(path, score)| DocEntry {
+                    language: Language::Rust,
+                    path: path.to_string(),
+                    kind: None,
+                    signature: None,
+                    summary: None,
+                    score: *score,
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn scores_names_and_qualified_paths() {
+        let cases = [
+            ("Vec", "std::vec::Vec", "Vec", 100),
+            ("vec", "std::vec::VecDeque", "VecDeque", 75),
+            ("deque", "std::collections::VecDeque", "VecDeque", 50),
+            ("vdq", "std::collections::VecDeque", "VecDeque", 20),
+            ("hashmap", "std::vec::Vec", "Vec", 0),
+            ("vec::Vec", "std::vec::Vec", "Vec", 100),
+            ("http.Get", "net/http.Get", "Get", 90),
+            ("http.Get", "net/http.GetBody", "GetBody", 60),
+            ("io::Read", "std::fs::File", "File", 0),
+        ];
+
+        for (query, path, name, expected) in cases {
+            assert_eq!(score(query, path, name), expected, "{query} {path}");
+        }
+    }
+
+    #[test]
+    fn search_keeps_the_best_and_shortest_entries() {
+        let sources: Vec<Box<dyn DocSource>> = vec![
+            Box::new(FakeSource(vec![
+                ("std::vec::VecDeque", 75),
+                ("std::vec::Vec", 100),
+            ])),
+            Box::new(FakeSource(vec![("alloc::vec::Vec", 100), ("a::b", 20)])),
+        ];
+
+        let paths: Vec<String> = search(&sources, "Vec", 3)
+            .into_iter()
+            .map(|entry| entry.path)
+            .collect();
+        assert_eq!(
+            paths,
+            ["std::vec::Vec", "alloc::vec::Vec", "std::vec::VecDeque"]
+        );
+    }
+
+    #[test]
+    fn strips_html_tags_and_entities() {
+        let cases = [
+            ("<code>Vec&lt;T&gt;</code>", "Vec<T>"),
+            ("pub fn <a href=\"#\">new</a>()", "pub fn new()"),
+            ("<p>One</p><p>Two</p>", "One Two"),
+            ("a<br>b<div class=\"x\">c</div>", "a b c"),
+            (
+                "&quot;x&quot; &amp;&amp; &#39;y&#39;&nbsp;z",
+                "\"x\" && 'y' z",
+            ),
+            ("  spread\n  over\tlines ", "spread over lines"),
+        ];
+
+        for (html, expected) in cases {
+            assert_eq!(strip_html(html), expected, "{html}");
+        }
+    }
+
+    #[test]
+    fn keeps_the_first_sentence() {
+        let cases = [
+            ("Creates a vector. It is empty.", "Creates a vector."),
+            ("  Calls e.g. this  ", "Calls e.g."),
+            ("No period", "No period"),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(first_sentence(text), expected, "{text}");
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::template::Language;
+
+use super::{DocEntry, DocSource, first_sentence, score, strip_html};
+
+/// Crates documented by `rustup doc`.
+static STD_CRATES: &[&str] = &["std", "core", "alloc", "proc_macro", "test"];
+
+/// Rustdoc HTML output from `rustup doc` and the current project's `target/doc`.
+pub struct RustDocs {
+    /// Crate documentation directories, each containing an `all.html` item list.
+    crate_dirs: Vec<PathBuf>,
+}
+
+impl RustDocs {
+    pub fn discover() -> Self {
+        let mut crate_dirs = Vec::new();
+
+        if let Some(sysroot) = sysroot() {
+            let html = sysroot.join("share").join("doc").join("rust").join("html");
+            crate_dirs.extend(STD_CRATES.iter().map(|krate| html.join(krate)));
+        }
+
+        if let Ok(current_dir) = env::current_dir()
+            && let Ok(entries) = fs::read_dir(current_dir.join("target").join("doc"))
+        {
+            crate_dirs.extend(entries.filter_map(|entry| entry.ok()).map(|e| e.path()));
+        }
+
+        crate_dirs.retain(|dir| dir.join("all.html").is_file());
+        Self { crate_dirs }
+    }
+
+    /// Lists the items of a crate from the links of its `all.html` page.
+    fn items(&self, crate_dir: &Path) -> Vec<(String, PathBuf, String)> {
+        let Some(krate) = crate_dir.file_name().and_then(|name| name.to_str()) else {
+            return Vec::new();
+        };
+        let Ok(html) = fs::read_to_string(crate_dir.join("all.html")) else {
+            return Vec::new();
+        };
+
+        html.split("<a href=\"")
+            .skip(1)
+            .filter_map(|link| {
+                let (href, rest) = link.split_once("\">")?;
+                let (text, _) = rest.split_once("</a>")?;
+                // Item pages are named `<kind>.<Name>.html`
+                let file_name = href.rsplit('/').next()?;
+                let (kind, _) = file_name.split_once('.')?;
+                if href.starts_with('#') || href.starts_with("..") || !href.ends_with(".html") {
+                    return None;
+                }
+                Some((
+                    format!("{krate}::{}", strip_html(text)),
+                    crate_dir.join(href),
+                    kind.to_string(),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl DocSource for RustDocs {
+    fn language(&self) -> Language {
+        Language::Rust
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Vec<DocEntry> {
+        let mut matches: Vec<(u32, String, PathBuf, String)> = self
+            .crate_dirs
+            .iter()
+            .flat_map(|dir| self.items(dir))
+            .filter_map(|(path, page, kind)| {
+                let name = path.rsplit("::").next().unwrap_or(&path).to_string();
+                let score = score(query, &path, &name);
+                (score > 0).then_some((score, path, page, kind))
+            })
+            .collect();
+        matches.sort_by_key(|(score, path, _, _)| (std::cmp::Reverse(*score), path.len()));
+        matches.truncate(limit);
+
+        matches
+            .into_iter()
+            .map(|(score, path, page, kind)| {
+                let (signature, summary) = read_item_page(&page);
+                DocEntry {
+                    language: Language::Rust,
+                    path,
+                    kind: Some(kind),
+                    signature,
+                    summary,
+                    score,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Extracts the declaration and the first sentence of the description of an item page.
+fn read_item_page(page: &Path) -> (Option<String>, Option<String>) {
+    let Ok(html) = fs::read_to_string(page) else {
+        return (None, None);
+    };
+
+    let signature = between(&html, "<pre class=\"rust item-decl\">", "</pre>").map(strip_html);
+    let summary = between(&html, "class=\"docblock\">", "</div>")
+        .and_then(|docblock| between(docblock, "<p>", "</p>"))
+        .map(|paragraph| first_sentence(&strip_html(paragraph)));
+    (signature, summary)
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let to = text[from..].find(end)? + from;
+    Some(&text[from..to])
+}
+
+fn sysroot() -> Option<PathBuf> {
+    let output = Command::new("rustc")
+        .args(["--print", "sysroot"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_declaration_and_summary_of_an_item_page() {
+        let dir = tempfile::tempdir().unwrap();
+        let page = dir.path().join("struct.Vec.html");
+        fs::write(
+            &page,
+            r#"<pre class="rust item-decl"><code>pub struct Vec&lt;T&gt; { <span>/* private fields */</span> }</code></pre>
+<details><div class="docblock"><p>A contiguous growable array type. Written as <code>Vec&lt;T&gt;</code>.</p></div></details>"#,
+        )
+        .unwrap();
+
+        let (signature, summary) = read_item_page(&page);
+        assert_eq!(
+            signature.as_deref(),
+            Some("pub struct Vec<T> { /* private fields */ }")
+        );
+        assert_eq!(
+            summary.as_deref(),
+            Some("A contiguous growable array type.")
+        );
+        assert_eq!(
+            read_item_page(&dir.path().join("missing.html")),
+            (None, None)
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    cmp::Reverse,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::Language;
+
+use super::{DocEntry, DocSource, first_sentence, score};
+
+/// Declaration keywords recognized in `.d.ts` files.
+static DECLARATION_KINDS: &[&str] = &[
+    "function",
+    "class",
+    "interface",
+    "type",
+    "const",
+    "let",
+    "var",
+    "enum",
+    "namespace",
+];
+
+/// Upper bound on the number of declaration files scanned, large monorepos have many.
+const MAX_DECLARATION_FILES: usize = 5000;
+
+/// Type declarations shipped in the current project's `node_modules`.
+pub struct TypescriptDocs {
+    node_modules: Option<PathBuf>,
+}
+
+impl TypescriptDocs {
+    pub fn discover() -> Self {
+        let node_modules = env::current_dir()
+            .ok()
+            .map(|dir| dir.join("node_modules"))
+            .filter(|dir| dir.is_dir());
+        Self { node_modules }
+    }
+}
+
+impl DocSource for TypescriptDocs {
+    fn language(&self) -> Language {
+        Language::Typescript
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Vec<DocEntry> {
+        let Some(node_modules) = &self.node_modules else {
+            return Vec::new();
+        };
+
+        let mut files = Vec::new();
+        collect_declaration_files(node_modules, &mut files);
+
+        let mut entries: Vec<DocEntry> = files
+            .iter()
+            .flat_map(|file| {
+                let package = package_name(node_modules, file);
+                fs::read_to_string(file)
+                    .map(|content| parse_declarations(&package, &content, query))
+                    .unwrap_or_default()
+            })
+            .collect();
+        entries.sort_by_key(|entry| (Reverse(entry.score), entry.path.len()));
+        entries.dedup_by(|a, b| a.path == b.path);
+        entries.truncate(limit);
+        entries
+    }
+}
+
+fn collect_declaration_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        if files.len() >= MAX_DECLARATION_FILES {
+            return;
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            collect_declaration_files(&path, files);
+        } else if path.to_string_lossy().ends_with(".d.ts") {
+            files.push(path);
+        }
+    }
+}
+
+/// Returns the package owning a declaration file, e.g. `@types/bun` or `hono`.
+fn package_name(node_modules: &Path, file: &Path) -> String {
+    let relative = file.strip_prefix(node_modules).unwrap_or(file);
+    let mut components = relative
+        .components()
+        .filter_map(|component| component.as_os_str().to_str());
+    match components.next() {
+        Some(scope) if scope.starts_with('@') => {
+            format!("{scope}/{}", components.next().unwrap_or_default())
+        }
+        Some(package) => package.to_string(),
+        None => String::new(),
+    }
+}
+
+/// Finds matching top-level declarations together with the summary of their JSDoc comment.
+fn parse_declarations(package: &str, content: &str, query: &str) -> Vec<DocEntry> {
+    let mut entries = Vec::new();
+    let mut doc_comment: Vec<&str> = Vec::new();
+    let mut in_comment = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("/**") {
+            doc_comment.clear();
+            in_comment = !trimmed.ends_with("*/");
+            doc_comment.push(trimmed);
+            continue;
+        }
+        if in_comment {
+            in_comment = !trimmed.ends_with("*/");
+            doc_comment.push(trimmed);
+            continue;
+        }
+
+        if let Some((kind, name)) = parse_declaration(trimmed) {
+            let path = format!("{package}.{name}");
+            let score = score(query, &path, name);
+            if score > 0 {
+                let signature = trimmed.trim_end_matches('{').trim().to_string();
+                entries.push(DocEntry {
+                    language: Language::Typescript,
+                    path,
+                    kind: Some(kind.to_string()),
+                    signature: Some(signature),
+                    summary: summarize(&doc_comment),
+                    score,
+                });
+            }
+        }
+        if !trimmed.is_empty() {
+            doc_comment.clear();
+        }
+    }
+    entries
+}
+
+/// Parses `export declare function name(...)` style lines into their kind and name.
+fn parse_declaration(line: &str) -> Option<(&'static str, &str)> {
+    let mut rest = line;
+    for modifier in ["export ", "default ", "declare ", "abstract "] {
+        rest = rest.strip_prefix(modifier).unwrap_or(rest);
+    }
+
+    let kind = DECLARATION_KINDS
+        .iter()
+        .find(|kind| rest.starts_with(&format!("{kind} ")))?;
+    let name = rest[kind.len()..]
+        .trim_start()
+        .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '$')
+        .next()?;
+    (!name.is_empty()).then_some((*kind, name))
+}
+
+/// Keeps the first sentence of a JSDoc comment, ignoring tags such as `@param`.
+fn summarize(doc_comment: &[&str]) -> Option<String> {
+    let text: Vec<&str> = doc_comment
+        .iter()
+        .map(|line| {
+            line.trim_start_matches("/**")
+                .trim_end_matches("*/")
+                .trim_start_matches('*')
+                .trim()
+        })
+        .take_while(|line| !line.starts_with('@'))
+        .filter(|line| !line.is_empty())
+        .collect();
+    (!text.is_empty()).then(|| first_sentence(&text.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_declaration_lines() {
+        let cases = [
+            (
+                "export declare function serve(options: Options): Server;",
+                Some(("function", "serve")),
+            ),
+            (
+                "export default class Hono<E = Env> {",
+                Some(("class", "Hono")),
+            ),
+            ("export abstract class Base {", Some(("class", "Base"))),
+            ("export interface Context {", Some(("interface", "Context"))),
+            (
+                "export type $Handler = () => void;",
+                Some(("type", "$Handler")),
+            ),
+            ("declare const version: string;", Some(("const", "version"))),
+            ("import { Server } from \"./server\";", None),
+            ("export { serve };", None),
+        ];
+
+        for (line, expected) in cases {
+            assert_eq!(parse_declaration(line), expected, "{line}");
+        }
+    }
+
+    #[test]
+    fn parses_matching_declarations_with_their_doc_comment() {
+        let content = r#"
+/**
+ * Starts an HTTP server. Returns once it listens.
+ * @param options server options
+ */
+export declare function serve(options: Options): Server;
+
+/** Options of {@link serve}. */
+export interface ServeOptions {
+    port?: number;
+}
+
+export declare const server: Server;
+export declare function fetch(input: string): Promise<Response>;
+"#;
+
+        let entries = parse_declarations("bun", content, "serve");
+        let found: Vec<_> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.path.as_str(),
+                    entry.kind.as_deref(),
+                    entry.summary.as_deref(),
+                    entry.score,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    "bun.serve",
+                    Some("function"),
+                    Some("Starts an HTTP server."),
+                    100
+                ),
+                (
+                    "bun.ServeOptions",
+                    Some("interface"),
+                    Some("Options of {@link serve}."),
+                    75
+                ),
+                ("bun.server", Some("const"), None, 75),
+            ]
+        );
+        assert_eq!(
+            entries[1].signature.as_deref(),
+            Some("export interface ServeOptions")
+        );
+    }
+
+    #[test]
+    fn names_the_package_of_a_declaration_file() {
+        let node_modules = Path::new("/app/node_modules");
+        let cases = [
+            ("/app/node_modules/hono/dist/types/index.d.ts", "hono"),
+            ("/app/node_modules/@types/bun/index.d.ts", "@types/bun"),
+        ];
+
+        for (file, expected) in cases {
+            assert_eq!(
+                package_name(node_modules, Path::new(file)),
+                expected,
+                "{file}"
+            );
+        }
+    }
+}
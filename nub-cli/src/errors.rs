@@ -15,15 +15,126 @@ pub enum CliError {
     #[error("Template directory {0} not found")]
     TemplateNotFound(String),
 
+    #[error("Unknown template {0}")]
+    UnknownTemplate(String),
+
+    #[error(
+        "No .nub/project.toml found in {0} or its parents, only projects created by nub can be updated"
+    )]
+    NoProvenance(String),
+
+    #[error("Uncommitted changes in {0}, commit or stash them first or use --force")]
+    UncommittedChanges(String),
+
+    #[error("{0} is not a JavaScript package manager, use bun, pnpm, npm or yarn")]
+    UnsupportedPackageManager(String),
+
+    #[error("--pm only applies to TypeScript projects, not {0} ones")]
+    PackageManagerNotApplicable(String),
+
     #[error("Non empty directory {0}")]
     NonEmptyDirectory(String),
 
+    #[error(
+        "Invalid project name {name}: {reason}. Try {suggestion} instead by passing `{flag} {suggestion}`"
+    )]
+    InvalidProjectName {
+        name: String,
+        reason: String,
+        suggestion: String,
+        /// Option setting the name, `--module` for Go and `--project-name` otherwise.
+        flag: &'static str,
+    },
+
+    #[error("No Rust, Go, TypeScript, Python, Zig or C++ project found in {0}")]
+    ProjectNotFound(String),
+
+    #[error("Deploying to {0} is not supported for {1} projects")]
+    UnsupportedDeployTarget(String, String),
+
+    #[error("No deployment configuration found, run `nub deploy init --target <target>` first")]
+    NoDeployTarget,
+
+    #[error("Missing {0}, run `nub deploy init --target {1}` first")]
+    MissingDeployConfig(String, String),
+
+    #[error("`{0}` is not installed")]
+    MissingCli(String),
+
+    #[error("Some required tools are missing or outdated, see the hints above")]
+    ToolchainIncomplete,
+
+    #[error("Not authenticated with {0}, run `{1}` first")]
+    DeployAuth(String, String),
+
+    #[error("Deployment to {0} failed while running `{1}`")]
+    DeployBuild(String, String),
+
+    #[error(
+        "Docker deployments need an image with its registry, e.g. `--image ghcr.io/<user>/{0}`"
+    )]
+    MissingImageRegistry(String),
+
+    #[error("No member of the {0} workspace builds a binary")]
+    NoWorkspaceBinary(String),
+
+    #[error("The {0} workspace builds several binaries ({1}), an image runs a single one")]
+    AmbiguousWorkspaceBinary(String, String),
+
+    #[error("Malformed file {0}")]
+    MalformedFile(String),
+
     #[error("Malformed go.mod")]
     MalformedGoMod,
 
+    #[error("Malformed template manifest {0}")]
+    MalformedManifest(String),
+
     #[error("Invalid file or directory name")]
     InvalidName,
 
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("Unknown configuration key {0}")]
+    UnknownConfigKey(String),
+
+    #[error("Configuration directory not found")]
+    ConfigDirNotFound,
+
+    #[error("No license given and no default license configured")]
+    MissingLicense,
+
+    #[error("Unknown concept {0}, run `nub docs compare` to list the available ones")]
+    UnknownConcept(String),
+
+    #[error("Snippet {0} not found")]
+    SnippetNotFound(String),
+
+    #[error("Snippet {0} already exists, use --force to overwrite it")]
+    SnippetExists(String),
+
+    #[error("Several snippets are named {0} ({1}), pick one with --lang")]
+    AmbiguousSnippet(String, String),
+
+    #[error("Snippet {0} is built in and cannot be removed")]
+    BuiltinSnippet(String),
+
+    #[error("File {0} not found")]
+    FileNotFound(String),
+
+    #[error("Nothing to review, pass files or make changes in a git repository")]
+    NothingToReview,
+
+    #[error("Model request failed: {0}")]
+    ModelRequest(String),
+
+    #[error("No {0} task for {1} projects, add it to the [tasks] table of .nub.toml")]
+    UnknownTask(String, String),
+
+    #[error("Task {0} failed with exit code {1}")]
+    TaskFailed(String, i32),
+
     #[error("Interactive prompt error: {0}")]
     InteractivePrompt(#[from] inquire::InquireError),
 
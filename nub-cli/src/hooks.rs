@@ -0,0 +1,316 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use serde::Deserialize;
+
+use crate::template::Language;
+
+/// Post-generation commands declared by a template or the user configuration.
+///
+/// A missing entry falls back to the language defaults while an empty list disables the step.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct HookConfig {
+    /// Generates sources the project needs to build, run before installing dependencies.
+    pub generate: Option<Vec<String>>,
+    pub install: Option<Vec<String>>,
+    pub format: Option<Vec<String>>,
+}
+
+impl HookConfig {
+    /// Returns a config where the entries set in `other` take precedence over `self`.
+    pub fn merge(self, other: HookConfig) -> HookConfig {
+        HookConfig {
+            generate: other.generate.or(self.generate),
+            install: other.install.or(self.install),
+            format: other.format.or(self.format),
+        }
+    }
+
+    /// Builds the ordered list of hooks to run for a freshly generated project.
+    pub fn hooks(&self, language: Language, options: HookOptions) -> Vec<Hook> {
+        let mut hooks = Vec::new();
+
+        if options.install {
+            if let Some(command) = self.generate.clone().filter(|command| !command.is_empty()) {
+                hooks.push(Hook::new(
+                    format!("Generating code ({})", command.join(" ")),
+                    vec![command],
+                ));
+            }
+
+            let command = self
+                .install
+                .clone()
+                .unwrap_or_else(|| default_command(language.install_command()));
+            if !command.is_empty() {
+                hooks.push(Hook::new(
+                    format!("Installing dependencies ({})", command.join(" ")),
+                    vec![command],
+                ));
+            }
+        }
+
+        if options.format {
+            let command = self
+                .format
+                .clone()
+                .unwrap_or_else(|| default_command(language.format_command()));
+            if !command.is_empty() {
+                hooks.push(Hook::new(
+                    format!("Formatting project ({})", command.join(" ")),
+                    vec![command],
+                ));
+            }
+        }
+
+        // Runs last so the initial commit includes lockfiles and formatted sources
+        if options.git {
+            hooks.push(Hook::new(
+                "Initializing git repository".to_string(),
+                vec![
+                    default_command(&["git", "init", "--quiet"]),
+                    default_command(&["git", "add", "--all"]),
+                    default_command(&["git", "commit", "--quiet", "-m", "Initial commit"]),
+                ],
+            ));
+        }
+
+        hooks
+    }
+}
+
+fn default_command(command: &[&str]) -> Vec<String> {
+    command.iter().map(|s| s.to_string()).collect()
+}
+
+/// Post-generation steps enabled from the command line.
+#[derive(Debug, Clone, Copy)]
+pub struct HookOptions {
+    pub git: bool,
+    pub install: bool,
+    pub format: bool,
+}
+
+/// A post-generation step made of one or more commands run in sequence.
+#[derive(Debug, Clone)]
+pub struct Hook {
+    description: String,
+    commands: Vec<Vec<String>>,
+}
+
+enum HookOutcome {
+    Done,
+    MissingTool(String),
+    Failed(String, String),
+}
+
+impl Hook {
+    fn new(description: String, commands: Vec<Vec<String>>) -> Self {
+        Self {
+            description,
+            commands,
+        }
+    }
+
+    fn run(&self, dir: &Path) -> HookOutcome {
+        for command in &self.commands {
+            let Some((program, args)) = command.split_first() else {
+                continue;
+            };
+
+            let output = Command::new(program)
+                .args(args)
+                .current_dir(dir)
+                .stdin(Stdio::null())
+                .output();
+
+            match output {
+                Ok(output) if output.status.success() => {}
+                Ok(output) => {
+                    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                    return HookOutcome::Failed(command.join(" "), stderr);
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    return HookOutcome::MissingTool(program.clone());
+                }
+                Err(err) => return HookOutcome::Failed(command.join(" "), err.to_string()),
+            }
+        }
+        HookOutcome::Done
+    }
+}
+
+/// Runs every hook in `dir`, reporting progress without aborting on failures.
+///
+/// The project is already generated at this point so a missing tool or a network
+/// failure only results in a warning.
+pub fn run_hooks(hooks: &[Hook], dir: &Path) {
+    if hooks.is_empty() {
+        return;
+    }
+
+    println!();
+    for hook in hooks {
+        print!("> {}... ", hook.description);
+        let _ = io::stdout().flush();
+
+        match hook.run(dir) {
+            HookOutcome::Done => println!("done"),
+            HookOutcome::MissingTool(program) => {
+                println!("skipped, `{program}` is not installed")
+            }
+            HookOutcome::Failed(command, stderr) => {
+                println!("failed");
+                if !stderr.is_empty() {
+                    eprintln!("{stderr}");
+                }
+                eprintln!("  Run `{command}` manually once the issue is fixed");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: HookOptions = HookOptions {
+        git: true,
+        install: true,
+        format: true,
+    };
+
+    fn command(command: &str) -> Option<Vec<String>> {
+        Some(command.split_whitespace().map(String::from).collect())
+    }
+
+    fn commands(hooks: &[Hook]) -> Vec<String> {
+        hooks
+            .iter()
+            .flat_map(|hook| &hook.commands)
+            .map(|command| command.join(" "))
+            .collect()
+    }
+
+    #[test]
+    fn merge_prefers_the_entries_set_in_other() {
+        let template = HookConfig {
+            generate: command("buf generate"),
+            install: command("bun install"),
+            format: command("biome format"),
+        };
+        let config = HookConfig {
+            install: command("pnpm install"),
+            format: Some(Vec::new()),
+            ..HookConfig::default()
+        };
+
+        let merged = template.merge(config);
+        assert_eq!(merged.generate, command("buf generate"));
+        assert_eq!(merged.install, command("pnpm install"));
+        assert_eq!(merged.format, Some(Vec::new()));
+    }
+
+    #[test]
+    fn merge_keeps_self_when_other_is_empty() {
+        let template = HookConfig {
+            install: command("go mod download"),
+            ..HookConfig::default()
+        };
+
+        let merged = template.merge(HookConfig::default());
+        assert_eq!(merged.generate, None);
+        assert_eq!(merged.install, command("go mod download"));
+        assert_eq!(merged.format, None);
+    }
+
+    #[test]
+    fn hooks_follow_template_then_config_then_install_precedence() {
+        let template = HookConfig {
+            generate: command("buf generate"),
+            install: command("bun install"),
+            format: command("biome format --write ."),
+        };
+        let config = HookConfig {
+            install: command("npm install"),
+            ..HookConfig::default()
+        };
+        // The package manager picked on the command line, as merged by `nub new`
+        let install = HookConfig {
+            install: command("pnpm install"),
+            ..HookConfig::default()
+        };
+
+        let hooks = template
+            .merge(config)
+            .merge(install)
+            .hooks(Language::Typescript, ALL);
+        assert_eq!(
+            commands(&hooks),
+            [
+                "buf generate",
+                "pnpm install",
+                "biome format --write .",
+                "git init --quiet",
+                "git add --all",
+                "git commit --quiet -m Initial commit",
+            ]
+        );
+    }
+
+    #[test]
+    fn hooks_default_to_the_language_commands() {
+        let hooks = HookConfig::default().hooks(Language::Rust, ALL);
+        let descriptions: Vec<&str> = hooks.iter().map(|hook| hook.description.as_str()).collect();
+        assert_eq!(
+            descriptions,
+            [
+                "Installing dependencies (cargo fetch)",
+                "Formatting project (cargo fmt)",
+                "Initializing git repository",
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_commands_disable_their_step() {
+        let config = HookConfig {
+            generate: Some(Vec::new()),
+            install: Some(Vec::new()),
+            format: Some(Vec::new()),
+        };
+        assert_eq!(commands(&config.hooks(Language::Go, ALL)).len(), 3);
+
+        // TypeScript has no default formatter
+        let hooks = HookConfig::default().hooks(Language::Typescript, ALL);
+        assert_eq!(commands(&hooks)[0], "bun install");
+        assert_eq!(hooks.len(), 2);
+    }
+
+    #[test]
+    fn disabled_options_produce_no_hooks() {
+        let config = HookConfig {
+            generate: command("buf generate"),
+            ..HookConfig::default()
+        };
+        let none = HookOptions {
+            git: false,
+            install: false,
+            format: false,
+        };
+        assert!(config.hooks(Language::Go, none).is_empty());
+
+        let format_only = HookOptions {
+            format: true,
+            ..none
+        };
+        assert_eq!(
+            commands(&config.hooks(Language::Go, format_only)),
+            ["go fmt ./..."]
+        );
+    }
+}
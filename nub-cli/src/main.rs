@@ -1,16 +1,33 @@
 mod commands;
+mod config;
+mod deploy;
+mod detect;
+mod docker;
+mod docs;
 mod emoji;
 mod errors;
+mod hooks;
+mod provenance;
+mod review;
+mod snippet;
+mod tasks;
 mod template;
+mod toolchain;
+mod tooling;
+mod update;
+mod validation;
 
+use clap::Parser;
 use commands::Cli;
 use emoji::{CRY_WAVE, DEAL_WITH_IT, EXCITED, FLIP_TABLE};
 use errors::CliError;
 use inquire::InquireError;
 
 fn main() {
-    match Cli::run() {
-        Ok(_) => println!("\n{EXCITED}"),
+    let cli = Cli::parse();
+    match cli.run() {
+        Ok(_) if cli.is_interactive() => println!("\n{EXCITED}"),
+        Ok(_) => {}
         Err(CliError::InteractivePrompt(err)) => {
             match err {
                 InquireError::OperationCanceled => println!("\n{FLIP_TABLE}"),
@@ -19,11 +36,29 @@ fn main() {
             }
             std::process::exit(1);
         }
+        Err(CliError::TaskFailed(task, code)) => {
+            eprintln!("{CRY_WAVE}\n\n{task} failed with exit code {code}");
+            std::process::exit(code);
+        }
+        Err(err @ CliError::ToolchainIncomplete) => {
+            eprintln!("{CRY_WAVE}\n\n{err}");
+            std::process::exit(1);
+        }
         Err(CliError::NonEmptyDirectory(_)) => {
             eprintln!("{CRY_WAVE}\n\nNub needs an empty directory");
         }
+        Err(CliError::InvalidProjectName {
+            name,
+            reason,
+            suggestion,
+            flag,
+        }) => {
+            eprintln!("{CRY_WAVE}\n\n{name} is not a valid project name: {reason}");
+            eprintln!("Try {suggestion} instead by passing `{flag} {suggestion}`");
+        }
         Err(err) => {
             eprintln!("{DEAL_WITH_IT}\n\nOops, something unexpected happened: {err}");
+            std::process::exit(1);
         }
     }
 }
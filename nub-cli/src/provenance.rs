@@ -0,0 +1,137 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::CliError,
+    template::{Language, RenderedFile, Template, TemplateVariables},
+};
+
+/// Provenance record, relative to the project root.
+pub const PROVENANCE_FILE: &str = ".nub/project.toml";
+
+/// Template files as last rendered, relative to the project root.
+pub const SNAPSHOT_FILE: &str = ".nub/template.json";
+
+const HEADER: &str = "# Written by nub when the project was created, commit it to keep track of the template.\n\
+                      # Reproduce the project with `nub project create --from .nub/project.toml`.\n\n";
+
+/// The template and answers a project was generated from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Provenance {
+    /// Template identifier, e.g. `rust/axum-basic`.
+    pub template: String,
+    /// SHA-256 of the template files, telling whether the template changed since.
+    pub template_hash: String,
+    /// Version of nub that generated the project.
+    pub nub_version: String,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+    /// RFC 3339 timestamp of the last `nub project update`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    pub variables: TemplateVariables,
+}
+
+impl Provenance {
+    pub fn new(template_path: &str, template_hash: String, variables: &TemplateVariables) -> Self {
+        Self {
+            template: template_path.to_string(),
+            template_hash,
+            nub_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: chrono::Local::now().to_rfc3339(),
+            updated_at: None,
+            variables: variables.clone(),
+        }
+    }
+
+    /// Reads the record of the project rooted at `root`, if it has one.
+    pub fn load(root: &Path) -> Result<Option<Self>, CliError> {
+        let path = root.join(PROVENANCE_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Self::read(&path).map(Some)
+    }
+
+    pub fn read(path: &Path) -> Result<Self, CliError> {
+        let content = fs::read_to_string(path)
+            .map_err(|_| CliError::FileNotFound(path.display().to_string()))?;
+        toml::from_str(&content).map_err(|_| CliError::MalformedFile(path.display().to_string()))
+    }
+
+    pub fn save(&self, root: &Path) -> Result<(), CliError> {
+        let path = root.join(PROVENANCE_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = toml::to_string(self)
+            .map_err(|_| CliError::MalformedFile(path.display().to_string()))?;
+        fs::write(&path, format!("{HEADER}{content}"))?;
+        Ok(())
+    }
+
+    /// Records that the project now follows the current version of its template.
+    pub fn mark_updated(&mut self, template_hash: String) {
+        self.template_hash = template_hash;
+        self.nub_version = env!("CARGO_PKG_VERSION").to_string();
+        self.updated_at = Some(chrono::Local::now().to_rfc3339());
+    }
+
+    /// The recorded template, split into its language and name.
+    pub fn template(&self) -> Option<Template> {
+        let (language, name) = self.template.split_once('/')?;
+        Some(Template {
+            name: name.to_string(),
+            language: language.parse::<Language>().ok()?,
+        })
+    }
+}
+
+/// The text files of a template as rendered for the project, the common ancestor when
+/// `nub project update` merges a newer version of the template into the project.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TemplateSnapshot {
+    /// Contents keyed by path relative to the project root, with `/` separators.
+    pub files: BTreeMap<String, String>,
+}
+
+impl TemplateSnapshot {
+    pub fn new(files: &[RenderedFile]) -> Self {
+        let files = files
+            .iter()
+            .filter_map(|file| {
+                let content = String::from_utf8(file.content.clone()).ok()?;
+                Some((snapshot_path(&file.path), content))
+            })
+            .collect();
+        Self { files }
+    }
+
+    pub fn load(root: &Path) -> Result<Option<Self>, CliError> {
+        let path = root.join(SNAPSHOT_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)?;
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|_| CliError::MalformedFile(path.display().to_string()))
+    }
+
+    pub fn save(&self, root: &Path) -> Result<(), CliError> {
+        let path = root.join(SNAPSHOT_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|_| CliError::MalformedFile(path.display().to_string()))?;
+        fs::write(&path, content + "\n")?;
+        Ok(())
+    }
+}
+
+/// Path of a rendered file as stored in the snapshot, the same on every platform.
+pub fn snapshot_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
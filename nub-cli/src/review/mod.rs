@@ -0,0 +1,460 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{errors::CliError, template::Language};
+
+mod openai;
+
+pub use openai::OpenAiCompatible;
+
+/// Ollama's OpenAI-compatible endpoint, so reviews stay local unless configured otherwise.
+pub const DEFAULT_BASE_URL: &str = "http://localhost:11434/v1";
+
+pub const DEFAULT_MODEL: &str = "qwen2.5-coder";
+
+/// Upper bound on the reviewed content, small local models have limited context windows.
+const MAX_INPUT_CHARS: usize = 60_000;
+
+/// Directories never worth reviewing when a directory is given.
+static SKIPPED_DIRS: &[&str] = &[
+    "target",
+    "node_modules",
+    "vendor",
+    "dist",
+    "build",
+    "zig-out",
+    "__pycache__",
+];
+
+/// A chat message sent to the model.
+#[derive(Debug, Clone)]
+pub struct Message {
+    pub role: &'static str,
+    pub content: String,
+}
+
+impl Message {
+    pub fn system(content: String) -> Self {
+        Self {
+            role: "system",
+            content,
+        }
+    }
+
+    pub fn user(content: String) -> Self {
+        Self {
+            role: "user",
+            content,
+        }
+    }
+}
+
+/// A chat completion backend.
+///
+/// Kept minimal so a local server, a hosted API or a test stub can stand behind it.
+pub trait ModelProvider {
+    fn complete(&self, messages: &[Message]) -> Result<String, CliError>;
+}
+
+/// Code sent for review, either a diff or whole files.
+pub struct ReviewInput {
+    pub kind: InputKind,
+    /// Content annotated with line numbers so findings can point at them.
+    pub content: String,
+    pub languages: BTreeSet<Language>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Diff,
+    Files,
+}
+
+impl ReviewInput {
+    /// Collects the uncommitted changes of the current repository.
+    pub fn from_git_diff() -> Result<Self, CliError> {
+        let output = Command::new("git")
+            .args(["diff", "HEAD", "--no-color", "--no-ext-diff"])
+            .output()
+            .map_err(|_| CliError::MissingCli("git".to_string()))?;
+        if !output.status.success() {
+            return Err(CliError::NothingToReview);
+        }
+
+        let diff = String::from_utf8_lossy(&output.stdout);
+        let languages = diff
+            .lines()
+            .filter_map(|line| line.strip_prefix("+++ b/"))
+            .filter_map(|path| Language::from_path(Path::new(path)))
+            .collect();
+        Self::new(InputKind::Diff, annotate_diff(&diff), languages)
+    }
+
+    /// Collects the named files, walking directories for source files.
+    pub fn from_paths(paths: &[PathBuf]) -> Result<Self, CliError> {
+        let mut files = Vec::new();
+        for path in paths {
+            if path.is_dir() {
+                collect_source_files(path, &mut files);
+            } else if path.is_file() {
+                files.push(path.clone());
+            } else {
+                return Err(CliError::FileNotFound(path.display().to_string()));
+            }
+        }
+
+        let mut content = String::new();
+        let mut languages = BTreeSet::new();
+        for file in &files {
+            let Ok(text) = fs::read_to_string(file) else {
+                // Binary files cannot be reviewed
+                continue;
+            };
+            languages.extend(Language::from_path(file));
+            content.push_str(&format!("=== {}\n", file.display()));
+            for (index, line) in text.lines().enumerate() {
+                content.push_str(&format!("{:>5}| {line}\n", index + 1));
+            }
+            content.push('\n');
+        }
+        Self::new(InputKind::Files, content, languages)
+    }
+
+    fn new(
+        kind: InputKind,
+        mut content: String,
+        languages: BTreeSet<Language>,
+    ) -> Result<Self, CliError> {
+        if content.trim().is_empty() {
+            return Err(CliError::NothingToReview);
+        }
+
+        let truncated = content.len() > MAX_INPUT_CHARS;
+        if truncated {
+            let end = (0..=MAX_INPUT_CHARS)
+                .rev()
+                .find(|index| content.is_char_boundary(*index))
+                .unwrap_or_default();
+            content.truncate(end);
+        }
+        Ok(Self {
+            kind,
+            content,
+            languages,
+            truncated,
+        })
+    }
+
+    /// Builds the conversation asking the model for anchored findings.
+    pub fn messages(&self) -> Vec<Message> {
+        let mut system = String::from(
+            "You are a meticulous senior engineer reviewing code. \
+             Point out bugs, security issues, error handling mistakes and clear improvements. \
+             Skip style nitpicks a formatter would fix.\n\n",
+        );
+        for language in &self.languages {
+            system.push_str(&format!("{language}: {}\n", guidelines(*language)));
+        }
+        system.push_str(
+            "\nReply with one finding per line formatted exactly as\n\
+             path:line: severity: message\n\
+             where severity is one of error, warning or note and line is the number shown \
+             on the left of the code. Reply with nothing else. \
+             If there is nothing to report, reply with an empty message.",
+        );
+
+        let subject = match self.kind {
+            InputKind::Diff => {
+                "Review the added lines of this diff, removed lines are shown for context only."
+            }
+            InputKind::Files => "Review these files.",
+        };
+        vec![
+            Message::system(system),
+            Message::user(format!("{subject}\n\n{}", self.content)),
+        ]
+    }
+}
+
+/// What reviewers of each language look for first.
+fn guidelines(language: Language) -> &'static str {
+    match language {
+        Language::Cpp => {
+            "check for memory and lifetime bugs such as dangling references or use after \
+             move, raw new and delete instead of RAII, undefined behavior, and missing bounds checks"
+        }
+        Language::Go => {
+            "check that errors are handled or wrapped with %w, goroutines cannot leak, \
+             contexts are propagated, and resources are closed with defer"
+        }
+        Language::Python => {
+            "check for missing type hints on public functions, bare except clauses, mutable \
+             default arguments, unclosed resources outside with blocks, and blocking calls in async code"
+        }
+        Language::Rust => {
+            "check for unwrap or expect that can panic on user input, needless clones and \
+             allocations, blocking calls in async code, and unsafe code without justification"
+        }
+        Language::Typescript => {
+            "check for any types, unhandled promise rejections, missing await, unchecked \
+             JSON input, and null or undefined access"
+        }
+        Language::Zig => {
+            "check that errors are handled rather than discarded with catch unreachable, \
+             allocations are freed with defer or errdefer, and slices are not used after their memory is freed"
+        }
+    }
+}
+
+fn collect_source_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|e| e.path())
+        .collect();
+    paths.sort();
+    for path in paths {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        if path.is_dir() {
+            if !name.starts_with('.') && !SKIPPED_DIRS.contains(&name) {
+                collect_source_files(&path, files);
+            }
+        } else if Language::from_path(&path).is_some() {
+            files.push(path);
+        }
+    }
+}
+
+/// Prefixes added and context lines of a unified diff with their line number in the new file.
+fn annotate_diff(diff: &str) -> String {
+    let mut annotated = String::with_capacity(diff.len());
+    let mut line_number: Option<usize> = None;
+
+    for line in diff.lines() {
+        if let Some(file) = line.strip_prefix("+++ b/") {
+            annotated.push_str(&format!("=== {file}\n"));
+            line_number = None;
+            continue;
+        }
+        let is_header = [
+            "diff --git",
+            "index ",
+            "--- a/",
+            "--- /dev/null",
+            "+++ /dev/null",
+        ]
+        .iter()
+        .any(|prefix| line.starts_with(prefix));
+        if is_header {
+            continue;
+        }
+        if let Some(hunk) = line.strip_prefix("@@ ") {
+            // @@ -12,4 +12,6 @@ fn main()
+            line_number = hunk
+                .split_whitespace()
+                .find_map(|range| range.strip_prefix('+'))
+                .and_then(|range| range.split(',').next())
+                .and_then(|start| start.parse().ok());
+            annotated.push_str(line);
+            annotated.push('\n');
+            continue;
+        }
+
+        match line_number.as_mut() {
+            // Removed lines and "\ No newline at end of file" markers have no new line number
+            Some(_) if line.starts_with('-') || line.starts_with('\\') => {
+                annotated.push_str(&format!("     | {line}\n"));
+            }
+            Some(number) => {
+                annotated.push_str(&format!("{:>5}| {line}\n", *number));
+                *number += 1;
+            }
+            None => {
+                annotated.push_str(line);
+                annotated.push('\n');
+            }
+        }
+    }
+    annotated
+}
+
+/// A review comment anchored to a line when the model gave one.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub path: Option<String>,
+    pub line: Option<usize>,
+    pub severity: Option<String>,
+    pub message: String,
+}
+
+/// Parses `path:line: severity: message` lines, keeping any other text as unanchored notes.
+pub fn parse_findings(response: &str) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = response
+        .lines()
+        .map(|line| {
+            line.trim()
+                .trim_start_matches(['-', '*'])
+                .trim()
+                .trim_matches('`')
+        })
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            parse_finding(line).unwrap_or_else(|| Finding {
+                path: None,
+                line: None,
+                severity: None,
+                message: line.to_string(),
+            })
+        })
+        .collect();
+    // Anchored findings first, in file order, general remarks last
+    findings.sort_by(|a, b| {
+        (a.path.is_none(), &a.path, a.line).cmp(&(b.path.is_none(), &b.path, b.line))
+    });
+    findings
+}
+
+fn parse_finding(line: &str) -> Option<Finding> {
+    let mut parts = line.splitn(3, ':');
+    let path = parts.next()?.trim();
+    let line_number = parts.next()?.trim().parse().ok()?;
+    let rest = parts.next()?.trim();
+    if path.is_empty() || path.contains(' ') {
+        return None;
+    }
+
+    let (severity, message) = match rest.split_once(':') {
+        Some((severity, message))
+            if ["error", "warning", "note"].contains(&severity.trim().to_lowercase().as_str()) =>
+        {
+            (Some(severity.trim().to_lowercase()), message.trim())
+        }
+        _ => (None, rest),
+    };
+    Some(Finding {
+        path: Some(path.to_string()),
+        line: Some(line_number),
+        severity,
+        message: message.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers every request with a canned response.
+    struct StubProvider(&'static str);
+
+    impl ModelProvider for StubProvider {
+        fn complete(&self, _messages: &[Message]) -> Result<String, CliError> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn parses_findings_from_the_model_response() {
+        let provider = StubProvider(
+            "- `src/main.rs:12: warning: unwrap can panic`\n\
+             \n\
+             src/lib.rs:3: Error: missing await\n\
+             Overall the change looks fine\n\
+             src/lib.rs:1: possible overflow\n",
+        );
+        let input =
+            ReviewInput::new(InputKind::Files, "code".to_string(), BTreeSet::new()).unwrap();
+        let response = provider.complete(&input.messages()).unwrap();
+        let findings = parse_findings(&response);
+
+        let summary: Vec<_> = findings
+            .iter()
+            .map(|finding| {
+                (
+                    finding.path.as_deref(),
+                    finding.line,
+                    finding.severity.as_deref(),
+                    finding.message.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Some("src/lib.rs"), Some(1), None, "possible overflow"),
+                (Some("src/lib.rs"), Some(3), Some("error"), "missing await"),
+                (
+                    Some("src/main.rs"),
+                    Some(12),
+                    Some("warning"),
+                    "unwrap can panic"
+                ),
+                (None, None, None, "Overall the change looks fine"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_lines_that_are_not_anchored() {
+        let cases = [
+            "Note: this is prose",
+            "see the docs at http://example.com",
+            "src/main.rs: missing line",
+            "some file.rs:3: spaces in path",
+        ];
+        for line in cases {
+            assert!(parse_finding(line).is_none(), "{line}");
+        }
+    }
+
+    #[test]
+    fn annotates_diffs_with_new_line_numbers() {
+        let diff = "\
+diff --git a/src/main.rs b/src/main.rs
+index 1111111..2222222 100644
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -10,3 +10,4 @@ fn main() {
+     let a = 1;
+-    let b = 2;
++    let b = 3;
++    let c = 4;
+\\ No newline at end of file
+";
+        let expected = "\
+=== src/main.rs
+@@ -10,3 +10,4 @@ fn main() {
+   10|      let a = 1;
+     | -    let b = 2;
+   11| +    let b = 3;
+   12| +    let c = 4;
+     | \\ No newline at end of file
+";
+        assert_eq!(annotate_diff(diff), expected);
+    }
+
+    #[test]
+    fn truncates_large_inputs_on_char_boundaries() {
+        let content = "é".repeat(MAX_INPUT_CHARS);
+        let input = ReviewInput::new(InputKind::Files, content, BTreeSet::new()).unwrap();
+        assert!(input.truncated);
+        assert!(input.content.len() <= MAX_INPUT_CHARS);
+    }
+
+    #[test]
+    fn empty_inputs_have_nothing_to_review() {
+        assert!(matches!(
+            ReviewInput::new(InputKind::Diff, " \n".to_string(), BTreeSet::new()),
+            Err(CliError::NothingToReview)
+        ));
+    }
+}
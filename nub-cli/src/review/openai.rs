@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::json;
+use ureq::Agent;
+
+use crate::errors::CliError;
+
+use super::{Message, ModelProvider};
+
+/// Local models can take minutes to answer on modest hardware.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// A server implementing the OpenAI chat completions API, e.g. Ollama, llama.cpp or OpenAI itself.
+pub struct OpenAiCompatible {
+    base_url: String,
+    model: String,
+    api_key: Option<String>,
+    agent: Agent,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<Choice>,
+}
+
+#[derive(Deserialize)]
+struct Choice {
+    message: ChoiceMessage,
+}
+
+#[derive(Deserialize)]
+struct ChoiceMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+impl OpenAiCompatible {
+    pub fn new(base_url: &str, model: &str, api_key: Option<String>) -> Self {
+        let agent = Agent::config_builder()
+            .timeout_global(Some(REQUEST_TIMEOUT))
+            .build()
+            .into();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            model: model.to_string(),
+            api_key,
+            agent,
+        }
+    }
+}
+
+impl ModelProvider for OpenAiCompatible {
+    fn complete(&self, messages: &[Message]) -> Result<String, CliError> {
+        let body = json!({
+            "model": self.model,
+            "temperature": 0.2,
+            "messages": messages
+                .iter()
+                .map(|message| json!({ "role": message.role, "content": message.content }))
+                .collect::<Vec<_>>(),
+        });
+
+        let mut request = self
+            .agent
+            .post(format!("{}/chat/completions", self.base_url));
+        if let Some(api_key) = &self.api_key {
+            request = request.header("Authorization", format!("Bearer {api_key}"));
+        }
+        let mut response = request
+            .send_json(&body)
+            .map_err(|err| CliError::ModelRequest(format!("{} ({err})", self.base_url)))?;
+
+        let response: ChatResponse = response
+            .body_mut()
+            .read_json()
+            .map_err(|err| CliError::ModelRequest(err.to_string()))?;
+        response
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message.content.unwrap_or_default())
+            .ok_or_else(|| CliError::ModelRequest("the response has no choices".to_string()))
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+
+use crate::{deploy::GeneratedFile, template::Language};
+
+use super::{Segment, Snippet};
+
+/// An editor whose native snippet format nub can export to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Editor {
+    Vscode,
+    Zed,
+    Helix,
+    Neovim,
+}
+
+impl std::fmt::Display for Editor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Vscode => write!(f, "VS Code"),
+            Self::Zed => write!(f, "Zed"),
+            Self::Helix => write!(f, "Helix"),
+            Self::Neovim => write!(f, "Neovim"),
+        }
+    }
+}
+
+impl Editor {
+    /// Converts snippets into files relative to the output directory.
+    pub fn export(&self, snippets: &[Snippet]) -> Vec<GeneratedFile> {
+        match self {
+            Self::Vscode => vec![GeneratedFile::new(
+                ".vscode/nub.code-snippets",
+                vscode(snippets),
+            )],
+            Self::Zed => zed(snippets),
+            Self::Helix => vec![GeneratedFile::new("snippets/nub.toml", helix(snippets))],
+            Self::Neovim => vec![GeneratedFile::new("lua/nub_snippets.lua", neovim(snippets))],
+        }
+    }
+
+    /// Explains where the exported files are picked up.
+    pub fn install_hint(&self) -> &'static str {
+        match self {
+            Self::Vscode => "VS Code loads .vscode/nub.code-snippets from the workspace",
+            Self::Zed => "Export with --output ~/.config/zed to make the snippets available in Zed",
+            Self::Helix => {
+                "Export with --output ~/.config/helix and use simple-completion-language-server to complete them"
+            }
+            Self::Neovim => {
+                "Export with --output ~/.config/nvim and add require(\"nub_snippets\") after LuaSnip is set up"
+            }
+        }
+    }
+}
+
+/// Rewrites placeholders as LSP tabstops, e.g. `{{name:default}}` becomes `${1:default}`.
+///
+/// Repeated placeholders share their tabstop so editors mirror the edits.
+fn to_tabstops(snippet: &Snippet) -> String {
+    let mut tabstops: Vec<&str> = Vec::new();
+    let mut body = String::new();
+    for segment in snippet.segments() {
+        match segment {
+            Segment::Text(text) => body.push_str(&escape(text, &['$', '\\'])),
+            Segment::Placeholder { name, default } => {
+                let index = match tabstops.iter().position(|known| *known == name) {
+                    Some(index) => index + 1,
+                    None => {
+                        tabstops.push(name);
+                        tabstops.len()
+                    }
+                };
+                let text = default.unwrap_or(name);
+                body.push_str(&format!("${{{index}:{}}}", escape(text, &['$', '}', '\\'])));
+            }
+        }
+    }
+    body.trim_end().to_string()
+}
+
+fn escape(text: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if special.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// VS Code scopes snippets with language identifiers, TypeScript also covers TSX files.
+fn vscode_scope(language: Language) -> &'static str {
+    match language {
+        Language::Cpp => "c,cpp",
+        Language::Go => "go",
+        Language::Python => "python",
+        Language::Rust => "rust",
+        Language::Typescript => "typescript,typescriptreact",
+        Language::Zig => "zig",
+    }
+}
+
+fn json_entry(snippet: &Snippet) -> Map<String, Value> {
+    let body: Vec<String> = to_tabstops(snippet).lines().map(String::from).collect();
+    let mut entry = Map::new();
+    entry.insert("prefix".to_string(), json!(snippet.name));
+    entry.insert("body".to_string(), json!(body));
+    entry.insert("description".to_string(), json!(snippet.description));
+    entry
+}
+
+fn vscode(snippets: &[Snippet]) -> String {
+    let mut file = Map::new();
+    for snippet in snippets {
+        let mut entry = json_entry(snippet);
+        entry.insert("scope".to_string(), json!(vscode_scope(snippet.language)));
+        // Names must be unique across languages in a single file
+        file.insert(
+            format!("{} ({})", snippet.name, snippet.language),
+            Value::Object(entry),
+        );
+    }
+    to_json(file)
+}
+
+/// Zed reads one `snippets/<language>.json` file per language.
+fn zed(snippets: &[Snippet]) -> Vec<GeneratedFile> {
+    let mut files: BTreeMap<Language, Map<String, Value>> = BTreeMap::new();
+    for snippet in snippets {
+        files
+            .entry(snippet.language)
+            .or_default()
+            .insert(snippet.name.clone(), Value::Object(json_entry(snippet)));
+    }
+    files
+        .into_iter()
+        .map(|(language, file)| {
+            GeneratedFile::new(&format!("snippets/{language}.json"), to_json(file))
+        })
+        .collect()
+}
+
+fn to_json(file: Map<String, Value>) -> String {
+    let mut content = serde_json::to_string_pretty(&Value::Object(file)).unwrap_or_default();
+    content.push('\n');
+    content
+}
+
+#[derive(Serialize)]
+struct HelixFile<'a> {
+    snippets: Vec<HelixSnippet<'a>>,
+}
+
+/// Helix has no built-in snippets, this is the format of simple-completion-language-server.
+#[derive(Serialize)]
+struct HelixSnippet<'a> {
+    prefix: &'a str,
+    scope: Vec<String>,
+    body: String,
+    description: &'a str,
+}
+
+fn helix(snippets: &[Snippet]) -> String {
+    let file = HelixFile {
+        snippets: snippets
+            .iter()
+            .map(|snippet| HelixSnippet {
+                prefix: &snippet.name,
+                scope: vec![snippet.language.to_string()],
+                body: to_tabstops(snippet),
+                description: &snippet.description,
+            })
+            .collect(),
+    };
+    toml::to_string(&file).unwrap_or_default()
+}
+
+/// LuaSnip parses LSP snippets, so bodies are passed through `ls.parser.parse_snippet`.
+fn neovim(snippets: &[Snippet]) -> String {
+    let mut languages: BTreeMap<Language, Vec<&Snippet>> = BTreeMap::new();
+    for snippet in snippets {
+        languages.entry(snippet.language).or_default().push(snippet);
+    }
+
+    let mut lua = String::from(
+        "-- Generated by `nub snippet export --editor neovim`\n\
+         local ls = require(\"luasnip\")\n\
+         local parse = ls.parser.parse_snippet\n",
+    );
+    for (language, snippets) in languages {
+        lua.push_str(&format!("\nls.add_snippets(\"{language}\", {{\n"));
+        for snippet in snippets {
+            lua.push_str(&format!(
+                "  parse({{ trig = {}, desc = {} }}, {}),\n",
+                lua_string(&snippet.name),
+                lua_string(&snippet.description),
+                lua_long_string(&to_tabstops(snippet)),
+            ));
+        }
+        lua.push_str("})\n");
+    }
+    lua
+}
+
+fn lua_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Wraps text in a `[==[ ... ]==]` long string whose level does not appear in the text.
+fn lua_long_string(text: &str) -> String {
+    let level = (0..)
+        .map(|level| "=".repeat(level))
+        .find(|equals| !text.contains(&format!("]{equals}]")))
+        .unwrap_or_default();
+    // A newline right after the opening bracket is skipped by Lua, keeping the body intact
+    format!("[{level}[\n{text}]{level}]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snippet::SnippetSource;
+
+    fn snippet(body: &str) -> Snippet {
+        Snippet {
+            name: "test".to_string(),
+            language: Language::Rust,
+            description: String::new(),
+            tags: Vec::new(),
+            body: body.to_string(),
+            source: SnippetSource::User,
+        }
+    }
+
+    #[test]
+    fn converts_placeholders_to_tabstops() {
+        let cases = [
+            ("fn {{name}}() {}", "fn ${1:name}() {}"),
+            ("{{ty:u32}}", "${1:u32}"),
+            // Repeated placeholders share their tabstop
+            (
+                "let {{name}} = {{value:0}};\n{{name}} += 1;\n",
+                "let ${1:name} = ${2:0};\n${1:name} += 1;",
+            ),
+            // `$` and `\` are literal in text, `}` also ends a default
+            ("echo $HOME \\n", "echo \\$HOME \\\\n"),
+            ("{{rest:} else {}}", "${1:\\} else {}"),
+            ("{{price:$5}}", "${1:\\$5}"),
+        ];
+        for (body, expected) in cases {
+            assert_eq!(to_tabstops(&snippet(body)), expected, "{body}");
+        }
+    }
+
+    #[test]
+    fn picks_a_long_string_level_absent_from_the_text() {
+        let cases = [
+            ("print(1)", "[[\nprint(1)]]"),
+            ("local t = a[b[1]]", "[=[\nlocal t = a[b[1]]]=]"),
+            ("x = t[u[1]] .. \"]=]\"", "[==[\nx = t[u[1]] .. \"]=]\"]==]"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(lua_long_string(text), expected, "{text}");
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use include_dir::{Dir, include_dir};
+use serde::{Deserialize, Serialize};
+
+use crate::{config, errors::CliError, template::Language};
+
+mod export;
+
+pub use export::Editor;
+
+/// Snippets shipped with nub, stored as `<language>/<name>.toml`.
+static BUILTIN_SNIPPETS: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/../snippets");
+
+/// Where a snippet comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnippetSource {
+    Builtin,
+    User,
+}
+
+/// A reusable piece of code whose body may contain `{{name}}` or `{{name:default}}` placeholders.
+#[derive(Debug, Clone)]
+pub struct Snippet {
+    pub name: String,
+    pub language: Language,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub body: String,
+    pub source: SnippetSource,
+}
+
+/// On-disk representation of a snippet, the name and language come from its path.
+#[derive(Deserialize, Serialize)]
+struct SnippetFile {
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    body: String,
+}
+
+/// A piece of a snippet body.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Segment<'a> {
+    Text(&'a str),
+    Placeholder {
+        name: &'a str,
+        default: Option<&'a str>,
+    },
+}
+
+impl Snippet {
+    /// Splits the body into literal text and placeholders.
+    pub fn segments(&self) -> Vec<Segment<'_>> {
+        parse_segments(&self.body)
+    }
+
+    /// Returns each distinct placeholder with its default, in order of first appearance.
+    pub fn placeholders(&self) -> Vec<(&str, Option<&str>)> {
+        let mut placeholders: Vec<(&str, Option<&str>)> = Vec::new();
+        for segment in self.segments() {
+            if let Segment::Placeholder { name, default } = segment {
+                match placeholders.iter_mut().find(|(known, _)| *known == name) {
+                    Some((_, known_default)) => *known_default = known_default.or(default),
+                    None => placeholders.push((name, default)),
+                }
+            }
+        }
+        placeholders
+    }
+
+    /// Replaces placeholders with the given values, falling back to their defaults.
+    pub fn render(&self, values: &BTreeMap<String, String>) -> String {
+        let defaults = self.placeholders();
+        self.segments()
+            .into_iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text,
+                Segment::Placeholder { name, .. } => values
+                    .get(name)
+                    .map(String::as_str)
+                    .or_else(|| {
+                        defaults
+                            .iter()
+                            .find(|(known, _)| *known == name)
+                            .and_then(|(_, default)| *default)
+                    })
+                    .unwrap_or(name),
+            })
+            .collect()
+    }
+
+    fn parse(
+        name: &str,
+        language: Language,
+        content: &str,
+        source: SnippetSource,
+    ) -> Result<Self, CliError> {
+        let file: SnippetFile = toml::from_str(content)
+            .map_err(|_| CliError::MalformedFile(format!("{language}/{name}.toml")))?;
+        Ok(Self {
+            name: name.to_string(),
+            language,
+            description: file.description,
+            tags: file.tags,
+            body: file.body,
+            source,
+        })
+    }
+}
+
+/// Parses `{{name}}` and `{{name:default}}` placeholders, anything else is kept as text.
+fn parse_segments(body: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let placeholder = rest[start + 2..]
+            .find("}}")
+            .map(|end| &rest[start + 2..start + 2 + end])
+            .and_then(|inner| {
+                let (name, default) = match inner.split_once(':') {
+                    Some((name, default)) => (name, Some(default)),
+                    None => (inner, None),
+                };
+                is_placeholder_name(name).then_some((inner.len(), name, default))
+            });
+
+        match placeholder {
+            Some((len, name, default)) => {
+                if start > 0 {
+                    segments.push(Segment::Text(&rest[..start]));
+                }
+                segments.push(Segment::Placeholder { name, default });
+                rest = &rest[start + len + 4..];
+            }
+            None => {
+                // Not a placeholder, e.g. a Go template action: keep the braces as text
+                segments.push(Segment::Text(&rest[..start + 2]));
+                rest = &rest[start + 2..];
+            }
+        }
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+    segments
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Built-in snippets merged with the user's own, which live in `~/.config/nub/snippets`.
+pub struct SnippetStore {
+    dir: Option<PathBuf>,
+}
+
+impl SnippetStore {
+    pub fn open() -> Self {
+        Self {
+            dir: config::global_dir().map(|dir| dir.join("snippets")),
+        }
+    }
+
+    /// Returns every snippet sorted by language and name, user snippets shadowing built-in ones.
+    pub fn all(&self) -> Result<Vec<Snippet>, CliError> {
+        let mut snippets: BTreeMap<(Language, String), Snippet> = BTreeMap::new();
+
+        for dir in BUILTIN_SNIPPETS.dirs() {
+            let Some(language) = dir_language(dir.path()) else {
+                continue;
+            };
+            for file in dir.files() {
+                let (Some(name), Some(content)) = (snippet_name(file.path()), file.contents_utf8())
+                else {
+                    continue;
+                };
+                let snippet = Snippet::parse(name, language, content, SnippetSource::Builtin)?;
+                snippets.insert((language, snippet.name.clone()), snippet);
+            }
+        }
+
+        if let Some(dir) = &self.dir
+            && let Ok(entries) = fs::read_dir(dir)
+        {
+            for language_dir in entries.filter_map(|entry| entry.ok()).map(|e| e.path()) {
+                let Some(language) = dir_language(&language_dir) else {
+                    continue;
+                };
+                let Ok(files) = fs::read_dir(&language_dir) else {
+                    continue;
+                };
+                for path in files.filter_map(|entry| entry.ok()).map(|e| e.path()) {
+                    let Some(name) = snippet_name(&path) else {
+                        continue;
+                    };
+                    let content = fs::read_to_string(&path)?;
+                    let snippet = Snippet::parse(name, language, &content, SnippetSource::User)?;
+                    snippets.insert((language, snippet.name.clone()), snippet);
+                }
+            }
+        }
+
+        Ok(snippets.into_values().collect())
+    }
+
+    /// Finds a snippet by name, the language is required when several languages share the name.
+    pub fn find(&self, name: &str, language: Option<Language>) -> Result<Snippet, CliError> {
+        let mut matches: Vec<Snippet> = self
+            .all()?
+            .into_iter()
+            .filter(|snippet| snippet.name == name)
+            .filter(|snippet| language.is_none_or(|language| snippet.language == language))
+            .collect();
+
+        match matches.len() {
+            0 => Err(CliError::SnippetNotFound(name.to_string())),
+            1 => Ok(matches.remove(0)),
+            _ => Err(CliError::AmbiguousSnippet(
+                name.to_string(),
+                matches
+                    .iter()
+                    .map(|snippet| snippet.language.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )),
+        }
+    }
+
+    /// Returns snippets matching the query in their name, tags, description or body, best first.
+    pub fn search(&self, query: &str) -> Result<Vec<Snippet>, CliError> {
+        let query = query.to_lowercase();
+        let mut matches: Vec<(u32, Snippet)> = self
+            .all()?
+            .into_iter()
+            .filter_map(|snippet| {
+                let score = if snippet.name.to_lowercase().contains(&query) {
+                    4
+                } else if snippet.tags.iter().any(|tag| tag.to_lowercase() == query) {
+                    3
+                } else if snippet.description.to_lowercase().contains(&query) {
+                    2
+                } else if snippet.body.to_lowercase().contains(&query) {
+                    1
+                } else {
+                    0
+                };
+                (score > 0).then_some((score, snippet))
+            })
+            .collect();
+        matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        Ok(matches.into_iter().map(|(_, snippet)| snippet).collect())
+    }
+
+    /// Saves a user snippet, refusing to overwrite an existing one unless `force` is set.
+    pub fn add(&self, snippet: &Snippet, force: bool) -> Result<PathBuf, CliError> {
+        let path = self.path(&snippet.name, snippet.language)?;
+        if path.exists() && !force {
+            return Err(CliError::SnippetExists(snippet.name.clone()));
+        }
+
+        let file = SnippetFile {
+            description: snippet.description.clone(),
+            tags: snippet.tags.clone(),
+            body: snippet.body.clone(),
+        };
+        let content = toml::to_string(&file)
+            .map_err(|_| CliError::MalformedFile(path.display().to_string()))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+
+    /// Deletes a user snippet, built-in snippets cannot be removed.
+    pub fn remove(&self, name: &str, language: Option<Language>) -> Result<Snippet, CliError> {
+        let snippet = self.find(name, language)?;
+        if snippet.source == SnippetSource::Builtin {
+            return Err(CliError::BuiltinSnippet(snippet.name));
+        }
+        fs::remove_file(self.path(&snippet.name, snippet.language)?)?;
+        Ok(snippet)
+    }
+
+    fn path(&self, name: &str, language: Language) -> Result<PathBuf, CliError> {
+        let dir = self.dir.as_ref().ok_or(CliError::ConfigDirNotFound)?;
+        Ok(dir.join(language.to_string()).join(format!("{name}.toml")))
+    }
+}
+
+fn dir_language(dir: &Path) -> Option<Language> {
+    dir.file_name()?.to_str()?.parse().ok()
+}
+
+fn snippet_name(path: &Path) -> Option<&str> {
+    if path.extension()? != "toml" {
+        return None;
+    }
+    path.file_stem()?.to_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippet(body: &str) -> Snippet {
+        Snippet {
+            name: "test".to_string(),
+            language: Language::Go,
+            description: String::new(),
+            tags: Vec::new(),
+            body: body.to_string(),
+            source: SnippetSource::User,
+        }
+    }
+
+    #[test]
+    fn parses_placeholders_and_text() {
+        use Segment::{Placeholder, Text};
+
+        let cases = [
+            ("plain", vec![Text("plain")]),
+            (
+                "fn {{name}}() -> {{ty:u32}}",
+                vec![
+                    Text("fn "),
+                    Placeholder {
+                        name: "name",
+                        default: None,
+                    },
+                    Text("() -> "),
+                    Placeholder {
+                        name: "ty",
+                        default: Some("u32"),
+                    },
+                ],
+            ),
+            (
+                "{{msg:hello: world}}",
+                vec![Placeholder {
+                    name: "msg",
+                    default: Some("hello: world"),
+                }],
+            ),
+            (
+                "{{value:}}",
+                vec![Placeholder {
+                    name: "value",
+                    default: Some(""),
+                }],
+            ),
+            // Go template actions and unclosed braces are kept as text
+            ("{{ .Name }}", vec![Text("{{"), Text(" .Name }}")]),
+            (
+                "{{.Name}}{{x}}",
+                vec![
+                    Text("{{"),
+                    Text(".Name}}"),
+                    Placeholder {
+                        name: "x",
+                        default: None,
+                    },
+                ],
+            ),
+            ("open {{name", vec![Text("open {{"), Text("name")]),
+        ];
+        for (body, expected) in cases {
+            assert_eq!(parse_segments(body), expected, "{body}");
+        }
+    }
+
+    #[test]
+    fn collects_distinct_placeholders_with_their_defaults() {
+        let snippet = snippet("{{name}} {{ty:u32}} {{name:item}} {{ty:u64}}");
+        assert_eq!(
+            snippet.placeholders(),
+            [("name", Some("item")), ("ty", Some("u32"))]
+        );
+    }
+
+    #[test]
+    fn renders_values_falling_back_to_defaults() {
+        let snippet = snippet("let {{name}}: {{ty:u32}} = {{name}}_{{other}};");
+        let values = BTreeMap::from([("name".to_string(), "count".to_string())]);
+        assert_eq!(snippet.render(&values), "let count: u32 = count_other;");
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    fmt, fs, io,
+    path::Path,
+    process::{Child, Command},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    detect::{self, PackageManager, Project},
+    template::Language,
+};
+
+/// Exit code reported when the program of a task is not installed, as shells do.
+pub const MISSING_PROGRAM_CODE: i32 = 127;
+
+/// A command run by `nub run`, either a shell line or a program with its arguments.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum TaskCommand {
+    Shell(String),
+    Args(Vec<String>),
+}
+
+impl TaskCommand {
+    fn args(args: &[&str]) -> Self {
+        Self::Args(args.iter().map(|arg| arg.to_string()).collect())
+    }
+
+    /// Starts the command in `dir`, inheriting the terminal so output streams as it comes.
+    pub fn spawn(&self, dir: &Path) -> io::Result<Child> {
+        let mut command = match self {
+            Self::Shell(line) if cfg!(windows) => {
+                let mut command = Command::new("cmd");
+                command.args(["/C", line]);
+                command
+            }
+            Self::Shell(line) => {
+                let mut command = Command::new("sh");
+                command.args(["-c", line]);
+                command
+            }
+            Self::Args(args) => {
+                let Some((program, args)) = args.split_first() else {
+                    return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty command"));
+                };
+                let mut command = Command::new(program);
+                command.args(args);
+                command
+            }
+        };
+        command.current_dir(dir).spawn()
+    }
+}
+
+impl fmt::Display for TaskCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Shell(line) => write!(f, "{line}"),
+            Self::Args(args) => write!(f, "{}", args.join(" ")),
+        }
+    }
+}
+
+/// The command running `task` with the project toolchain, if the language has one.
+pub fn default_command(project: &Project, task: &str) -> Option<TaskCommand> {
+    match project.language {
+        Language::Cpp => cpp_command(project, task),
+        Language::Go => go_command(project, task),
+        Language::Python => python_command(project, task),
+        Language::Rust => rust_command(task),
+        Language::Typescript => typescript_command(project, task),
+        Language::Zig => zig_command(task),
+    }
+}
+
+/// Configures into `build/` before each build, CMake only regenerates what changed.
+fn cpp_command(project: &Project, task: &str) -> Option<TaskCommand> {
+    let build = "cmake -S . -B build && cmake --build build";
+    let command = match task {
+        "build" => build.to_string(),
+        "test" => format!("{build} && ctest --test-dir build --output-on-failure"),
+        "dev" => format!("{build} && ./build/{}", project.name),
+        _ => return None,
+    };
+    Some(TaskCommand::Shell(command))
+}
+
+/// Runs the tools inside the project environment, through uv when the project uses it.
+fn python_command(project: &Project, task: &str) -> Option<TaskCommand> {
+    let executor = project
+        .package_manager
+        .executor()
+        .unwrap_or(&["python3", "-m"]);
+    let run = |args: &[&str]| TaskCommand::args(&[executor, args].concat());
+    let command = match task {
+        "build" if project.package_manager == PackageManager::Uv => {
+            TaskCommand::args(&["uv", "build"])
+        }
+        "build" => run(&["build"]),
+        "test" => run(&["pytest"]),
+        "lint" => run(&["ruff", "check", "."]),
+        "fmt" => run(&["ruff", "format", "."]),
+        "dev" if project.package_manager == PackageManager::Uv => {
+            run(&["python", "-m", &project.python_module()])
+        }
+        "dev" => TaskCommand::args(&["python3", "-m", &project.python_module()]),
+        _ => return None,
+    };
+    Some(command)
+}
+
+fn zig_command(task: &str) -> Option<TaskCommand> {
+    let command = match task {
+        "build" => TaskCommand::args(&["zig", "build"]),
+        "test" => TaskCommand::args(&["zig", "build", "test"]),
+        "lint" => TaskCommand::args(&["zig", "fmt", "--check", "."]),
+        "fmt" => TaskCommand::args(&["zig", "fmt", "."]),
+        "dev" => TaskCommand::args(&["zig", "build", "run"]),
+        _ => return None,
+    };
+    Some(command)
+}
+
+fn rust_command(task: &str) -> Option<TaskCommand> {
+    let command = match task {
+        "build" => TaskCommand::args(&["cargo", "build"]),
+        "test" => TaskCommand::args(&["cargo", "test"]),
+        "lint" => TaskCommand::args(&["cargo", "clippy", "--all-targets", "--", "-D", "warnings"]),
+        "fmt" => TaskCommand::args(&["cargo", "fmt", "--all"]),
+        "dev" => TaskCommand::args(&["cargo", "run"]),
+        _ => return None,
+    };
+    Some(command)
+}
+
+fn go_command(project: &Project, task: &str) -> Option<TaskCommand> {
+    let command = match task {
+        "build" => TaskCommand::args(&["go", "build", "./..."]),
+        "test" => TaskCommand::args(&["go", "test", "./..."]),
+        "lint" => TaskCommand::args(&["go", "vet", "./..."]),
+        "fmt" => TaskCommand::args(&["go", "fmt", "./..."]),
+        "dev" => TaskCommand::args(&["go", "run", &project.go_main_package()]),
+        _ => return None,
+    };
+    Some(command)
+}
+
+/// Prefers the `package.json` scripts, falling back to Bun and Biome for the usual tasks.
+fn typescript_command(project: &Project, task: &str) -> Option<TaskCommand> {
+    let package: serde_json::Value = fs::read_to_string(project.root.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    let manager = project.package_manager.to_string();
+
+    if package
+        .get("scripts")
+        .and_then(|scripts| scripts.get(task))
+        .is_some()
+    {
+        return Some(TaskCommand::args(&[&manager, "run", task]));
+    }
+
+    let bun = project.package_manager == PackageManager::Bun || detect::uses_bun_runtime(&package);
+    let biome = project.root.join("biome.json").exists();
+    let biome_command = |args: &[&str]| {
+        let executor = project.package_manager.executor().unwrap_or(&["npx"]);
+        TaskCommand::args(&[executor, &["biome"], args].concat())
+    };
+    let command = match task {
+        "test" if bun => TaskCommand::args(&["bun", "test"]),
+        "dev" if bun => TaskCommand::args(&["bun", "--watch", &project.typescript_entry()]),
+        "lint" if biome => biome_command(&["lint", "."]),
+        "fmt" if biome => biome_command(&["format", "--write", "."]),
+        _ => return None,
+    };
+    Some(command)
+}
@@ -1,13 +1,35 @@
-use std::{env, fs, path::Path, str::FromStr};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use clap::ValueEnum;
 use include_dir::{Dir, include_dir};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use crate::errors::CliError;
+use crate::{
+    deploy::DeployTarget,
+    detect::PackageManager,
+    errors::CliError,
+    hooks::HookConfig,
+    provenance::{Provenance, TemplateSnapshot},
+    toolchain::Version,
+};
 
 /// Maps hidden files and directories to their correct names
 /// Prefixed with "_" instead of "." to allow embedding in the binary.
-static RENAME_MAP: &[(&str, &str)] = &[("_github", ".github"), ("_gitignore", ".gitignore")];
+static RENAME_MAP: &[(&str, &str)] = &[
+    ("_github", ".github"),
+    ("_gitignore", ".gitignore"),
+    ("_env.example", ".env.example"),
+    ("_clang-format", ".clang-format"),
+];
+
+/// Template metadata file, read by Nub but never copied into the generated project.
+const MANIFEST_FILE: &str = "_nub.toml";
 
 #[derive(Clone)]
 pub struct Template {
@@ -15,6 +37,49 @@ pub struct Template {
     pub language: Language,
 }
 
+impl Template {
+    /// Path of the template directory relative to the embedded templates root.
+    pub fn path(&self) -> String {
+        format!(
+            "{}/{}",
+            self.language.to_string().to_lowercase(),
+            self.name.to_lowercase()
+        )
+    }
+}
+
+/// Values substituted into a template while it is copied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateVariables {
+    /// Package name written to `Cargo.toml`, `package.json`, `pyproject.toml`,
+    /// `build.zig.zon` and `CMakeLists.txt`.
+    pub name: String,
+    /// Module path written to `go.mod` and used to rewrite the template's imports.
+    pub go_module: String,
+    /// JavaScript package manager chosen with `--pm`, the template's own when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_manager: Option<PackageManager>,
+}
+
+/// A template file rendered for a project.
+#[derive(Debug, Clone)]
+pub struct RenderedFile {
+    /// Path relative to the project root.
+    pub path: PathBuf,
+    pub content: Vec<u8>,
+}
+
+/// Metadata optionally declared by a template in its `_nub.toml` file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TemplateManifest {
+    pub hooks: HookConfig,
+    /// Minimum tool versions keyed by tool name, e.g. `rustc = "1.85"`.
+    pub requirements: BTreeMap<String, Version>,
+    /// Platforms whose deployment configuration is generated with the project, e.g. `deploy = ["fly"]`.
+    pub deploy: Vec<DeployTarget>,
+}
+
 /// Manages template operations for copying project templates.
 #[derive(Clone)]
 pub struct TemplateManager {
@@ -28,12 +93,15 @@ impl TemplateManager {
         }
     }
 
-    pub fn languages(&self) -> Vec<String> {
-        self.templates_dir
+    /// Languages having a template directory, unknown directories are ignored.
+    pub fn languages(&self) -> Vec<Language> {
+        let mut languages: Vec<Language> = self
+            .templates_dir
             .dirs()
-            .filter_map(|dir| dir.path().file_name()?.to_str())
-            .map(String::from)
-            .collect()
+            .filter_map(|dir| dir.path().file_name()?.to_str()?.parse().ok())
+            .collect();
+        languages.sort();
+        languages
     }
 
     pub fn templates_for_language(&self, language: Language) -> Vec<Template> {
@@ -56,53 +124,117 @@ impl TemplateManager {
     pub fn templates(&self) -> Vec<Template> {
         self.languages()
             .into_iter()
-            .flat_map(|lang| {
-                let language = lang.parse().unwrap();
-                self.templates_for_language(language)
-            })
+            .flat_map(|language| self.templates_for_language(language))
             .collect()
     }
 
-    pub fn copy_template(&self, template_path: &str, destination: &Path) -> Result<(), CliError> {
+    pub fn manifest(&self, template_path: &str) -> Result<TemplateManifest, CliError> {
+        let manifest_path = format!("{template_path}/{MANIFEST_FILE}");
+        let Some(file) = self.templates_dir.get_file(&manifest_path) else {
+            return Ok(TemplateManifest::default());
+        };
+
+        file.contents_utf8()
+            .and_then(|content| toml::from_str(content).ok())
+            .ok_or(CliError::MalformedManifest(manifest_path))
+    }
+
+    /// Returns the content of a text file embedded in a template.
+    pub fn template_file(&self, template_path: &str, file_name: &str) -> Option<&'static str> {
+        self.templates_dir
+            .get_file(format!("{template_path}/{file_name}"))?
+            .contents_utf8()
+    }
+
+    /// Hashes the paths and contents of the template files, in a stable order.
+    pub fn template_hash(&self, template_path: &str) -> Result<String, CliError> {
         let template_dir = self
             .templates_dir
             .get_dir(template_path)
             .ok_or_else(|| CliError::TemplateNotFound(template_path.to_string()))?;
 
+        let mut files = Vec::new();
+        collect_files(template_dir, &mut files);
+        files.sort_by_key(|file| file.path());
+
+        let mut hasher = Sha256::new();
+        for file in files {
+            hasher.update(file.path().to_string_lossy().as_bytes());
+            hasher.update([0]);
+            hasher.update(file.contents());
+            hasher.update([0]);
+        }
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect())
+    }
+
+    /// Copies the template into `destination` and records its provenance there.
+    pub fn copy_template(
+        &self,
+        template_path: &str,
+        destination: &Path,
+        variables: &TemplateVariables,
+    ) -> Result<(), CliError> {
+        let files = self.render_template(template_path, variables)?;
+        for file in &files {
+            let path = destination.join(&file.path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, &file.content)?;
+        }
+
+        TemplateSnapshot::new(&files).save(destination)?;
+        Provenance::new(template_path, self.template_hash(template_path)?, variables)
+            .save(destination)
+    }
+
+    /// Renders every file of the template in memory, with paths relative to the project root.
+    pub fn render_template(
+        &self,
+        template_path: &str,
+        variables: &TemplateVariables,
+    ) -> Result<Vec<RenderedFile>, CliError> {
+        let template_dir = self
+            .templates_dir
+            .get_dir(template_path)
+            .ok_or_else(|| CliError::TemplateNotFound(template_path.to_string()))?;
+        let template_module = self.template_go_module(template_path)?;
+        let template_crate = self.template_crate_name(template_path);
+        let mut files = Vec::new();
+
         for entry in template_dir.entries() {
             match entry {
                 include_dir::DirEntry::File(file) => {
-                    // Copy file directly to destination, ignoring the parent directories
+                    // Render file directly to the root, ignoring the parent directories
                     let file_name = file
                         .path()
                         .file_name()
                         .ok_or(CliError::InvalidName)?
                         .to_str()
                         .ok_or(CliError::InvalidName)?;
+                    if file_name == MANIFEST_FILE {
+                        continue;
+                    }
                     let dest_file_name = rename_file(file_name);
-                    let dest_path = destination.join(dest_file_name);
-
-                    // Get the current directory name for the module path
-                    let current_dir = env::current_dir()?;
-                    let dir_name = current_dir
-                        .file_name()
-                        .ok_or(CliError::InvalidName)?
-                        .to_str()
-                        .ok_or(CliError::InvalidName)?;
-
-                    let content = if dest_file_name == "go.mod" {
-                        let content = std::str::from_utf8(file.contents())
-                            .map_err(|_| CliError::InvalidName)?;
-                        let updated_content = self.update_go_module_path(content, dir_name)?;
-                        updated_content.into_bytes()
-                    } else {
-                        file.contents().to_vec()
-                    };
-
-                    fs::write(&dest_path, content)?;
+                    let content = self.render_file(
+                        file,
+                        dest_file_name,
+                        variables,
+                        template_module.as_deref(),
+                        template_crate.as_deref(),
+                        true,
+                    )?;
+                    files.push(RenderedFile {
+                        path: PathBuf::from(dest_file_name),
+                        content,
+                    });
                 }
                 include_dir::DirEntry::Dir(dir) => {
-                    // Copy subdirectory contents directly to destination
+                    // Render subdirectory contents directly under the root
                     let dir_name = dir
                         .path()
                         .file_name()
@@ -110,41 +242,178 @@ impl TemplateManager {
                         .to_str()
                         .ok_or(CliError::InvalidName)?;
                     let dest_dir_name = rename_file(dir_name);
-                    let dest_dir = destination.join(dest_dir_name);
-                    self.clone().copy_dir_recursive(dir, &dest_dir)?;
+                    self.render_dir_recursive(
+                        dir,
+                        Path::new(dest_dir_name),
+                        variables,
+                        template_module.as_deref(),
+                        template_crate.as_deref(),
+                        &mut files,
+                    )?;
                 }
             }
         }
 
-        Ok(())
+        if let Some(module) = self.template_python_module(template_path) {
+            rename_python_module(&mut files, &module, &python_module(&variables.name));
+        }
+        rename_member_crates(&mut files, &variables.name);
+        if let Some(manager) = variables.package_manager
+            && let Some(package) = self.template_file(template_path, "package.json")
+        {
+            let package = serde_json::from_str(package).unwrap_or_default();
+            switch_package_manager(
+                &mut files,
+                PackageManager::from_package_json(&package),
+                manager,
+            );
+        }
+
+        Ok(files)
     }
 
-    fn copy_dir_recursive(
-        self,
+    fn render_dir_recursive(
+        &self,
         source: &include_dir::Dir<'_>,
         destination: &Path,
+        variables: &TemplateVariables,
+        template_module: Option<&str>,
+        template_crate: Option<&str>,
+        files: &mut Vec<RenderedFile>,
     ) -> Result<(), CliError> {
-        if !destination.exists() {
-            fs::create_dir_all(destination)?;
-        }
-
-        // Copy files
+        // Render files
         for file in source.files() {
-            let file_name = file.path().file_name().ok_or(CliError::InvalidName)?;
-            let file_path = destination.join(file_name);
-            fs::write(&file_path, file.contents())?;
+            let file_name = file
+                .path()
+                .file_name()
+                .ok_or(CliError::InvalidName)?
+                .to_str()
+                .ok_or(CliError::InvalidName)?;
+            let content = self.render_file(
+                file,
+                file_name,
+                variables,
+                template_module,
+                template_crate,
+                false,
+            )?;
+            files.push(RenderedFile {
+                path: destination.join(file_name),
+                content,
+            });
         }
 
-        // Recursively copy subdirectories
+        // Recursively render subdirectories
         for dir in source.dirs() {
             let dir_name = dir.path().file_name().ok_or(CliError::InvalidName)?;
-            let dir_path = destination.join(dir_name);
-            self.clone().copy_dir_recursive(dir, &dir_path)?;
+            self.render_dir_recursive(
+                dir,
+                &destination.join(dir_name),
+                variables,
+                template_module,
+                template_crate,
+                files,
+            )?;
         }
 
         Ok(())
     }
 
+    /// Substitutes template variables into a file before it is written.
+    ///
+    /// Only the manifests at the template root are renamed here, workspace members are
+    /// renamed once every file is rendered.
+    fn render_file(
+        &self,
+        file: &include_dir::File<'_>,
+        dest_file_name: &str,
+        variables: &TemplateVariables,
+        template_module: Option<&str>,
+        template_crate: Option<&str>,
+        root: bool,
+    ) -> Result<Vec<u8>, CliError> {
+        if dest_file_name == "go.mod" {
+            let content = file.contents_utf8().ok_or(CliError::MalformedGoMod)?;
+            let updated_content = self.update_go_module_path(content, &variables.go_module)?;
+            return Ok(updated_content.into_bytes());
+        }
+
+        match (dest_file_name, template_module, file.contents_utf8()) {
+            ("Cargo.toml", _, Some(content)) if root => {
+                Ok(update_cargo_package_name(content, &variables.name).into_bytes())
+            }
+            ("package.json", _, Some(content)) if root => {
+                Ok(update_package_json_name(content, &variables.name).into_bytes())
+            }
+            ("pyproject.toml", _, Some(content)) if root => {
+                Ok(update_pyproject_name(content, &variables.name).into_bytes())
+            }
+            ("build.zig.zon" | "build.zig", _, Some(content)) if root => {
+                Ok(update_zig_name(content, &variables.name).into_bytes())
+            }
+            ("CMakeLists.txt", _, Some(content)) if root => {
+                Ok(update_cmake_project_name(content, &variables.name).into_bytes())
+            }
+            (name, Some(module), Some(content))
+                if name.ends_with(".go") || name.ends_with(".proto") =>
+            {
+                Ok(rewrite_go_imports(content, module, &variables.go_module).into_bytes())
+            }
+            (name, _, Some(content)) if name.ends_with(".rs") => match template_crate {
+                Some(krate) => {
+                    Ok(rewrite_crate_paths(content, krate, &variables.name).into_bytes())
+                }
+                None => Ok(content.as_bytes().to_vec()),
+            },
+            _ => Ok(file.contents().to_vec()),
+        }
+    }
+
+    /// Reads the module path declared in the template's own `go.mod`, if any.
+    fn template_go_module(&self, template_path: &str) -> Result<Option<String>, CliError> {
+        let Some(file) = self
+            .templates_dir
+            .get_file(format!("{template_path}/go.mod"))
+        else {
+            return Ok(None);
+        };
+
+        file.contents_utf8()
+            .and_then(|content| {
+                content
+                    .lines()
+                    .find_map(|line| line.trim().strip_prefix("module "))
+            })
+            .map(|module| Some(module.trim().to_string()))
+            .ok_or(CliError::MalformedGoMod)
+    }
+
+    /// Reads the package name declared in the template's own `Cargo.toml`, if any.
+    fn template_crate_name(&self, template_path: &str) -> Option<String> {
+        let table: toml::Table = self
+            .template_file(template_path, "Cargo.toml")?
+            .parse()
+            .ok()?;
+        table
+            .get("package")?
+            .get("name")?
+            .as_str()
+            .map(String::from)
+    }
+
+    /// Reads the importable module of the template's own `pyproject.toml`, if any.
+    fn template_python_module(&self, template_path: &str) -> Option<String> {
+        let table: toml::Table = self
+            .template_file(template_path, "pyproject.toml")?
+            .parse()
+            .ok()?;
+        table
+            .get("project")?
+            .get("name")?
+            .as_str()
+            .map(python_module)
+    }
+
     fn update_go_module_path(
         &self,
         content: &str,
@@ -163,6 +432,481 @@ impl TemplateManager {
     }
 }
 
+/// Replaces the `name` of the `[package]` section, leaving workspace manifests untouched.
+fn update_cargo_package_name(content: &str, name: &str) -> String {
+    let mut in_package = false;
+    let mut renamed = false;
+    let lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim();
+            if trimmed.starts_with('[') {
+                in_package = trimmed == "[package]";
+            } else if in_package && !renamed && trimmed.starts_with("name") {
+                renamed = true;
+                return format!("name = \"{name}\"");
+            }
+            line.to_string()
+        })
+        .collect();
+    lines.join("\n") + "\n"
+}
+
+/// Replaces the top-level `name` field, assumed to be the first one in the file.
+fn update_package_json_name(content: &str, name: &str) -> String {
+    let mut renamed = false;
+    let lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            if !renamed && trimmed.starts_with("\"name\"") {
+                renamed = true;
+                let indent = &line[..line.len() - trimmed.len()];
+                // The last field of the object has no trailing comma
+                let comma = if trimmed.trim_end().ends_with(',') {
+                    ","
+                } else {
+                    ""
+                };
+                return format!("{indent}\"name\": \"{name}\"{comma}");
+            }
+            line.to_string()
+        })
+        .collect();
+    lines.join("\n") + "\n"
+}
+
+/// Replaces the `name` of the `[project]` table and the script named after the template.
+fn update_pyproject_name(content: &str, name: &str) -> String {
+    let mut table = "";
+    let mut template_name = None;
+    let lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim();
+            if trimmed.starts_with('[') {
+                table = trimmed;
+            } else if table == "[project]" && template_name.is_none() && trimmed.starts_with("name")
+            {
+                template_name = trimmed.split('"').nth(1).map(String::from);
+                return format!("name = \"{name}\"");
+            } else if let (Some(template), "[project.scripts]") = (&template_name, table) {
+                let prefix = format!("{template} =");
+                if let Some(target) = trimmed.strip_prefix(&prefix) {
+                    let target = target.replace(
+                        &format!("\"{}:", python_module(template)),
+                        &format!("\"{}:", python_module(name)),
+                    );
+                    return format!("{name} ={target}");
+                }
+            }
+            line.to_string()
+        })
+        .collect();
+    lines.join("\n") + "\n"
+}
+
+/// Replaces the package name of `build.zig.zon`, recomputing its fingerprint, and the
+/// artifact name of `build.zig`.
+///
+/// Zig rejects a manifest whose fingerprint does not match its name, the upper half being
+/// the CRC-32 of the name and the lower half a random identifier kept from the template.
+fn update_zig_name(content: &str, name: &str) -> String {
+    let mut renamed = false;
+    let lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let indent = &line[..line.len() - trimmed.len()];
+            if !renamed && trimmed.starts_with(".name = ") {
+                renamed = true;
+                return match trimmed.contains('"') {
+                    true => format!("{indent}.name = \"{name}\","),
+                    false => format!("{indent}.name = .{name},"),
+                };
+            }
+            if let Some(value) = trimmed.strip_prefix(".fingerprint = 0x") {
+                let id = u64::from_str_radix(value.trim_end_matches(','), 16).unwrap_or_default()
+                    & 0xffff_ffff;
+                let checksum = u64::from(crc32fast::hash(name.as_bytes()));
+                return format!("{indent}.fingerprint = 0x{:016x},", checksum << 32 | id);
+            }
+            line.to_string()
+        })
+        .collect();
+    lines.join("\n") + "\n"
+}
+
+/// Replaces the name given to the first `project()` command.
+fn update_cmake_project_name(content: &str, name: &str) -> String {
+    let mut renamed = false;
+    let lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            if let Some(rest) = line.trim().strip_prefix("project(")
+                && !renamed
+            {
+                renamed = true;
+                let rest = rest.trim_start_matches(|c: char| c != ' ' && c != ')');
+                return format!("project({name}{rest}");
+            }
+            line.to_string()
+        })
+        .collect();
+    lines.join("\n") + "\n"
+}
+
+/// Importable module of a Python distribution, as normalized by build backends.
+pub fn python_module(name: &str) -> String {
+    name.replace(['-', '.'], "_").to_lowercase()
+}
+
+/// Moves the template's Python package to the project module and rewrites its imports.
+fn rename_python_module(files: &mut [RenderedFile], template_module: &str, new_module: &str) {
+    if template_module == new_module {
+        return;
+    }
+    for file in files {
+        file.path = file
+            .path
+            .components()
+            .map(|component| match component.as_os_str().to_str() {
+                Some(part) if part == template_module => Path::new(new_module),
+                _ => Path::new(component.as_os_str()),
+            })
+            .collect();
+        if file.path.extension().is_some_and(|ext| ext == "py")
+            && let Ok(content) = std::str::from_utf8(&file.content)
+        {
+            file.content = content
+                .replace(
+                    &format!("from {template_module} "),
+                    &format!("from {new_module} "),
+                )
+                .replace(
+                    &format!("from {template_module}."),
+                    &format!("from {new_module}."),
+                )
+                .replace(
+                    &format!("import {template_module}\n"),
+                    &format!("import {new_module}\n"),
+                )
+                .into_bytes();
+        }
+    }
+}
+
+/// Renames the member crates of a workspace template after the project, e.g. `app-core`
+/// becomes `demo-core`, along with the dependencies, paths and commands referring to them.
+///
+/// Members are only renamed when they share a `<prefix>-` naming scheme.
+fn rename_member_crates(files: &mut [RenderedFile], name: &str) {
+    let members: Vec<String> = files
+        .iter()
+        .filter(|file| file.path.parent().is_some_and(|dir| dir != Path::new("")))
+        .filter(|file| {
+            file.path
+                .file_name()
+                .is_some_and(|file| file == "Cargo.toml")
+        })
+        .filter_map(|file| {
+            let table: toml::Table = std::str::from_utf8(&file.content).ok()?.parse().ok()?;
+            table
+                .get("package")?
+                .get("name")?
+                .as_str()
+                .map(String::from)
+        })
+        .collect();
+    let Some(prefix) = members
+        .first()
+        .and_then(|member| member.split_once('-'))
+        .map(|(prefix, _)| format!("{prefix}-"))
+    else {
+        return;
+    };
+    if !members.iter().all(|member| member.starts_with(&prefix)) {
+        return;
+    }
+    let renames: Vec<(&str, String)> = members
+        .iter()
+        .map(|member| {
+            (
+                member.as_str(),
+                format!("{name}-{}", &member[prefix.len()..]),
+            )
+        })
+        .filter(|(from, to)| from != to)
+        .collect();
+
+    for file in files {
+        let Ok(content) = std::str::from_utf8(&file.content) else {
+            continue;
+        };
+        let is_rust = file.path.extension().is_some_and(|ext| ext == "rs");
+        let is_text = ["Cargo.toml", "README.md"]
+            .iter()
+            .any(|text| file.path.file_name().is_some_and(|file| file == *text))
+            || file.path.starts_with(".github");
+        if !is_rust && !is_text {
+            continue;
+        }
+        let mut content = content.to_string();
+        for (from, to) in &renames {
+            content = if is_rust {
+                rewrite_crate_paths(&content, from, to)
+            } else {
+                replace_crate_name(&content, from, to)
+            };
+        }
+        file.content = content.into_bytes();
+    }
+}
+
+/// Replaces a crate name where it is not part of a longer name, e.g. `app-core` but not
+/// `app-core-macros`.
+fn replace_crate_name(content: &str, from: &str, to: &str) -> String {
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    let mut replaced = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(index) = rest.find(from) {
+        let end = index + from.len();
+        let standalone = !rest[..index].chars().next_back().is_some_and(is_name_char)
+            && !rest[end..].chars().next().is_some_and(is_name_char);
+        replaced.push_str(&rest[..index]);
+        replaced.push_str(if standalone { to } else { from });
+        rest = &rest[end..];
+    }
+    replaced.push_str(rest);
+    replaced
+}
+
+/// Adapts a JavaScript template written for the `from` package manager to `to`.
+///
+/// The manifests declare `to` in their `packageManager` field and have their scripts
+/// rewritten, as do the CI workflows and the README. The runtime is left as is, so Bun
+/// templates still run on Bun whatever installs their dependencies.
+fn switch_package_manager(files: &mut Vec<RenderedFile>, from: PackageManager, to: PackageManager) {
+    let mut workspaces = None;
+    for file in files.iter_mut() {
+        let Ok(content) = std::str::from_utf8(&file.content) else {
+            continue;
+        };
+        let path = file.path.to_string_lossy().replace('\\', "/");
+        let content = if path == "package.json" {
+            workspaces = serde_json::from_str::<serde_json::Value>(content)
+                .ok()
+                .and_then(|package| package.get("workspaces").cloned());
+            rewrite_package_json(content, from, to, true)
+        } else if path.ends_with("/package.json") {
+            rewrite_package_json(content, from, to, false)
+        } else if path.starts_with(".github/workflows/") {
+            rewrite_workflow(content, from, to)
+        } else if path == "README.md" {
+            content
+                .lines()
+                .map(|line| to.translate_command(line, from))
+                .collect::<Vec<_>>()
+                .join("\n")
+                + "\n"
+        } else if path == ".gitignore" && to == PackageManager::Yarn {
+            format!("{}\n\n# Yarn\n.yarn/\n.pnp.*\n", content.trim_end())
+        } else {
+            continue;
+        };
+        file.content = content.into_bytes();
+    }
+
+    match to {
+        // pnpm ignores the `workspaces` field and reads its own file instead
+        PackageManager::Pnpm => {
+            if let Some(patterns) = workspaces.as_ref().and_then(|list| list.as_array()) {
+                let mut content = String::from("packages:\n");
+                for pattern in patterns.iter().filter_map(|pattern| pattern.as_str()) {
+                    content.push_str(&format!("  - \"{pattern}\"\n"));
+                }
+                files.push(RenderedFile {
+                    path: PathBuf::from("pnpm-workspace.yaml"),
+                    content: content.into_bytes(),
+                });
+            }
+        }
+        // Plug'n'Play breaks the tools expecting a `node_modules` directory
+        PackageManager::Yarn => files.push(RenderedFile {
+            path: PathBuf::from(".yarnrc.yml"),
+            content: b"nodeLinker: node-modules\n".to_vec(),
+        }),
+        _ => {}
+    }
+}
+
+/// Rewrites the scripts of a `package.json`, declaring the package manager at the root.
+fn rewrite_package_json(
+    content: &str,
+    from: PackageManager,
+    to: PackageManager,
+    root: bool,
+) -> String {
+    let declaration = to
+        .pinned_version()
+        .map(|version| format!("\"packageManager\": \"{to}@{version}\""));
+    let mut lines = Vec::new();
+    let mut in_scripts = false;
+    let mut workspaces_depth = 0;
+    let mut declared = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        // Drops the `workspaces` field, replaced by `pnpm-workspace.yaml`
+        if root
+            && to == PackageManager::Pnpm
+            && (workspaces_depth > 0 || trimmed.starts_with("\"workspaces\""))
+        {
+            workspaces_depth += line.matches(['[', '{']).count();
+            workspaces_depth -= line.matches([']', '}']).count().min(workspaces_depth);
+            if workspaces_depth == 0 {
+                drop_last_field(&mut lines, trimmed);
+            }
+            continue;
+        }
+        if root && trimmed.starts_with("\"packageManager\"") {
+            drop_last_field(&mut lines, trimmed);
+            continue;
+        }
+
+        if trimmed.starts_with("\"scripts\"") {
+            in_scripts = true;
+        } else if in_scripts && trimmed.starts_with('}') {
+            in_scripts = false;
+        } else if in_scripts && let Some((key, value)) = trimmed.split_once("\": \"") {
+            let (command, end) = value.rsplit_once('"').unwrap_or((value, ""));
+            lines.push(format!(
+                "{indent}{key}\": \"{}\"{end}",
+                to.translate_command(command, from)
+            ));
+            continue;
+        }
+
+        // npm links workspace members without the `workspace:` protocol
+        if to == PackageManager::Npm && trimmed.contains("\"workspace:") {
+            lines.push(
+                line.replace("\"workspace:*\"", "\"*\"")
+                    .replace("\"workspace:^\"", "\"*\""),
+            );
+            continue;
+        }
+
+        if root
+            && !declared
+            && trimmed.starts_with("\"name\"")
+            && let Some(declaration) = &declaration
+        {
+            declared = true;
+            // Declared right after the name, which keeps the comma when it was the last field
+            let name = line.trim_end();
+            match name.strip_suffix(',') {
+                Some(_) => lines.extend([name.to_string(), format!("{indent}{declaration},")]),
+                None => lines.extend([format!("{name},"), format!("{indent}{declaration}")]),
+            }
+            continue;
+        }
+        lines.push(line.to_string());
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Accounts for a dropped field ending with `last_line`: when it was the last field of its
+/// object, the field before it becomes the last one and loses its trailing comma.
+fn drop_last_field(lines: &mut [String], last_line: &str) {
+    if last_line.trim_end().ends_with(',') {
+        return;
+    }
+    if let Some(previous) = lines.last_mut()
+        && let Some(stripped) = previous.trim_end().strip_suffix(',')
+    {
+        *previous = stripped.to_string();
+    }
+}
+
+/// Sets up the package manager before the dependencies are installed and rewrites the
+/// commands run by the workflow.
+fn rewrite_workflow(content: &str, from: PackageManager, to: PackageManager) -> String {
+    let setup: &[&str] = match to {
+        PackageManager::Pnpm => &["- name: Set up pnpm", "  uses: pnpm/action-setup@v4"],
+        PackageManager::Yarn => &["- name: Enable Corepack", "  run: corepack enable"],
+        PackageManager::Bun if !content.contains("oven-sh/setup-bun") => {
+            &["- name: Set up Bun", "  uses: oven-sh/setup-bun@v2"]
+        }
+        _ => &[],
+    };
+
+    let mut lines = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        if trimmed == "- name: Install dependencies" && !setup.is_empty() {
+            lines.extend(setup.iter().map(|step| format!("{indent}{step}")));
+            lines.push(String::new());
+        }
+        match trimmed.strip_prefix("run: ") {
+            Some(command) => lines.push(format!(
+                "{indent}run: {}",
+                to.translate_command(command, from)
+            )),
+            None => lines.push(line.to_string()),
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Rewrites imports of the template's own packages, and `go_package` options, to the generated module path.
+fn rewrite_go_imports(content: &str, template_module: &str, new_module: &str) -> String {
+    if template_module == new_module {
+        return content.to_string();
+    }
+    content
+        .replace(
+            &format!("\"{template_module}/"),
+            &format!("\"{new_module}/"),
+        )
+        .replace(
+            &format!("\"{template_module}\""),
+            &format!("\"{new_module}\""),
+        )
+}
+
+/// Rewrites paths starting with the template's crate name, as used by doctests and integration tests.
+fn rewrite_crate_paths(content: &str, template_crate: &str, new_crate: &str) -> String {
+    let from = format!("{}::", template_crate.replace('-', "_"));
+    let to = format!("{}::", new_crate.replace('-', "_"));
+    if from == to {
+        return content.to_string();
+    }
+
+    let mut rewritten = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(index) = rest.find(&from) {
+        let preceded_by_ident = rest[..index]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        rewritten.push_str(&rest[..index]);
+        rewritten.push_str(if preceded_by_ident { &from } else { &to });
+        rest = &rest[index + from.len()..];
+    }
+    rewritten.push_str(rest);
+    rewritten
+}
+
+fn collect_files<'a>(dir: &'a Dir<'a>, files: &mut Vec<&'a include_dir::File<'a>>) {
+    files.extend(dir.files());
+    for subdir in dir.dirs() {
+        collect_files(subdir, files);
+    }
+}
+
 fn rename_file(file_name: &str) -> &str {
     RENAME_MAP
         .iter()
@@ -171,19 +915,71 @@ fn rename_file(file_name: &str) -> &str {
         .unwrap_or(file_name)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Deserialize)]
+#[serde(try_from = "String")]
 pub enum Language {
+    /// C and C++ projects built with CMake.
+    #[value(aliases = ["c++", "c", "cxx"])]
+    Cpp,
+    #[value(alias = "golang")]
     Go,
+    #[value(alias = "py")]
+    Python,
+    #[value(alias = "rs")]
     Rust,
+    #[value(alias = "ts")]
     Typescript,
+    Zig,
 }
 
 impl std::fmt::Display for Language {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Cpp => write!(f, "cpp"),
             Self::Go => write!(f, "go"),
+            Self::Python => write!(f, "python"),
             Self::Rust => write!(f, "rust"),
             Self::Typescript => write!(f, "typescript"),
+            Self::Zig => write!(f, "zig"),
+        }
+    }
+}
+
+impl Language {
+    /// Command fetching the dependencies of a freshly generated project.
+    pub fn install_command(&self) -> &'static [&'static str] {
+        match self {
+            // Configuring downloads the FetchContent dependencies
+            Self::Cpp => &["cmake", "-S", ".", "-B", "build"],
+            Self::Go => &["go", "mod", "tidy"],
+            Self::Python => &["uv", "sync"],
+            Self::Rust => &["cargo", "fetch"],
+            Self::Typescript => &["bun", "install"],
+            Self::Zig => &["zig", "build", "--fetch"],
+        }
+    }
+
+    /// Command formatting a freshly generated project, if the toolchain ships a formatter.
+    pub fn format_command(&self) -> &'static [&'static str] {
+        match self {
+            Self::Go => &["go", "fmt", "./..."],
+            Self::Python => &["uv", "run", "ruff", "format", "."],
+            Self::Rust => &["cargo", "fmt"],
+            Self::Zig => &["zig", "fmt", "."],
+            Self::Cpp | Self::Typescript => &[],
+        }
+    }
+
+    /// The language of a source file, guessed from its extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "c" | "h" | "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => Some(Self::Cpp),
+            "go" => Some(Self::Go),
+            "py" | "pyi" => Some(Self::Python),
+            "rs" => Some(Self::Rust),
+            "ts" | "tsx" | "mts" | "cts" => Some(Self::Typescript),
+            "zig" => Some(Self::Zig),
+            _ => None,
         }
     }
 }
@@ -191,16 +987,528 @@ impl std::fmt::Display for Language {
 #[derive(Debug)]
 pub struct ParseLanguageError;
 
+impl std::fmt::Display for ParseLanguageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown language")
+    }
+}
+
 impl FromStr for Language {
     type Err = ParseLanguageError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "go" => Ok(Language::Go),
-            "golang" => Ok(Language::Go),
-            "rust" => Ok(Language::Rust),
-            "typescript" => Ok(Language::Typescript),
+            "cpp" | "c++" | "c" | "cxx" => Ok(Language::Cpp),
+            "go" | "golang" => Ok(Language::Go),
+            "python" | "py" => Ok(Language::Python),
+            "rust" | "rs" => Ok(Language::Rust),
+            "typescript" | "ts" => Ok(Language::Typescript),
+            "zig" => Ok(Language::Zig),
             _ => Err(ParseLanguageError),
         }
     }
 }
+
+impl TryFrom<String> for Language {
+    type Error = ParseLanguageError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrites_go_imports_of_the_template_module() {
+        let content = "\
+import (
+\t\"fmt\"
+
+\t\"example.com/app/internal/server\"
+\tapp \"example.com/app\"
+\t\"example.com/application/other\"
+)
+";
+        let expected = "\
+import (
+\t\"fmt\"
+
+\t\"github.com/acme/api/internal/server\"
+\tapp \"github.com/acme/api\"
+\t\"example.com/application/other\"
+)
+";
+        assert_eq!(
+            rewrite_go_imports(content, "example.com/app", "github.com/acme/api"),
+            expected
+        );
+    }
+
+    #[test]
+    fn renames_cargo_packages() {
+        let cases = [
+            (
+                "[package]\nname = \"app\"\nversion = \"0.1.0\"\n",
+                "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+            ),
+            // Dependencies sharing the template name are left alone
+            (
+                "[package]\nname = \"app\"\n\n[dependencies]\napp-core = { path = \"core\" }\nname = \"1\"\n",
+                "[package]\nname = \"demo\"\n\n[dependencies]\napp-core = { path = \"core\" }\nname = \"1\"\n",
+            ),
+            (
+                "[dependencies]\nserde = \"1\"\n\n[package]\nname = \"app\"\n",
+                "[dependencies]\nserde = \"1\"\n\n[package]\nname = \"demo\"\n",
+            ),
+            // Virtual workspace manifests have no package to rename
+            (
+                "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nname = \"app\"\n",
+                "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nname = \"app\"\n",
+            ),
+            // Only the package of a workspace root is renamed
+            (
+                "[workspace]\nmembers = [\"crates/core\"]\n\n[package]\nname = \"app\"\n\n[workspace.dependencies]\napp-core = { path = \"crates/core\" }\n",
+                "[workspace]\nmembers = [\"crates/core\"]\n\n[package]\nname = \"demo\"\n\n[workspace.dependencies]\napp-core = { path = \"crates/core\" }\n",
+            ),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(update_cargo_package_name(content, "demo"), expected);
+        }
+    }
+
+    #[test]
+    fn renames_npm_packages() {
+        let cases = [
+            (
+                "{\n  \"name\": \"app\",\n  \"version\": \"1.0.0\"\n}",
+                "{\n  \"name\": \"demo\",\n  \"version\": \"1.0.0\"\n}\n",
+            ),
+            // Nested names, such as a repository or author name, come after the package's
+            (
+                "{\n  \"name\": \"app\",\n  \"author\": {\n    \"name\": \"Ann\"\n  }\n}\n",
+                "{\n  \"name\": \"demo\",\n  \"author\": {\n    \"name\": \"Ann\"\n  }\n}\n",
+            ),
+            (
+                "{\n\t\"name\":\"app\",\n\t\"private\": true\n}\n",
+                "{\n\t\"name\": \"demo\",\n\t\"private\": true\n}\n",
+            ),
+            (
+                "{\n  \"private\": true,\n  \"name\": \"app\"\n}\n",
+                "{\n  \"private\": true,\n  \"name\": \"demo\"\n}\n",
+            ),
+        ];
+        for (content, expected) in cases {
+            let renamed = update_package_json_name(content, "demo");
+            assert_eq!(renamed, expected);
+            assert!(serde_json::from_str::<serde_json::Value>(&renamed).is_ok());
+        }
+    }
+
+    #[test]
+    fn rewrites_crate_paths() {
+        let cases = [
+            ("use app_lib::Config;", "use demo_app::Config;"),
+            (
+                "let x = app_lib::parse(app_lib::DEFAULT);",
+                "let x = demo_app::parse(demo_app::DEFAULT);",
+            ),
+            (
+                "/// assert!(app_lib::ok());",
+                "/// assert!(demo_app::ok());",
+            ),
+            // Paths of other crates ending with the template name are left alone
+            ("use my_app_lib::Config;", "use my_app_lib::Config;"),
+            (
+                "use crate::app_lib::Config;",
+                "use crate::demo_app::Config;",
+            ),
+            (
+                "use myapp_lib::x; use app_lib::y;",
+                "use myapp_lib::x; use demo_app::y;",
+            ),
+            ("no paths here", "no paths here"),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(
+                rewrite_crate_paths(content, "app-lib", "demo-app"),
+                expected
+            );
+        }
+        assert_eq!(
+            rewrite_crate_paths("use app_lib::X;", "app_lib", "app-lib"),
+            "use app_lib::X;"
+        );
+    }
+
+    fn rendered_file(path: &str, content: &str) -> RenderedFile {
+        RenderedFile {
+            path: PathBuf::from(path),
+            content: content.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn renames_python_projects() {
+        let cases = [
+            (
+                "[project]\nname = \"app\"\nversion = \"0.1.0\"\n\n[project.scripts]\napp = \"app:main\"\nother = \"app.cli:run\"\n",
+                "[project]\nname = \"my-demo\"\nversion = \"0.1.0\"\n\n[project.scripts]\nmy-demo = \"my_demo:main\"\nother = \"app.cli:run\"\n",
+            ),
+            // Names of other tables are left alone
+            (
+                "[tool.poetry]\nname = \"app\"\n\n[project]\nname = \"app\"\n",
+                "[tool.poetry]\nname = \"app\"\n\n[project]\nname = \"my-demo\"\n",
+            ),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(update_pyproject_name(content, "my-demo"), expected);
+        }
+    }
+
+    #[test]
+    fn renames_zig_packages_with_a_matching_fingerprint() {
+        let content = "\
+.{
+    .name = .app,
+    .version = \"0.0.0\",
+    .fingerprint = 0x1234567890abcdef,
+    .dependencies = .{
+        .zap = .{ .name = .zap },
+    },
+}
+";
+        let renamed = update_zig_name(content, "demo");
+        let checksum = crc32fast::hash(b"demo");
+        let fingerprint = format!(".fingerprint = 0x{checksum:08x}90abcdef,");
+        assert!(renamed.contains("    .name = .demo,\n"), "{renamed}");
+        assert!(renamed.contains(&fingerprint), "{renamed}");
+        assert!(renamed.contains(".zap = .{ .name = .zap },"), "{renamed}");
+
+        // build.zig names its artifact with a string
+        let build = "    const exe = b.addExecutable(.{\n        .name = \"app\",\n    });\n";
+        assert_eq!(
+            update_zig_name(build, "demo"),
+            "    const exe = b.addExecutable(.{\n        .name = \"demo\",\n    });\n"
+        );
+    }
+
+    #[test]
+    fn renames_cmake_projects() {
+        let cases = [
+            ("project(app)\n", "project(demo)\n"),
+            (
+                "cmake_minimum_required(VERSION 3.20)\nproject(app VERSION 0.1.0 LANGUAGES CXX)\nadd_executable(${PROJECT_NAME} main.cpp)\n",
+                "cmake_minimum_required(VERSION 3.20)\nproject(demo VERSION 0.1.0 LANGUAGES CXX)\nadd_executable(${PROJECT_NAME} main.cpp)\n",
+            ),
+            (
+                "project(app)\nproject(vendored)\n",
+                "project(demo)\nproject(vendored)\n",
+            ),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(update_cmake_project_name(content, "demo"), expected);
+        }
+    }
+
+    #[test]
+    fn moves_python_modules_and_rewrites_imports() {
+        let mut files = vec![
+            rendered_file(
+                "src/app/__init__.py",
+                "from app.core import run\nfrom app import util\nimport app\nimport application\n",
+            ),
+            rendered_file("src/app/core.py", "def run(): ...\n"),
+            rendered_file("tests/test_app.py", "from app import run\n"),
+            rendered_file("README.md", "from app import run\n"),
+        ];
+        rename_python_module(&mut files, "app", "my_demo");
+
+        let paths: Vec<_> = files.iter().map(|file| file.path.clone()).collect();
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("src/my_demo/__init__.py"),
+                PathBuf::from("src/my_demo/core.py"),
+                PathBuf::from("tests/test_app.py"),
+                PathBuf::from("README.md"),
+            ]
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&files[0].content),
+            "from my_demo.core import run\nfrom my_demo import util\nimport my_demo\nimport application\n"
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&files[2].content),
+            "from my_demo import run\n"
+        );
+        // Only Python sources have their imports rewritten
+        assert_eq!(
+            String::from_utf8_lossy(&files[3].content),
+            "from app import run\n"
+        );
+    }
+
+    const PACKAGE_JSON: &str = r#"{
+  "name": "app",
+  "packageManager": "bun@1.2.19",
+  "workspaces": [
+    "packages/*"
+  ],
+  "scripts": {
+    "dev": "bun run --filter web dev",
+    "build": "bun install && bun run build:all",
+    "lint": "bunx biome check ."
+  },
+  "dependencies": {
+    "shared": "workspace:*"
+  }
+}
+"#;
+
+    #[test]
+    fn rewrites_root_package_json_for_pnpm() {
+        let rewritten = rewrite_package_json(
+            PACKAGE_JSON,
+            PackageManager::Bun,
+            PackageManager::Pnpm,
+            true,
+        );
+        let expected = r#"{
+  "name": "app",
+  "packageManager": "pnpm@10.13.1",
+  "scripts": {
+    "dev": "pnpm --filter web run dev",
+    "build": "pnpm install && pnpm run build:all",
+    "lint": "pnpm exec biome check ."
+  },
+  "dependencies": {
+    "shared": "workspace:*"
+  }
+}
+"#;
+        assert_eq!(rewritten, expected);
+        assert!(serde_json::from_str::<serde_json::Value>(&rewritten).is_ok());
+    }
+
+    #[test]
+    fn rewrites_package_json_whose_edited_fields_come_last() {
+        let cases = [
+            (
+                "{\n  \"private\": true,\n  \"name\": \"app\"\n}\n",
+                PackageManager::Yarn,
+                "{\n  \"private\": true,\n  \"name\": \"app\",\n  \"packageManager\": \"yarn@4.9.2\"\n}\n",
+            ),
+            (
+                "{\n  \"name\": \"app\",\n  \"private\": true,\n  \"packageManager\": \"bun@1.2.19\"\n}\n",
+                PackageManager::Npm,
+                "{\n  \"name\": \"app\",\n  \"packageManager\": \"npm@11.4.2\",\n  \"private\": true\n}\n",
+            ),
+            (
+                "{\n  \"name\": \"app\",\n  \"workspaces\": [\n    \"packages/*\"\n  ]\n}\n",
+                PackageManager::Pnpm,
+                "{\n  \"name\": \"app\",\n  \"packageManager\": \"pnpm@10.13.1\"\n}\n",
+            ),
+            (
+                "{\n  \"name\": \"app\",\n  \"workspaces\": [\"packages/*\"]\n}\n",
+                PackageManager::Pnpm,
+                "{\n  \"name\": \"app\",\n  \"packageManager\": \"pnpm@10.13.1\"\n}\n",
+            ),
+        ];
+
+        for (content, to, expected) in cases {
+            let rewritten = rewrite_package_json(content, PackageManager::Bun, to, true);
+            assert_eq!(rewritten, expected, "{to}");
+            assert!(serde_json::from_str::<serde_json::Value>(&rewritten).is_ok());
+        }
+    }
+
+    #[test]
+    fn rewrites_member_package_json_for_npm() {
+        let rewritten = rewrite_package_json(
+            PACKAGE_JSON,
+            PackageManager::Bun,
+            PackageManager::Npm,
+            false,
+        );
+        assert!(
+            rewritten.contains("\"packageManager\": \"bun@1.2.19\""),
+            "{rewritten}"
+        );
+        assert!(rewritten.contains("\"workspaces\""), "{rewritten}");
+        assert!(
+            rewritten.contains("\"build\": \"npm install && npm run build:all\""),
+            "{rewritten}"
+        );
+        assert!(
+            rewritten.contains("\"lint\": \"npx biome check .\""),
+            "{rewritten}"
+        );
+        assert!(rewritten.contains("\"shared\": \"*\""), "{rewritten}");
+    }
+
+    #[test]
+    fn rewrites_workflows() {
+        let workflow = "\
+    steps:
+      - uses: oven-sh/setup-bun@v2
+
+      - name: Install dependencies
+        run: bun install --frozen-lockfile
+
+      - name: Test
+        run: bun test
+";
+        let expected = "\
+    steps:
+      - uses: oven-sh/setup-bun@v2
+
+      - name: Set up pnpm
+        uses: pnpm/action-setup@v4
+
+      - name: Install dependencies
+        run: pnpm install --frozen-lockfile
+
+      - name: Test
+        run: bun test
+";
+        assert_eq!(
+            rewrite_workflow(workflow, PackageManager::Bun, PackageManager::Pnpm),
+            expected
+        );
+        // Bun is only set up when the workflow does not already
+        let rewritten = rewrite_workflow(
+            "      - name: Install dependencies\n        run: npm install\n",
+            PackageManager::Npm,
+            PackageManager::Bun,
+        );
+        assert_eq!(
+            rewritten,
+            "      - name: Set up Bun\n        uses: oven-sh/setup-bun@v2\n\n      - name: Install dependencies\n        run: bun install\n"
+        );
+    }
+
+    #[test]
+    fn switches_package_managers() {
+        let mut files = vec![
+            rendered_file("package.json", PACKAGE_JSON),
+            rendered_file(
+                "packages/web/package.json",
+                "{\n  \"name\": \"web\",\n  \"scripts\": {\n    \"dev\": \"bun run vite\"\n  }\n}\n",
+            ),
+            rendered_file(
+                ".github/workflows/ci.yml",
+                "      - name: Install dependencies\n        run: bun install\n",
+            ),
+            rendered_file("README.md", "Run `bun install` first\nbun run dev\n"),
+            rendered_file(".gitignore", "node_modules/\n"),
+            rendered_file("src/index.ts", "console.log(\"bun run dev\");\n"),
+        ];
+        switch_package_manager(&mut files, PackageManager::Bun, PackageManager::Yarn);
+
+        let content = |path: &str| {
+            files
+                .iter()
+                .find(|file| file.path == Path::new(path))
+                .map(|file| String::from_utf8_lossy(&file.content).into_owned())
+                .unwrap()
+        };
+        assert!(content("package.json").contains("\"packageManager\": \"yarn@4.9.2\""));
+        assert!(!content("packages/web/package.json").contains("packageManager"));
+        assert!(content("packages/web/package.json").contains("\"dev\": \"yarn run vite\""));
+        assert!(content(".github/workflows/ci.yml").contains("run: corepack enable"));
+        assert!(content(".github/workflows/ci.yml").contains("run: yarn install"));
+        assert_eq!(
+            content("README.md"),
+            "Run `bun install` first\nyarn run dev\n"
+        );
+        assert_eq!(
+            content(".gitignore"),
+            "node_modules/\n\n# Yarn\n.yarn/\n.pnp.*\n"
+        );
+        assert_eq!(content("src/index.ts"), "console.log(\"bun run dev\");\n");
+        assert_eq!(content(".yarnrc.yml"), "nodeLinker: node-modules\n");
+    }
+
+    #[test]
+    fn switching_to_pnpm_writes_the_workspace_file() {
+        let mut files = vec![rendered_file("package.json", PACKAGE_JSON)];
+        switch_package_manager(&mut files, PackageManager::Bun, PackageManager::Pnpm);
+        let workspace = files
+            .iter()
+            .find(|file| file.path == Path::new("pnpm-workspace.yaml"))
+            .unwrap();
+        assert_eq!(workspace.content, b"packages:\n  - \"packages/*\"\n");
+    }
+
+    #[test]
+    fn replaces_standalone_crate_names() {
+        let cases = [
+            (
+                "app-core = { path = \"crates/core\" }",
+                "demo-core = { path = \"crates/core\" }",
+            ),
+            ("app-core.workspace = true", "demo-core.workspace = true"),
+            (
+                "cargo run -p app-core -- list",
+                "cargo run -p demo-core -- list",
+            ),
+            ("app-core-macros = \"1\"", "app-core-macros = \"1\""),
+            ("my-app-core = \"1\"", "my-app-core = \"1\""),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(
+                replace_crate_name(content, "app-core", "demo-core"),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn renames_workspace_members_after_the_project() {
+        let mut files = vec![
+            rendered_file(
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.dependencies]\napp-core = { path = \"crates/core\" }\n",
+            ),
+            rendered_file("crates/core/Cargo.toml", "[package]\nname = \"app-core\"\n"),
+            rendered_file(
+                "crates/cli/Cargo.toml",
+                "[package]\nname = \"app-cli\"\n\n[[bin]]\nname = \"app\"\n\n[dependencies]\napp-core.workspace = true\n",
+            ),
+            rendered_file("crates/cli/src/main.rs", "use app_core::TodoList;\n"),
+            rendered_file("README.md", "cargo run -p app-cli\n"),
+            rendered_file("notes.txt", "app-core\n"),
+        ];
+        rename_member_crates(&mut files, "demo");
+
+        let contents: Vec<_> = files
+            .iter()
+            .map(|file| String::from_utf8_lossy(&file.content).into_owned())
+            .collect();
+        assert_eq!(
+            contents,
+            [
+                "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.dependencies]\ndemo-core = { path = \"crates/core\" }\n",
+                "[package]\nname = \"demo-core\"\n",
+                "[package]\nname = \"demo-cli\"\n\n[[bin]]\nname = \"app\"\n\n[dependencies]\ndemo-core.workspace = true\n",
+                "use demo_core::TodoList;\n",
+                "cargo run -p demo-cli\n",
+                "app-core\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_members_without_a_shared_prefix() {
+        let mut files = vec![
+            rendered_file("core/Cargo.toml", "[package]\nname = \"core-lib\"\n"),
+            rendered_file("cli/Cargo.toml", "[package]\nname = \"tool\"\n"),
+        ];
+        rename_member_crates(&mut files, "demo");
+        assert_eq!(files[0].content, b"[package]\nname = \"core-lib\"\n");
+        assert_eq!(files[1].content, b"[package]\nname = \"tool\"\n");
+    }
+}
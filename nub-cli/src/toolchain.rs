@@ -0,0 +1,395 @@
+use std::{cmp::Ordering, collections::BTreeMap, fmt, process::Command, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::template::Language;
+
+/// An executable expected to be installed to work on projects of a given language.
+pub struct Tool {
+    pub name: &'static str,
+    program: &'static str,
+    args: &'static [&'static str],
+    pub required: bool,
+    pub hint: &'static str,
+}
+
+static RUST_TOOLS: &[Tool] = &[
+    Tool {
+        name: "rustc",
+        program: "rustc",
+        args: &["--version"],
+        required: true,
+        hint: "Install Rust with rustup from https://rustup.rs",
+    },
+    Tool {
+        name: "cargo",
+        program: "cargo",
+        args: &["--version"],
+        required: true,
+        hint: "Install Rust with rustup from https://rustup.rs",
+    },
+    Tool {
+        name: "clippy",
+        program: "cargo",
+        args: &["clippy", "--version"],
+        required: false,
+        hint: "Run `rustup component add clippy`",
+    },
+    Tool {
+        name: "rustfmt",
+        program: "rustfmt",
+        args: &["--version"],
+        required: false,
+        hint: "Run `rustup component add rustfmt`",
+    },
+];
+
+static GO_TOOLS: &[Tool] = &[
+    Tool {
+        name: "go",
+        program: "go",
+        args: &["version"],
+        required: true,
+        hint: "Install Go from https://go.dev/dl",
+    },
+    Tool {
+        name: "golangci-lint",
+        program: "golangci-lint",
+        args: &["--version"],
+        required: false,
+        hint: "Install golangci-lint from https://golangci-lint.run/welcome/install",
+    },
+    Tool {
+        name: "buf",
+        program: "buf",
+        args: &["--version"],
+        required: false,
+        hint: "Install buf from https://buf.build/docs/installation",
+    },
+];
+
+static TYPESCRIPT_TOOLS: &[Tool] = &[
+    Tool {
+        name: "bun",
+        program: "bun",
+        args: &["--version"],
+        required: true,
+        hint: "Install Bun from https://bun.sh",
+    },
+    Tool {
+        name: "node",
+        program: "node",
+        args: &["--version"],
+        required: false,
+        hint: "Install Node.js from https://nodejs.org",
+    },
+    Tool {
+        name: "tsc",
+        program: "tsc",
+        args: &["--version"],
+        required: false,
+        hint: "Run `bun add --global typescript`",
+    },
+];
+
+static PYTHON_TOOLS: &[Tool] = &[
+    Tool {
+        name: "uv",
+        program: "uv",
+        args: &["--version"],
+        required: true,
+        hint: "Install uv from https://docs.astral.sh/uv/getting-started/installation",
+    },
+    Tool {
+        name: "python",
+        program: "python3",
+        args: &["--version"],
+        required: false,
+        hint: "Run `uv python install` or install Python from https://www.python.org/downloads",
+    },
+    Tool {
+        name: "ruff",
+        program: "ruff",
+        args: &["--version"],
+        required: false,
+        hint: "Run `uv tool install ruff`",
+    },
+];
+
+static ZIG_TOOLS: &[Tool] = &[
+    Tool {
+        name: "zig",
+        program: "zig",
+        args: &["version"],
+        required: true,
+        hint: "Install Zig from https://ziglang.org/download",
+    },
+    Tool {
+        name: "zls",
+        program: "zls",
+        args: &["--version"],
+        required: false,
+        hint: "Install the Zig language server from https://github.com/zigtools/zls",
+    },
+];
+
+static CPP_TOOLS: &[Tool] = &[
+    Tool {
+        name: "cmake",
+        program: "cmake",
+        args: &["--version"],
+        required: true,
+        hint: "Install CMake from https://cmake.org/download",
+    },
+    Tool {
+        name: "c++",
+        program: "c++",
+        args: &["--version"],
+        required: true,
+        hint: "Install a C++ compiler such as GCC, Clang or the Visual Studio build tools",
+    },
+    Tool {
+        name: "ninja",
+        program: "ninja",
+        args: &["--version"],
+        required: false,
+        hint: "Install Ninja from https://ninja-build.org",
+    },
+    Tool {
+        name: "clang-format",
+        program: "clang-format",
+        args: &["--version"],
+        required: false,
+        hint: "Install clang-format with the LLVM tools from https://releases.llvm.org",
+    },
+    Tool {
+        name: "clang-tidy",
+        program: "clang-tidy",
+        args: &["--version"],
+        required: false,
+        hint: "Install clang-tidy with the LLVM tools from https://releases.llvm.org",
+    },
+];
+
+/// Returns the tools Nub checks for the given language.
+pub fn tools(language: Language) -> &'static [Tool] {
+    match language {
+        Language::Cpp => CPP_TOOLS,
+        Language::Go => GO_TOOLS,
+        Language::Python => PYTHON_TOOLS,
+        Language::Rust => RUST_TOOLS,
+        Language::Typescript => TYPESCRIPT_TOOLS,
+        Language::Zig => ZIG_TOOLS,
+    }
+}
+
+impl Tool {
+    /// Runs the tool to find out whether it is installed and which version.
+    pub fn check(&self) -> ToolStatus {
+        let output = Command::new(self.program).args(self.args).output();
+        match output {
+            Ok(output) if output.status.success() => {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                ToolStatus::Installed(Version::find(&stdout))
+            }
+            _ => ToolStatus::Missing,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ToolStatus {
+    Missing,
+    Installed(Option<Version>),
+}
+
+/// The outcome of checking a tool against an optional minimum version.
+pub struct ToolReport {
+    pub tool: &'static Tool,
+    pub status: ToolStatus,
+    pub minimum: Option<Version>,
+}
+
+impl ToolReport {
+    /// Whether the tool is missing or older than the declared minimum.
+    pub fn is_satisfied(&self) -> bool {
+        match (&self.status, &self.minimum) {
+            (ToolStatus::Missing, _) => false,
+            (ToolStatus::Installed(Some(version)), Some(minimum)) => version >= minimum,
+            (ToolStatus::Installed(_), _) => true,
+        }
+    }
+
+    /// Whether an unsatisfied tool should prevent working on the project.
+    pub fn is_required(&self) -> bool {
+        self.tool.required || self.minimum.is_some()
+    }
+
+    pub fn print(&self) {
+        match &self.status {
+            ToolStatus::Installed(version) => {
+                let version = version
+                    .as_ref()
+                    .map(|v| v.to_string())
+                    .unwrap_or("unknown version".to_string());
+                match &self.minimum {
+                    Some(minimum) if !self.is_satisfied() => {
+                        println!("> {:<15} {version} (requires {minimum})", self.tool.name);
+                        println!("  {}", self.tool.hint);
+                    }
+                    _ => println!("> {:<15} {version}", self.tool.name),
+                }
+            }
+            ToolStatus::Missing => {
+                let optional = if self.is_required() {
+                    ""
+                } else {
+                    " (optional)"
+                };
+                println!("> {:<15} missing{optional}", self.tool.name);
+                println!("  {}", self.tool.hint);
+            }
+        }
+    }
+}
+
+/// Checks every tool of a language against the given minimum versions.
+pub fn check(language: Language, requirements: &BTreeMap<String, Version>) -> Vec<ToolReport> {
+    tools(language)
+        .iter()
+        .map(|tool| ToolReport {
+            tool,
+            status: tool.check(),
+            minimum: requirements.get(tool.name).cloned(),
+        })
+        .collect()
+}
+
+/// A dotted numeric version such as `1.85` or `1.24.3`.
+///
+/// Missing components count as zero, so `1.85` equals `1.85.0`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Version(Vec<u64>);
+
+impl Version {
+    /// Finds the first version number in a tool's `--version` output.
+    ///
+    /// Handles prefixed forms such as `go1.24.3` or `v20.1.0`.
+    fn find(output: &str) -> Option<Version> {
+        output
+            .split_whitespace()
+            .map(|word| word.trim_start_matches(|c: char| !c.is_ascii_digit()))
+            .filter(|word| word.contains('.'))
+            .find_map(|word| {
+                let end = word
+                    .find(|c: char| !c.is_ascii_digit() && c != '.')
+                    .unwrap_or(word.len());
+                word[..end].trim_end_matches('.').parse().ok()
+            })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.0.len().max(other.0.len());
+        (0..len)
+            .map(|index| {
+                let part = |version: &Self| version.0.get(index).copied().unwrap_or_default();
+                part(self).cmp(&part(other))
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+#[derive(Debug)]
+pub struct ParseVersionError(String);
+
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid version {}", self.0)
+    }
+}
+
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split('.')
+            .map(|part| part.parse())
+            .collect::<Result<Vec<_>, _>>()
+            .map(Version)
+            .map_err(|_| ParseVersionError(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for Version {
+    type Error = ParseVersionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<_> = self.0.iter().map(|part| part.to_string()).collect();
+        write!(f, "{}", parts.join("."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(s: &str) -> Version {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn finds_versions_in_tool_output() {
+        let cases = [
+            ("rustc 1.85.0 (4d91de4e4 2025-02-17)", Some("1.85.0")),
+            ("cargo 1.85.0-nightly (abcdef 2025-01-01)", Some("1.85.0")),
+            ("go version go1.24.3 linux/amd64", Some("1.24.3")),
+            ("v20.1.0", Some("20.1.0")),
+            ("1.2.0", Some("1.2.0")),
+            ("Python 3.12.4", Some("3.12.4")),
+            ("0.14.0-dev.3+abc", Some("0.14.0")),
+            (
+                "cmake version 3.28.3\n\nCMake suite maintained by Kitware",
+                Some("3.28.3"),
+            ),
+            ("ruff 0.6.", Some("0.6")),
+            ("tool build 42", None),
+            ("", None),
+        ];
+        for (output, expected) in cases {
+            assert_eq!(Version::find(output), expected.map(version), "{output}");
+        }
+    }
+
+    #[test]
+    fn compares_versions_with_missing_components_as_zero() {
+        assert_eq!(version("1.85"), version("1.85.0"));
+        assert!(version("1.85.1") > version("1.85"));
+        assert!(version("1.9") < version("1.85"));
+        assert!(version("2") > version("1.99.99"));
+        assert!(version("1.85.0") >= version("1.85"));
+    }
+}
@@ -0,0 +1,499 @@
+use std::{fs, path::Path};
+
+use clap::ValueEnum;
+
+use crate::{
+    deploy::{self, GeneratedFile},
+    errors::CliError,
+    template::Language,
+};
+
+const EDITORCONFIG: &str = include_str!("presets/editorconfig");
+const RUSTFMT: &str = include_str!("presets/rustfmt.toml");
+const CLIPPY: &str = include_str!("presets/clippy.toml");
+const CARGO_DENY: &str = include_str!("presets/deny.toml");
+const GOLANGCI: &str = include_str!("presets/golangci.yml");
+const BIOME: &str = include_str!("presets/biome.json");
+const TSCONFIG: &str = include_str!("presets/tsconfig.json");
+const RUFF: &str = include_str!("presets/ruff.toml");
+const CLANG_FORMAT: &str = include_str!("presets/clang-format");
+const CLANG_TIDY: &str = include_str!("presets/clang-tidy");
+
+/// Compiler options turned on in an existing `tsconfig.json`.
+const STRICT_TS_OPTIONS: &[&str] = &[
+    "strict",
+    "noUncheckedIndexedAccess",
+    "noImplicitOverride",
+    "noImplicitReturns",
+    "noFallthroughCasesInSwitch",
+    "noUnusedLocals",
+    "noUnusedParameters",
+];
+
+/// Git hooks managers Nub can configure.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GitHooks {
+    #[default]
+    Lefthook,
+    PreCommit,
+}
+
+/// Task runners Nub can write tasks for.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TaskRunner {
+    #[default]
+    Just,
+    Make,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ToolingOptions {
+    pub hooks: GitHooks,
+    pub tasks: TaskRunner,
+}
+
+/// When a task runs automatically, besides being available from the task runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Manual,
+    PreCommit,
+    PrePush,
+}
+
+struct Task {
+    name: &'static str,
+    description: &'static str,
+    command: &'static str,
+    stage: Stage,
+}
+
+static RUST_TASKS: &[Task] = &[
+    Task {
+        name: "fmt",
+        description: "Format the code",
+        command: "cargo fmt --all",
+        stage: Stage::Manual,
+    },
+    Task {
+        name: "fmt-check",
+        description: "Check the formatting",
+        command: "cargo fmt --all -- --check",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "lint",
+        description: "Run the linter",
+        command: "cargo clippy --all-targets --all-features -- -D warnings",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "test",
+        description: "Run the tests",
+        command: "cargo test --all-features",
+        stage: Stage::PrePush,
+    },
+    Task {
+        name: "audit",
+        description: "Check dependencies for advisories and licenses",
+        command: "cargo deny check",
+        stage: Stage::Manual,
+    },
+    Task {
+        name: "build",
+        description: "Build the project in release mode",
+        command: "cargo build --release",
+        stage: Stage::Manual,
+    },
+];
+
+static GO_TASKS: &[Task] = &[
+    Task {
+        name: "fmt",
+        description: "Format the code",
+        command: "golangci-lint fmt",
+        stage: Stage::Manual,
+    },
+    Task {
+        name: "fmt-check",
+        description: "Check the formatting",
+        command: "golangci-lint fmt --diff",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "lint",
+        description: "Run the linters",
+        command: "golangci-lint run",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "test",
+        description: "Run the tests with the race detector",
+        command: "go test -race ./...",
+        stage: Stage::PrePush,
+    },
+    Task {
+        name: "build",
+        description: "Build every package",
+        command: "go build ./...",
+        stage: Stage::Manual,
+    },
+];
+
+static TYPESCRIPT_TASKS: &[Task] = &[
+    Task {
+        name: "fmt",
+        description: "Format the code",
+        command: "bunx biome format --write .",
+        stage: Stage::Manual,
+    },
+    Task {
+        name: "fmt-check",
+        description: "Check the formatting",
+        command: "bunx biome format .",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "lint",
+        description: "Run the linter",
+        command: "bunx biome lint .",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "typecheck",
+        description: "Check the types",
+        command: "bunx tsc --noEmit",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "test",
+        description: "Run the tests",
+        command: "bun test",
+        stage: Stage::PrePush,
+    },
+];
+
+static PYTHON_TASKS: &[Task] = &[
+    Task {
+        name: "fmt",
+        description: "Format the code",
+        command: "uv run ruff format .",
+        stage: Stage::Manual,
+    },
+    Task {
+        name: "fmt-check",
+        description: "Check the formatting",
+        command: "uv run ruff format --check .",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "lint",
+        description: "Run the linter",
+        command: "uv run ruff check .",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "test",
+        description: "Run the tests",
+        command: "uv run pytest",
+        stage: Stage::PrePush,
+    },
+    Task {
+        name: "build",
+        description: "Build the source distribution and wheel",
+        command: "uv build",
+        stage: Stage::Manual,
+    },
+];
+
+static ZIG_TASKS: &[Task] = &[
+    Task {
+        name: "fmt",
+        description: "Format the code",
+        command: "zig fmt .",
+        stage: Stage::Manual,
+    },
+    Task {
+        name: "fmt-check",
+        description: "Check the formatting",
+        command: "zig fmt --check .",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "test",
+        description: "Run the tests",
+        command: "zig build test",
+        stage: Stage::PrePush,
+    },
+    Task {
+        name: "build",
+        description: "Build the project in release mode",
+        command: "zig build -Doptimize=ReleaseSafe",
+        stage: Stage::Manual,
+    },
+];
+
+static CPP_TASKS: &[Task] = &[
+    Task {
+        name: "fmt",
+        description: "Format the code",
+        command: "find src include tests -name '*.cpp' -o -name '*.hpp' | xargs clang-format -i",
+        stage: Stage::Manual,
+    },
+    Task {
+        name: "fmt-check",
+        description: "Check the formatting",
+        command: "find src include tests -name '*.cpp' -o -name '*.hpp' | xargs clang-format --dry-run --Werror",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "lint",
+        description: "Run clang-tidy on the sources",
+        command: "cmake -S . -B build && clang-tidy -p build src/*.cpp",
+        stage: Stage::PreCommit,
+    },
+    Task {
+        name: "test",
+        description: "Build and run the tests",
+        command: "cmake -S . -B build && cmake --build build && ctest --test-dir build --output-on-failure",
+        stage: Stage::PrePush,
+    },
+    Task {
+        name: "build",
+        description: "Build the project in release mode",
+        command: "cmake -S . -B build -DCMAKE_BUILD_TYPE=Release && cmake --build build",
+        stage: Stage::Manual,
+    },
+];
+
+fn tasks(language: Language) -> &'static [Task] {
+    match language {
+        Language::Cpp => CPP_TASKS,
+        Language::Go => GO_TASKS,
+        Language::Python => PYTHON_TASKS,
+        Language::Rust => RUST_TASKS,
+        Language::Typescript => TYPESCRIPT_TASKS,
+        Language::Zig => ZIG_TASKS,
+    }
+}
+
+/// Renders the editor, linter, formatter, git hooks and task runner configurations.
+pub fn files(language: Language, options: ToolingOptions) -> Vec<GeneratedFile> {
+    let mut files = vec![GeneratedFile::new(
+        ".editorconfig",
+        EDITORCONFIG.to_string(),
+    )];
+    match language {
+        Language::Cpp => files.extend([
+            GeneratedFile::new(".clang-format", CLANG_FORMAT.to_string()),
+            GeneratedFile::new(".clang-tidy", CLANG_TIDY.to_string()),
+        ]),
+        Language::Go => files.push(GeneratedFile::new(".golangci.yml", GOLANGCI.to_string())),
+        Language::Rust => files.extend([
+            GeneratedFile::new("rustfmt.toml", RUSTFMT.to_string()),
+            GeneratedFile::new("clippy.toml", CLIPPY.to_string()),
+            GeneratedFile::new("deny.toml", CARGO_DENY.to_string()),
+        ]),
+        Language::Typescript => files.extend([
+            GeneratedFile::new("biome.json", BIOME.to_string()),
+            GeneratedFile::new("tsconfig.json", TSCONFIG.to_string()),
+        ]),
+        Language::Python => files.push(GeneratedFile::new("ruff.toml", RUFF.to_string())),
+        // `zig fmt` has no options to configure
+        Language::Zig => {}
+    }
+
+    let tasks = tasks(language);
+    files.push(match options.hooks {
+        GitHooks::Lefthook => GeneratedFile::new("lefthook.yml", lefthook(tasks)),
+        GitHooks::PreCommit => GeneratedFile::new(".pre-commit-config.yaml", pre_commit(tasks)),
+    });
+    files.push(match options.tasks {
+        TaskRunner::Just => GeneratedFile::new("Justfile", justfile(tasks)),
+        TaskRunner::Make => GeneratedFile::new("Makefile", makefile(tasks)),
+    });
+    files
+}
+
+/// Writes the configurations to `root`, tightening an existing `tsconfig.json` rather than
+/// replacing it unless `force` is set.
+pub fn init(
+    root: &Path,
+    language: Language,
+    options: ToolingOptions,
+    force: bool,
+) -> Result<(), CliError> {
+    let mut files = files(language, options);
+
+    let tsconfig_path = root.join("tsconfig.json");
+    if language == Language::Typescript && tsconfig_path.exists() && !force {
+        files.retain(|file| file.path != "tsconfig.json");
+        match strict_tsconfig(&fs::read_to_string(&tsconfig_path)?) {
+            TsconfigUpdate::Updated(content) => {
+                fs::write(&tsconfig_path, content)?;
+                println!("> Updated tsconfig.json with strict compiler options");
+            }
+            TsconfigUpdate::Unchanged => println!("> Skipped tsconfig.json (already strict)"),
+            TsconfigUpdate::Unsupported => {
+                println!("> Skipped tsconfig.json (no compilerOptions block found)")
+            }
+        }
+    }
+
+    deploy::write_files(root, &files, force)?;
+
+    match options.hooks {
+        GitHooks::Lefthook => println!("\nRun `lefthook install` to enable the git hooks"),
+        GitHooks::PreCommit => println!("\nRun `pre-commit install` to enable the git hooks"),
+    }
+    Ok(())
+}
+
+enum TsconfigUpdate {
+    Updated(String),
+    Unchanged,
+    Unsupported,
+}
+
+/// Turns on the strict compiler options, editing lines in place so comments survive.
+fn strict_tsconfig(content: &str) -> TsconfigUpdate {
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let mut changed = false;
+    let mut missing = Vec::new();
+
+    for option in STRICT_TS_OPTIONS {
+        let key = format!("\"{option}\"");
+        match lines
+            .iter_mut()
+            .find(|line| line.trim_start().starts_with(&key))
+        {
+            Some(line) if line.contains("false") => {
+                *line = line.replacen("false", "true", 1);
+                changed = true;
+            }
+            Some(_) => {}
+            None => missing.push(option),
+        }
+    }
+
+    if !missing.is_empty() {
+        // Groups the new options with `strict` when it is followed by other entries
+        let strict = lines.iter().position(|line| {
+            line.trim_start().starts_with("\"strict\"") && line.trim_end().ends_with(',')
+        });
+        let compiler_options = || {
+            lines.iter().position(|line| {
+                line.trim_start().starts_with("\"compilerOptions\"")
+                    && line.trim_end().ends_with('{')
+            })
+        };
+        let Some(index) = strict.or_else(compiler_options) else {
+            return TsconfigUpdate::Unsupported;
+        };
+        let indent = lines
+            .get(index + usize::from(strict.is_none()))
+            .map(|line| line[..line.len() - line.trim_start().len()].to_string())
+            .unwrap_or_else(|| "    ".to_string());
+        let inserted = missing
+            .iter()
+            .map(|option| format!("{indent}\"{option}\": true,"));
+        lines.splice(index + 1..index + 1, inserted);
+        changed = true;
+    }
+
+    if changed {
+        TsconfigUpdate::Updated(lines.join("\n") + "\n")
+    } else {
+        TsconfigUpdate::Unchanged
+    }
+}
+
+fn lefthook(tasks: &[Task]) -> String {
+    let mut content = String::from("# Git hooks run by lefthook, see https://lefthook.dev\n");
+    for (hook, stage, parallel) in [
+        ("pre-commit", Stage::PreCommit, true),
+        ("pre-push", Stage::PrePush, false),
+    ] {
+        content.push_str(&format!("\n{hook}:\n"));
+        if parallel {
+            content.push_str("  parallel: true\n");
+        }
+        content.push_str("  commands:\n");
+        for task in tasks.iter().filter(|task| task.stage == stage) {
+            content.push_str(&format!(
+                "    {}:\n      run: {}\n",
+                task.name, task.command
+            ));
+        }
+    }
+    content
+}
+
+fn pre_commit(tasks: &[Task]) -> String {
+    let mut content = String::from(
+        "# Git hooks run by pre-commit, see https://pre-commit.com\n\
+         default_install_hook_types: [pre-commit, pre-push]\n\
+         \n\
+         repos:\n  - repo: local\n    hooks:\n",
+    );
+    for task in tasks.iter().filter(|task| task.stage != Stage::Manual) {
+        let stage = match task.stage {
+            Stage::PrePush => "pre-push",
+            _ => "pre-commit",
+        };
+        content.push_str(&format!(
+            "      - id: {}\n        name: {}\n        entry: {}\n        language: system\n        pass_filenames: false\n        stages: [{stage}]\n",
+            task.name, task.description, task.command
+        ));
+    }
+    content
+}
+
+fn justfile(tasks: &[Task]) -> String {
+    let mut content = String::from(
+        "# Project tasks, run with `just <task>`, see https://just.systems\n\n\
+         # List the available tasks\n\
+         default:\n    @just --list\n",
+    );
+    for task in tasks {
+        content.push_str(&format!(
+            "\n# {}\n{}:\n    {}\n",
+            task.description, task.name, task.command
+        ));
+    }
+    content.push_str(&format!(
+        "\n# Run the checks done by the git hooks\ncheck: {}\n",
+        check_dependencies(tasks)
+    ));
+    content
+}
+
+fn makefile(tasks: &[Task]) -> String {
+    let names: Vec<&str> = tasks.iter().map(|task| task.name).collect();
+    let mut content = format!(
+        "# Project tasks, run with `make <task>`\n\n\
+         .PHONY: help {} check\n\n\
+         help: ## List the available tasks\n\
+         \t@grep -E '^[a-z-]+:.*## ' $(MAKEFILE_LIST) | awk 'BEGIN {{FS = \":.*## \"}} {{printf \"  %-12s %s\\n\", $$1, $$2}}'\n",
+        names.join(" ")
+    );
+    for task in tasks {
+        content.push_str(&format!(
+            "\n{}: ## {}\n\t{}\n",
+            task.name, task.description, task.command
+        ));
+    }
+    content.push_str(&format!(
+        "\ncheck: {} ## Run the checks done by the git hooks\n",
+        check_dependencies(tasks)
+    ));
+    content
+}
+
+fn check_dependencies(tasks: &[Task]) -> String {
+    tasks
+        .iter()
+        .filter(|task| task.stage != Stage::Manual)
+        .map(|task| task.name)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
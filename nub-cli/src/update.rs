@@ -0,0 +1,311 @@
+use std::{fs, path::Path};
+
+use crate::{
+    errors::CliError,
+    provenance::{TemplateSnapshot, snapshot_path},
+    template::RenderedFile,
+};
+
+/// What updating the template does to a project file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// New in the template and missing from the project.
+    Added,
+    /// Changed in the template and untouched in the project.
+    Updated,
+    /// Changed on both sides without overlapping.
+    Merged,
+    /// Changed on both sides in the same places, written with conflict markers.
+    Conflict,
+    /// Changed in the template but deleted from the project, left deleted.
+    Deleted,
+    /// Removed from the template, left in the project.
+    Removed,
+}
+
+/// A change to a project file, with the content to write if any.
+#[derive(Debug, Clone)]
+pub struct FileChange {
+    pub path: String,
+    pub outcome: Outcome,
+    content: Option<Vec<u8>>,
+}
+
+/// Three-way merges the newly rendered template into the project at `root`.
+///
+/// The snapshot of the previous rendering is the common ancestor, so edits made to the
+/// project since are kept and only the template changes are brought in. Files the
+/// template did not change are left alone whatever their state in the project.
+pub fn plan(
+    root: &Path,
+    base: &TemplateSnapshot,
+    rendered: &[RenderedFile],
+) -> Result<Vec<FileChange>, CliError> {
+    let mut changes = Vec::new();
+
+    for file in rendered {
+        let path = snapshot_path(&file.path);
+        let theirs = &file.content;
+        let ours = fs::read(root.join(&file.path)).ok();
+        let ancestor = base.files.get(&path);
+
+        if ancestor.is_some_and(|ancestor| ancestor.as_bytes() == theirs.as_slice())
+            || ours.as_ref() == Some(theirs)
+        {
+            continue;
+        }
+
+        let change = match (ancestor, ours) {
+            (None, None) => FileChange::write(path, Outcome::Added, theirs.clone()),
+            (Some(_), None) => FileChange::report(path, Outcome::Deleted),
+            (Some(ancestor), Some(ours)) if ancestor.as_bytes() == ours.as_slice() => {
+                FileChange::write(path, Outcome::Updated, theirs.clone())
+            }
+            // Added on both sides with different contents, merged against an empty ancestor
+            (ancestor, Some(ours)) => {
+                let ancestor = ancestor.map(String::as_str).unwrap_or_default();
+                match (String::from_utf8(ours), std::str::from_utf8(theirs)) {
+                    (Ok(ours), Ok(theirs)) => match diffy::merge(ancestor, &ours, theirs) {
+                        Ok(merged) => FileChange::write(path, Outcome::Merged, merged.into()),
+                        Err(conflicted) => {
+                            FileChange::write(path, Outcome::Conflict, conflicted.into())
+                        }
+                    },
+                    // Binary files cannot be merged, the project keeps its version
+                    _ => FileChange::report(path, Outcome::Conflict),
+                }
+            }
+        };
+        changes.push(change);
+    }
+
+    for path in base.files.keys() {
+        let still_rendered = rendered
+            .iter()
+            .any(|file| snapshot_path(&file.path) == *path);
+        if !still_rendered && root.join(path).exists() {
+            changes.push(FileChange::report(path.clone(), Outcome::Removed));
+        }
+    }
+
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(changes)
+}
+
+/// Writes the merged files to the project.
+pub fn apply(root: &Path, changes: &[FileChange]) -> Result<(), CliError> {
+    for change in changes {
+        if let Some(content) = &change.content {
+            let path = root.join(&change.path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, content)?;
+        }
+    }
+    Ok(())
+}
+
+impl FileChange {
+    fn write(path: String, outcome: Outcome, content: Vec<u8>) -> Self {
+        Self {
+            path,
+            outcome,
+            content: Some(content),
+        }
+    }
+
+    fn report(path: String, outcome: Outcome) -> Self {
+        Self {
+            path,
+            outcome,
+            content: None,
+        }
+    }
+
+    /// One line of the update report.
+    pub fn describe(&self) -> String {
+        let path = &self.path;
+        match self.outcome {
+            Outcome::Added => format!("Added {path}"),
+            Outcome::Updated => format!("Updated {path}"),
+            Outcome::Merged => format!("Merged {path}"),
+            Outcome::Conflict if self.content.is_none() => {
+                format!("Skipped {path} (binary file changed on both sides)")
+            }
+            Outcome::Conflict => format!("Conflict in {path}, resolve the conflict markers"),
+            Outcome::Deleted => format!("Skipped {path} (deleted in the project)"),
+            Outcome::Removed => format!("Kept {path} (no longer in the template)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn rendered(files: &[(&str, &str)]) -> Vec<RenderedFile> {
+        files
+            .iter()
+            .map(|(path, content)| RenderedFile {
+                path: PathBuf::from(path),
+                content: content.as_bytes().to_vec(),
+            })
+            .collect()
+    }
+
+    /// A project rendered from `base` with its files then replaced by `project`.
+    fn setup(
+        base: &[(&str, &str)],
+        project: &[(&str, &str)],
+    ) -> (tempfile::TempDir, TemplateSnapshot) {
+        let dir = tempfile::tempdir().unwrap();
+        for (path, content) in project {
+            let path = dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        (dir, TemplateSnapshot::new(&rendered(base)))
+    }
+
+    fn outcomes(changes: &[FileChange]) -> Vec<(&str, Outcome)> {
+        changes
+            .iter()
+            .map(|change| (change.path.as_str(), change.outcome))
+            .collect()
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    #[test]
+    fn unchanged_template_files_are_left_alone() {
+        let (dir, base) = setup(
+            &[("a.txt", "one\n"), ("b.txt", "two\n")],
+            &[("a.txt", "one\n"), ("b.txt", "edited\n")],
+        );
+        let changes = plan(
+            dir.path(),
+            &base,
+            &rendered(&[("a.txt", "one\n"), ("b.txt", "two\n")]),
+        )
+        .unwrap();
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn template_only_changes_are_applied() {
+        let (dir, base) = setup(&[("src/a.txt", "one\n")], &[("src/a.txt", "one\n")]);
+        let changes = plan(
+            dir.path(),
+            &base,
+            &rendered(&[("src/a.txt", "one\nmore\n")]),
+        )
+        .unwrap();
+        assert_eq!(outcomes(&changes), [("src/a.txt", Outcome::Updated)]);
+
+        apply(dir.path(), &changes).unwrap();
+        assert_eq!(read(dir.path(), "src/a.txt"), "one\nmore\n");
+    }
+
+    #[test]
+    fn user_only_changes_are_kept() {
+        let (dir, base) = setup(&[("a.txt", "one\n")], &[("a.txt", "mine\n")]);
+        let changes = plan(dir.path(), &base, &rendered(&[("a.txt", "one\n")])).unwrap();
+        assert!(changes.is_empty());
+        assert_eq!(read(dir.path(), "a.txt"), "mine\n");
+    }
+
+    #[test]
+    fn changes_on_both_sides_are_merged() {
+        let (dir, base) = setup(
+            &[("a.txt", "one\ntwo\nthree\nfour\nfive\n")],
+            &[("a.txt", "ONE\ntwo\nthree\nfour\nfive\n")],
+        );
+        let changes = plan(
+            dir.path(),
+            &base,
+            &rendered(&[("a.txt", "one\ntwo\nthree\nfour\nFIVE\n")]),
+        )
+        .unwrap();
+        assert_eq!(outcomes(&changes), [("a.txt", Outcome::Merged)]);
+
+        apply(dir.path(), &changes).unwrap();
+        assert_eq!(read(dir.path(), "a.txt"), "ONE\ntwo\nthree\nfour\nFIVE\n");
+    }
+
+    #[test]
+    fn overlapping_changes_are_written_with_conflict_markers() {
+        let (dir, base) = setup(
+            &[("a.txt", "one\nvalue = 1\n")],
+            &[("a.txt", "one\nvalue = 2\n")],
+        );
+        let changes = plan(
+            dir.path(),
+            &base,
+            &rendered(&[("a.txt", "one\nvalue = 3\n")]),
+        )
+        .unwrap();
+        assert_eq!(outcomes(&changes), [("a.txt", Outcome::Conflict)]);
+        assert!(changes[0].describe().contains("conflict markers"));
+
+        apply(dir.path(), &changes).unwrap();
+        let merged = read(dir.path(), "a.txt");
+        assert!(merged.starts_with("one\n<<<<<<<"), "{merged}");
+        assert!(merged.contains("value = 2\n"), "{merged}");
+        assert!(merged.contains("=======\nvalue = 3\n>>>>>>>"), "{merged}");
+    }
+
+    #[test]
+    fn binary_conflicts_keep_the_project_version() {
+        let (dir, base) = setup(&[], &[]);
+        fs::write(dir.path().join("icon.bin"), [0xff, 0x00]).unwrap();
+        let file = RenderedFile {
+            path: PathBuf::from("icon.bin"),
+            content: vec![0xfe, 0x01],
+        };
+        let changes = plan(dir.path(), &base, &[file]).unwrap();
+        assert_eq!(outcomes(&changes), [("icon.bin", Outcome::Conflict)]);
+
+        apply(dir.path(), &changes).unwrap();
+        assert_eq!(fs::read(dir.path().join("icon.bin")).unwrap(), [0xff, 0x00]);
+    }
+
+    #[test]
+    fn files_deleted_from_the_project_stay_deleted() {
+        let (dir, base) = setup(&[("a.txt", "one\n")], &[]);
+        let changes = plan(dir.path(), &base, &rendered(&[("a.txt", "two\n")])).unwrap();
+        assert_eq!(outcomes(&changes), [("a.txt", Outcome::Deleted)]);
+
+        apply(dir.path(), &changes).unwrap();
+        assert!(!dir.path().join("a.txt").exists());
+    }
+
+    #[test]
+    fn files_new_in_the_template_are_added() {
+        let (dir, base) = setup(&[], &[]);
+        let changes = plan(
+            dir.path(),
+            &base,
+            &rendered(&[("ci/build.yml", "on: push\n")]),
+        )
+        .unwrap();
+        assert_eq!(outcomes(&changes), [("ci/build.yml", Outcome::Added)]);
+
+        apply(dir.path(), &changes).unwrap();
+        assert_eq!(read(dir.path(), "ci/build.yml"), "on: push\n");
+    }
+
+    #[test]
+    fn files_removed_from_the_template_are_kept() {
+        let (dir, base) = setup(&[("old.txt", "old\n")], &[("old.txt", "old\n")]);
+        let changes = plan(dir.path(), &base, &[]).unwrap();
+        assert_eq!(outcomes(&changes), [("old.txt", Outcome::Removed)]);
+
+        apply(dir.path(), &changes).unwrap();
+        assert_eq!(read(dir.path(), "old.txt"), "old\n");
+    }
+}
@@ -0,0 +1,612 @@
+use crate::{
+    errors::CliError,
+    template::{Language, TemplateVariables},
+};
+
+/// Names rejected by Cargo because they clash with Rust keywords, built-in crates or
+/// build artifact directories.
+static CARGO_RESERVED_NAMES: &[&str] = &[
+    "abstract",
+    "alloc",
+    "as",
+    "async",
+    "await",
+    "become",
+    "box",
+    "break",
+    "build",
+    "const",
+    "continue",
+    "core",
+    "crate",
+    "deps",
+    "do",
+    "dyn",
+    "else",
+    "enum",
+    "examples",
+    "extern",
+    "false",
+    "final",
+    "fn",
+    "for",
+    "if",
+    "impl",
+    "in",
+    "incremental",
+    "let",
+    "loop",
+    "macro",
+    "match",
+    "mod",
+    "move",
+    "mut",
+    "override",
+    "priv",
+    "proc-macro",
+    "proc_macro",
+    "pub",
+    "ref",
+    "return",
+    "self",
+    "static",
+    "std",
+    "struct",
+    "super",
+    "test",
+    "trait",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "unsafe",
+    "unsized",
+    "use",
+    "virtual",
+    "where",
+    "while",
+    "yield",
+];
+
+/// Names rejected by npm because they clash with Node.js core modules or special files.
+static NPM_RESERVED_NAMES: &[&str] = &[
+    "assert",
+    "buffer",
+    "child_process",
+    "crypto",
+    "events",
+    "favicon.ico",
+    "fs",
+    "http",
+    "https",
+    "net",
+    "node_modules",
+    "os",
+    "path",
+    "process",
+    "stream",
+    "url",
+    "util",
+];
+
+/// Python keywords, which the importable module of a package cannot be named after.
+static PYTHON_KEYWORDS: &[&str] = &[
+    "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif",
+    "else", "except", "false", "finally", "for", "from", "global", "if", "import", "in", "is",
+    "lambda", "none", "nonlocal", "not", "or", "pass", "raise", "return", "true", "try", "while",
+    "with", "yield",
+];
+
+/// Zig keywords, rejected as package names since `build.zig.zon` names are identifiers.
+static ZIG_KEYWORDS: &[&str] = &[
+    "addrspace",
+    "align",
+    "allowzero",
+    "and",
+    "anyframe",
+    "anytype",
+    "asm",
+    "break",
+    "callconv",
+    "catch",
+    "comptime",
+    "const",
+    "continue",
+    "defer",
+    "else",
+    "enum",
+    "errdefer",
+    "error",
+    "export",
+    "extern",
+    "fn",
+    "for",
+    "if",
+    "inline",
+    "linksection",
+    "noalias",
+    "noinline",
+    "nosuspend",
+    "opaque",
+    "or",
+    "orelse",
+    "packed",
+    "pub",
+    "resume",
+    "return",
+    "struct",
+    "suspend",
+    "switch",
+    "test",
+    "threadlocal",
+    "try",
+    "union",
+    "unreachable",
+    "var",
+    "volatile",
+    "while",
+];
+
+/// Target names reserved by the CMake generators.
+static CMAKE_RESERVED_NAMES: &[&str] = &["all", "clean", "help", "install", "package", "test"];
+
+/// Device names that cannot be used as file or directory names on Windows.
+static WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "aux", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8", "com9", "con", "lpt1",
+    "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9", "nul", "prn",
+];
+
+const CARGO_MAX_LENGTH: usize = 64;
+const NPM_MAX_LENGTH: usize = 214;
+const ZIG_MAX_LENGTH: usize = 32;
+
+/// Checks the project name, or the module path for Go, against the rules of the
+/// language's package manager so the generated project builds out of the box.
+pub fn validate_project(language: Language, variables: &TemplateVariables) -> Result<(), CliError> {
+    let (name, result) = match language {
+        Language::Cpp => (&variables.name, check_cmake_name(&variables.name)),
+        Language::Go => (&variables.go_module, check_go_module(&variables.go_module)),
+        Language::Python => (&variables.name, check_python_name(&variables.name)),
+        Language::Rust => (&variables.name, check_cargo_name(&variables.name)),
+        Language::Typescript => (&variables.name, check_npm_name(&variables.name)),
+        Language::Zig => (&variables.name, check_zig_name(&variables.name)),
+    };
+
+    result.map_err(|reason| CliError::InvalidProjectName {
+        name: name.clone(),
+        reason,
+        suggestion: suggest_name(language, name),
+        flag: match language {
+            Language::Go => "--module",
+            _ => "--project-name",
+        },
+    })
+}
+
+/// Returns a corrected name following the conventions of the language.
+pub fn suggest_name(language: Language, name: &str) -> String {
+    match language {
+        Language::Cpp => {
+            let suggestion = sanitize(name, &['-', '_']);
+            if CMAKE_RESERVED_NAMES.contains(&suggestion.as_str()) {
+                format!("{suggestion}-app")
+            } else {
+                suggestion
+            }
+        }
+        Language::Go => name
+            .split('/')
+            .filter(|element| !element.trim().is_empty())
+            .enumerate()
+            .map(|(i, element)| {
+                // The first element is a domain or a local module name, without `_` or `~`
+                let (allowed, trimmed): (&[char], &[char]) = if i == 0 {
+                    (&['-', '.'], &['-', '.'])
+                } else {
+                    (&['-', '.', '_', '~'], &['.'])
+                };
+                sanitize(element, allowed).trim_matches(trimmed).to_string()
+            })
+            .filter(|element| !element.is_empty())
+            .collect::<Vec<_>>()
+            .join("/"),
+        Language::Python => {
+            let mut suggestion = sanitize(name, &['-', '.', '_'])
+                .trim_matches(['.', '_'])
+                .to_string();
+            if suggestion.starts_with(|c: char| c.is_ascii_digit()) {
+                suggestion = format!("app-{suggestion}");
+            }
+            if PYTHON_KEYWORDS.contains(&suggestion.as_str()) {
+                suggestion = format!("{suggestion}-app");
+            }
+            suggestion
+        }
+        Language::Rust => {
+            let mut suggestion = sanitize(name, &['-', '_']);
+            if suggestion.starts_with(|c: char| c.is_ascii_digit()) {
+                suggestion = format!("app-{suggestion}");
+            }
+            if CARGO_RESERVED_NAMES.contains(&suggestion.as_str())
+                || WINDOWS_RESERVED_NAMES.contains(&suggestion.as_str())
+            {
+                suggestion = format!("{suggestion}-app");
+            }
+            // Truncated last so the prefix or suffix cannot push it over the limit again
+            suggestion.truncate(CARGO_MAX_LENGTH);
+            suggestion.trim_end_matches('-').to_string()
+        }
+        Language::Typescript => {
+            let (scope, package) = match name.strip_prefix('@').and_then(|n| n.split_once('/')) {
+                Some((scope, package)) => (Some(scope), package),
+                None => (None, name),
+            };
+            let mut package = sanitize(package, &['-', '.', '_', '~'])
+                .trim_start_matches(['.', '_'])
+                .to_string();
+            if NPM_RESERVED_NAMES.contains(&package.as_str()) {
+                package = format!("{package}-app");
+            }
+            match scope {
+                Some(scope) => format!("@{}/{package}", sanitize(scope, &['-', '.', '_', '~'])),
+                None => package,
+            }
+        }
+        Language::Zig => {
+            let mut suggestion = sanitize(name, &['_']).replace('-', "_");
+            if suggestion.starts_with(|c: char| c.is_ascii_digit()) {
+                suggestion = format!("app_{suggestion}");
+            }
+            if ZIG_KEYWORDS.contains(&suggestion.as_str()) {
+                suggestion = format!("{suggestion}_app");
+            }
+            suggestion.truncate(ZIG_MAX_LENGTH);
+            suggestion
+        }
+    }
+}
+
+/// Lowercases the name and replaces unsupported characters with dashes.
+fn sanitize(name: &str, allowed: &[char]) -> String {
+    let replaced: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || allowed.contains(&c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+
+    let collapsed = replaced
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if collapsed.is_empty() {
+        "my-project".to_string()
+    } else {
+        collapsed
+    }
+}
+
+fn check_cargo_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Cargo package names cannot be empty".to_string());
+    }
+    if name.len() > CARGO_MAX_LENGTH {
+        return Err(format!(
+            "Cargo package names are limited to {CARGO_MAX_LENGTH} characters"
+        ));
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err("Cargo package names cannot start with a digit".to_string());
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '_')
+    {
+        return Err(format!(
+            "Cargo package names can only contain letters, digits, `-` and `_`, found `{c}`"
+        ));
+    }
+    if CARGO_RESERVED_NAMES.contains(&name) {
+        return Err(format!("`{name}` is reserved by Rust or Cargo"));
+    }
+    if WINDOWS_RESERVED_NAMES.contains(&name.to_lowercase().as_str()) {
+        return Err(format!("`{name}` is a reserved Windows file name"));
+    }
+    Ok(())
+}
+
+fn check_npm_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("npm package names cannot be empty".to_string());
+    }
+    if name.len() > NPM_MAX_LENGTH {
+        return Err(format!(
+            "npm package names are limited to {NPM_MAX_LENGTH} characters"
+        ));
+    }
+    if name.chars().any(|c| c.is_uppercase()) {
+        return Err("npm package names must be lowercase".to_string());
+    }
+
+    let package = match name.strip_prefix('@') {
+        Some(scoped) => {
+            let (scope, package) = scoped
+                .split_once('/')
+                .ok_or("scoped npm package names must look like @scope/name")?;
+            check_npm_characters(scope)?;
+            package
+        }
+        None => name,
+    };
+
+    if package.starts_with(['.', '_']) {
+        return Err("npm package names cannot start with `.` or `_`".to_string());
+    }
+    check_npm_characters(package)?;
+    if NPM_RESERVED_NAMES.contains(&package) {
+        return Err(format!("`{package}` is reserved by Node.js or npm"));
+    }
+    Ok(())
+}
+
+fn check_npm_characters(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("npm package and scope names cannot be empty".to_string());
+    }
+    match name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '-' | '.' | '_' | '~'))
+    {
+        Some(c) => Err(format!(
+            "npm package names can only contain URL-safe characters, found `{c}`"
+        )),
+        None => Ok(()),
+    }
+}
+
+fn check_go_module(path: &str) -> Result<(), String> {
+    if path.is_empty() {
+        return Err("Go module paths cannot be empty".to_string());
+    }
+    if path.starts_with('/') || path.ends_with('/') {
+        return Err("Go module paths cannot start or end with `/`".to_string());
+    }
+
+    for (i, element) in path.split('/').enumerate() {
+        if element.is_empty() {
+            return Err("Go module paths cannot contain empty elements".to_string());
+        }
+        if element.starts_with('.') || element.ends_with('.') {
+            return Err(format!(
+                "Go module path elements cannot start or end with `.`, found `{element}`"
+            ));
+        }
+        if let Some(c) = element
+            .chars()
+            .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '-' | '.' | '_' | '~'))
+        {
+            return Err(format!(
+                "Go module paths can only contain letters, digits and `-._~`, found `{c}`"
+            ));
+        }
+        if WINDOWS_RESERVED_NAMES.contains(&element.to_lowercase().as_str()) {
+            return Err(format!("`{element}` is a reserved Windows file name"));
+        }
+        // The first element is a domain or a local module name
+        if i == 0 {
+            if element.starts_with('-') {
+                return Err("Go module paths cannot start with `-`".to_string());
+            }
+            if element
+                .chars()
+                .any(|c| c.is_ascii_uppercase() || c == '_' || c == '~')
+            {
+                return Err(format!(
+                    "the first element of a Go module path must be lowercase without `_` or `~`, found `{element}`"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks a PEP 508 distribution name whose normalized form is also an importable module.
+fn check_python_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Python package names cannot be empty".to_string());
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '-' | '.' | '_'))
+    {
+        return Err(format!(
+            "Python package names can only contain letters, digits and `-._`, found `{c}`"
+        ));
+    }
+    if !name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        || !name.ends_with(|c: char| c.is_ascii_alphanumeric())
+    {
+        return Err("Python package names must start and end with a letter or digit".to_string());
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(
+            "Python package names cannot start with a digit, their module could not be imported"
+                .to_string(),
+        );
+    }
+    let module = crate::template::python_module(name);
+    if PYTHON_KEYWORDS.contains(&module.as_str()) {
+        return Err(format!("`{module}` is a Python keyword"));
+    }
+    Ok(())
+}
+
+fn check_zig_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Zig package names cannot be empty".to_string());
+    }
+    if name.len() > ZIG_MAX_LENGTH {
+        return Err(format!(
+            "Zig package names are limited to {ZIG_MAX_LENGTH} characters"
+        ));
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err("Zig package names cannot start with a digit".to_string());
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '_')
+    {
+        return Err(format!(
+            "Zig package names can only contain letters, digits and `_`, found `{c}`"
+        ));
+    }
+    if ZIG_KEYWORDS.contains(&name) {
+        return Err(format!("`{name}` is a Zig keyword"));
+    }
+    Ok(())
+}
+
+fn check_cmake_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("CMake project names cannot be empty".to_string());
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '_')
+    {
+        return Err(format!(
+            "CMake target names can only contain letters, digits, `-` and `_`, found `{c}`"
+        ));
+    }
+    if CMAKE_RESERVED_NAMES.contains(&name.to_lowercase().as_str()) {
+        return Err(format!("`{name}` is reserved by CMake"));
+    }
+    if WINDOWS_RESERVED_NAMES.contains(&name.to_lowercase().as_str()) {
+        return Err(format!("`{name}` is a reserved Windows file name"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_cargo_names() {
+        let cases = [
+            ("my-app", true),
+            ("my_app2", true),
+            ("", false),
+            ("2app", false),
+            ("my app", false),
+            ("my.app", false),
+            ("core", false),
+            ("self", false),
+            ("CON", false),
+            (&*"a".repeat(CARGO_MAX_LENGTH + 1), false),
+        ];
+        for (name, valid) in cases {
+            assert_eq!(check_cargo_name(name).is_ok(), valid, "{name}");
+        }
+    }
+
+    #[test]
+    fn checks_npm_names() {
+        let cases = [
+            ("my-app", true),
+            ("my.app~1", true),
+            ("@acme/my-app", true),
+            ("", false),
+            ("MyApp", false),
+            ("@acme", false),
+            ("@/my-app", false),
+            ("@acme/", false),
+            (".hidden", false),
+            ("_private", false),
+            ("my app", false),
+            ("fs", false),
+            ("@acme/fs", false),
+        ];
+        for (name, valid) in cases {
+            assert_eq!(check_npm_name(name).is_ok(), valid, "{name}");
+        }
+    }
+
+    #[test]
+    fn checks_go_modules() {
+        let cases = [
+            ("my-app", true),
+            ("github.com/acme/my_app", true),
+            ("example.com/acme/v2~beta", true),
+            ("", false),
+            ("/my-app", false),
+            ("my-app/", false),
+            ("github.com//app", false),
+            (".hidden/app", false),
+            ("my_app", false),
+            ("My-App", false),
+            ("-app", false),
+            ("github.com/acme/my app", false),
+            ("github.com/nul", false),
+        ];
+        for (path, valid) in cases {
+            assert_eq!(check_go_module(path).is_ok(), valid, "{path}");
+        }
+    }
+
+    #[test]
+    fn suggests_valid_names() {
+        let cases = [
+            (Language::Rust, "My App", "my-app"),
+            (Language::Rust, "2fast", "app-2fast"),
+            (Language::Rust, "core", "core-app"),
+            (
+                Language::Rust,
+                &*"1".repeat(64),
+                &*format!("app-{}", "1".repeat(60)),
+            ),
+            (
+                Language::Rust,
+                &*format!("{}-x", "a".repeat(63)),
+                &*"a".repeat(63),
+            ),
+            (Language::Typescript, "My App", "my-app"),
+            (Language::Typescript, "@Acme/My App", "@acme/my-app"),
+            (Language::Typescript, "_fs", "fs-app"),
+            (Language::Go, "my_app", "my-app"),
+            (Language::Go, "My~App", "my-app"),
+            (
+                Language::Go,
+                "GitHub.com/Acme/my_app/",
+                "github.com/acme/my_app",
+            ),
+            (Language::Python, "2 Fast", "app-2-fast"),
+            (Language::Python, "class", "class-app"),
+            (Language::Zig, "my-app", "my_app"),
+            (Language::Zig, "test", "test_app"),
+            (Language::Cpp, "My App", "my-app"),
+            (Language::Cpp, "install", "install-app"),
+        ];
+        for (language, name, expected) in cases {
+            let suggestion = suggest_name(language, name);
+            assert_eq!(suggestion, expected, "{language} {name}");
+            let variables = TemplateVariables {
+                name: suggestion.clone(),
+                go_module: suggestion.clone(),
+                package_manager: None,
+            };
+            assert!(
+                validate_project(language, &variables).is_ok(),
+                "{language} {suggestion}"
+            );
+        }
+    }
+}
@@ -0,0 +1,8 @@
+#include <iostream>
+
+#include "greet.hpp"
+
+int main() {
+    std::cout << greet("World") << '\n';
+    return 0;
+}
@@ -0,0 +1,13 @@
+#include <cstdlib>
+#include <iostream>
+
+#include "greet.hpp"
+
+int main() {
+    const auto greeting = greet("World");
+    if (greeting != "Hello World!") {
+        std::cerr << "expected \"Hello World!\", got \"" << greeting << "\"\n";
+        return EXIT_FAILURE;
+    }
+    return EXIT_SUCCESS;
+}
@@ -0,0 +1,5 @@
+module chi-rag
+
+go 1.24.3
+
+require github.com/go-chi/chi/v5 v5.2.2
@@ -0,0 +1,36 @@
+package main
+
+import (
+	"log"
+	"net/http"
+	"os"
+
+	"chi-rag/rag"
+)
+
+func main() {
+	store, err := rag.OpenStore(env("STORE_PATH", "data/store.json"))
+	if err != nil {
+		log.Fatalf("open store: %v", err)
+	}
+
+	provider := rag.NewOpenAI(
+		env("OPENAI_BASE_URL", "https://api.openai.com/v1"),
+		os.Getenv("OPENAI_API_KEY"),
+		env("OPENAI_MODEL", "gpt-4o-mini"),
+		env("OPENAI_EMBEDDING_MODEL", "text-embedding-3-small"),
+	)
+	engine := &rag.Engine{Provider: provider, Store: store, TopK: 4}
+
+	addr := ":" + env("PORT", "3000")
+	log.Printf("Server running at %s", addr)
+	log.Fatal(http.ListenAndServe(addr, newRouter(engine)))
+}
+
+// env returns the environment variable, or fallback when it is unset or empty.
+func env(key, fallback string) string {
+	if value := os.Getenv(key); value != "" {
+		return value
+	}
+	return fallback
+}
@@ -0,0 +1,106 @@
+package rag
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"strings"
+	"time"
+)
+
+// Message is a chat message sent to the model.
+type Message struct {
+	Role    string `json:"role"`
+	Content string `json:"content"`
+}
+
+// Provider embeds text and completes chats, implemented by OpenAI and by mocks in tests.
+type Provider interface {
+	Embed(ctx context.Context, texts []string) ([][]float32, error)
+	Complete(ctx context.Context, messages []Message) (string, error)
+}
+
+// OpenAI is a client for any server implementing the OpenAI API.
+type OpenAI struct {
+	BaseURL        string
+	APIKey         string
+	Model          string
+	EmbeddingModel string
+	HTTP           *http.Client
+}
+
+// NewOpenAI creates a client with a timeout suited to slow local models.
+func NewOpenAI(baseURL, apiKey, model, embeddingModel string) *OpenAI {
+	return &OpenAI{
+		BaseURL:        strings.TrimSuffix(baseURL, "/"),
+		APIKey:         apiKey,
+		Model:          model,
+		EmbeddingModel: embeddingModel,
+		HTTP:           &http.Client{Timeout: 2 * time.Minute},
+	}
+}
+
+func (c *OpenAI) Embed(ctx context.Context, texts []string) ([][]float32, error) {
+	var response struct {
+		Data []struct {
+			Index     int       `json:"index"`
+			Embedding []float32 `json:"embedding"`
+		} `json:"data"`
+	}
+	request := map[string]any{"model": c.EmbeddingModel, "input": texts}
+	if err := c.post(ctx, "/embeddings", request, &response); err != nil {
+		return nil, err
+	}
+
+	embeddings := make([][]float32, len(texts))
+	for _, item := range response.Data {
+		if item.Index < 0 || item.Index >= len(texts) {
+			return nil, fmt.Errorf("embedding index %d out of range", item.Index)
+		}
+		embeddings[item.Index] = item.Embedding
+	}
+	return embeddings, nil
+}
+
+func (c *OpenAI) Complete(ctx context.Context, messages []Message) (string, error) {
+	var response struct {
+		Choices []struct {
+			Message Message `json:"message"`
+		} `json:"choices"`
+	}
+	request := map[string]any{"model": c.Model, "messages": messages}
+	if err := c.post(ctx, "/chat/completions", request, &response); err != nil {
+		return "", err
+	}
+	if len(response.Choices) == 0 {
+		return "", fmt.Errorf("completion has no choices")
+	}
+	return response.Choices[0].Message.Content, nil
+}
+
+func (c *OpenAI) post(ctx context.Context, path string, body, out any) error {
+	payload, err := json.Marshal(body)
+	if err != nil {
+		return err
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, c.BaseURL+path, bytes.NewReader(payload))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	if c.APIKey != "" {
+		req.Header.Set("Authorization", "Bearer "+c.APIKey)
+	}
+
+	resp, err := c.HTTP.Do(req)
+	if err != nil {
+		return fmt.Errorf("request %s: %w", path, err)
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("request %s: unexpected status %s", path, resp.Status)
+	}
+	return json.NewDecoder(resp.Body).Decode(out)
+}
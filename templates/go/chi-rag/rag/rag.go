@@ -0,0 +1,103 @@
+// Package rag answers questions from indexed documents using retrieval-augmented generation.
+package rag
+
+import (
+	"context"
+	"fmt"
+	"slices"
+	"strings"
+)
+
+// maxChunkSize is the approximate number of characters embedded at once.
+const maxChunkSize = 1000
+
+// Engine indexes documents and answers questions grounded in them.
+type Engine struct {
+	Provider Provider
+	Store    *Store
+	// TopK is the number of chunks given to the model as context.
+	TopK int
+}
+
+// Answer is a model reply with the documents it was grounded in.
+type Answer struct {
+	Answer  string   `json:"answer"`
+	Sources []string `json:"sources"`
+}
+
+// Index splits a document into chunks, embeds them and stores them, replacing any previous version.
+func (e *Engine) Index(ctx context.Context, documentID, text string) (int, error) {
+	texts := Split(text, maxChunkSize)
+	if len(texts) == 0 {
+		return 0, fmt.Errorf("document %s is empty", documentID)
+	}
+
+	embeddings, err := e.Provider.Embed(ctx, texts)
+	if err != nil {
+		return 0, fmt.Errorf("embed %s: %w", documentID, err)
+	}
+	chunks := make([]Chunk, len(texts))
+	for i, text := range texts {
+		chunks[i] = Chunk{DocumentID: documentID, Text: text, Embedding: embeddings[i]}
+	}
+	if err := e.Store.Replace(documentID, chunks); err != nil {
+		return 0, fmt.Errorf("store %s: %w", documentID, err)
+	}
+	return len(chunks), nil
+}
+
+// Ask retrieves the chunks closest to the question and asks the model to answer from them.
+func (e *Engine) Ask(ctx context.Context, question string) (Answer, error) {
+	embeddings, err := e.Provider.Embed(ctx, []string{question})
+	if err != nil {
+		return Answer{}, fmt.Errorf("embed question: %w", err)
+	}
+	matches := e.Store.Search(embeddings[0], e.TopK)
+
+	var prompt strings.Builder
+	sources := []string{}
+	for _, match := range matches {
+		fmt.Fprintf(&prompt, "[%s]\n%s\n\n", match.DocumentID, match.Text)
+		if !slices.Contains(sources, match.DocumentID) {
+			sources = append(sources, match.DocumentID)
+		}
+	}
+
+	system := "Answer the question using only the context below. " +
+		"If the context does not contain the answer, say you do not know.\n\n" + prompt.String()
+	messages := []Message{
+		{Role: "system", Content: system},
+		{Role: "user", Content: question},
+	}
+	reply, err := e.Provider.Complete(ctx, messages)
+	if err != nil {
+		return Answer{}, fmt.Errorf("complete: %w", err)
+	}
+	return Answer{Answer: reply, Sources: sources}, nil
+}
+
+// Split breaks text into chunks of at most size characters, preferring paragraph boundaries.
+func Split(text string, size int) []string {
+	var chunks []string
+	var current strings.Builder
+	flush := func() {
+		if chunk := strings.TrimSpace(current.String()); chunk != "" {
+			chunks = append(chunks, chunk)
+		}
+		current.Reset()
+	}
+
+	for _, paragraph := range strings.Split(text, "\n\n") {
+		if current.Len()+len(paragraph) > size {
+			flush()
+		}
+		for len(paragraph) > size {
+			chunks = append(chunks, paragraph[:size])
+			paragraph = paragraph[size:]
+		}
+		current.WriteString(paragraph)
+		current.WriteString("\n\n")
+	}
+	flush()
+	return chunks
+}
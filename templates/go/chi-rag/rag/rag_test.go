@@ -0,0 +1,97 @@
+package rag
+
+import (
+	"context"
+	"strings"
+	"testing"
+)
+
+// mockProvider embeds texts by keyword so tests run without an API key.
+type mockProvider struct {
+	lastPrompt string
+}
+
+func (m *mockProvider) Embed(_ context.Context, texts []string) ([][]float32, error) {
+	embeddings := make([][]float32, len(texts))
+	for i, text := range texts {
+		text = strings.ToLower(text)
+		embeddings[i] = []float32{
+			score(strings.Contains(text, "office")),
+			score(strings.Contains(text, "lunch")),
+			1,
+		}
+	}
+	return embeddings, nil
+}
+
+func (m *mockProvider) Complete(_ context.Context, messages []Message) (string, error) {
+	m.lastPrompt = messages[0].Content
+	return "mock answer", nil
+}
+
+func score(ok bool) float32 {
+	if ok {
+		return 10
+	}
+	return 0
+}
+
+func TestAskUsesClosestDocument(t *testing.T) {
+	store, err := OpenStore("")
+	if err != nil {
+		t.Fatal(err)
+	}
+	provider := &mockProvider{}
+	engine := &Engine{Provider: provider, Store: store, TopK: 1}
+
+	ctx := context.Background()
+	if _, err := engine.Index(ctx, "office", "The office is closed on Fridays."); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := engine.Index(ctx, "food", "Lunch is served at noon."); err != nil {
+		t.Fatal(err)
+	}
+
+	answer, err := engine.Ask(ctx, "When is the office closed?")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if answer.Answer != "mock answer" {
+		t.Errorf("answer = %q, want %q", answer.Answer, "mock answer")
+	}
+	if len(answer.Sources) != 1 || answer.Sources[0] != "office" {
+		t.Errorf("sources = %v, want [office]", answer.Sources)
+	}
+	if !strings.Contains(provider.lastPrompt, "closed on Fridays") {
+		t.Errorf("prompt does not contain the retrieved document: %q", provider.lastPrompt)
+	}
+}
+
+func TestIndexReplacesDocument(t *testing.T) {
+	store, _ := OpenStore("")
+	engine := &Engine{Provider: &mockProvider{}, Store: store, TopK: 3}
+
+	ctx := context.Background()
+	for _, version := range []string{"first version", "second version"} {
+		if _, err := engine.Index(ctx, "doc", version); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	if store.Len() != 1 {
+		t.Errorf("store has %d chunks, want 1", store.Len())
+	}
+}
+
+func TestSplit(t *testing.T) {
+	chunks := Split("one\n\ntwo\n\n"+strings.Repeat("x", 25), 10)
+	want := []string{"one\n\ntwo", "xxxxxxxxxx", "xxxxxxxxxx", "xxxxx"}
+	if len(chunks) != len(want) {
+		t.Fatalf("chunks = %q, want %q", chunks, want)
+	}
+	for i := range want {
+		if chunks[i] != want[i] {
+			t.Errorf("chunk %d = %q, want %q", i, chunks[i], want[i])
+		}
+	}
+}
@@ -0,0 +1,121 @@
+package rag
+
+import (
+	"encoding/json"
+	"errors"
+	"io/fs"
+	"math"
+	"os"
+	"path/filepath"
+	"sort"
+	"sync"
+)
+
+// Chunk is a piece of a document together with its embedding.
+type Chunk struct {
+	DocumentID string    `json:"document_id"`
+	Text       string    `json:"text"`
+	Embedding  []float32 `json:"embedding"`
+}
+
+// Match is a chunk found by a similarity search.
+type Match struct {
+	Chunk
+	Score float64 `json:"score"`
+}
+
+// Store is an in-memory vector store persisted to a JSON file.
+type Store struct {
+	mu     sync.RWMutex
+	path   string
+	chunks []Chunk
+}
+
+// OpenStore loads the store saved at path, starting empty when the file does not exist.
+// An empty path keeps the store in memory only.
+func OpenStore(path string) (*Store, error) {
+	store := &Store{path: path}
+	if path == "" {
+		return store, nil
+	}
+
+	data, err := os.ReadFile(path)
+	if errors.Is(err, fs.ErrNotExist) {
+		return store, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	if err := json.Unmarshal(data, &store.chunks); err != nil {
+		return nil, err
+	}
+	return store, nil
+}
+
+// Replace swaps the chunks of a document and saves the store.
+func (s *Store) Replace(documentID string, chunks []Chunk) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	kept := s.chunks[:0]
+	for _, chunk := range s.chunks {
+		if chunk.DocumentID != documentID {
+			kept = append(kept, chunk)
+		}
+	}
+	s.chunks = append(kept, chunks...)
+	return s.save()
+}
+
+// Search returns the k chunks most similar to the embedding, best first.
+func (s *Store) Search(embedding []float32, k int) []Match {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+
+	matches := make([]Match, 0, len(s.chunks))
+	for _, chunk := range s.chunks {
+		matches = append(matches, Match{Chunk: chunk, Score: cosine(embedding, chunk.Embedding)})
+	}
+	sort.SliceStable(matches, func(i, j int) bool { return matches[i].Score > matches[j].Score })
+	if len(matches) > k {
+		matches = matches[:k]
+	}
+	return matches
+}
+
+// Len returns the number of stored chunks.
+func (s *Store) Len() int {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+	return len(s.chunks)
+}
+
+func (s *Store) save() error {
+	if s.path == "" {
+		return nil
+	}
+	if err := os.MkdirAll(filepath.Dir(s.path), 0o755); err != nil {
+		return err
+	}
+	data, err := json.Marshal(s.chunks)
+	if err != nil {
+		return err
+	}
+	return os.WriteFile(s.path, data, 0o644)
+}
+
+func cosine(a, b []float32) float64 {
+	if len(a) != len(b) || len(a) == 0 {
+		return 0
+	}
+	var dot, normA, normB float64
+	for i := range a {
+		dot += float64(a[i]) * float64(b[i])
+		normA += float64(a[i]) * float64(a[i])
+		normB += float64(b[i]) * float64(b[i])
+	}
+	if normA == 0 || normB == 0 {
+		return 0
+	}
+	return dot / (math.Sqrt(normA) * math.Sqrt(normB))
+}
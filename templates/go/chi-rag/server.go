@@ -0,0 +1,81 @@
+package main
+
+import (
+	"encoding/json"
+	"log"
+	"net/http"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/go-chi/chi/v5/middleware"
+
+	"chi-rag/rag"
+)
+
+type indexRequest struct {
+	ID   string `json:"id"`
+	Text string `json:"text"`
+}
+
+type askRequest struct {
+	Question string `json:"question"`
+}
+
+// newRouter exposes the RAG engine over HTTP.
+func newRouter(engine *rag.Engine) http.Handler {
+	r := chi.NewRouter()
+
+	r.Use(middleware.RequestID)
+	r.Use(middleware.RealIP)
+	r.Use(middleware.Logger)
+	r.Use(middleware.Recoverer)
+	r.Use(middleware.Timeout(120 * time.Second))
+
+	r.Get("/health", func(w http.ResponseWriter, r *http.Request) {
+		writeJSON(w, http.StatusOK, map[string]int{"chunks": engine.Store.Len()})
+	})
+
+	r.Post("/documents", func(w http.ResponseWriter, r *http.Request) {
+		var req indexRequest
+		if err := json.NewDecoder(r.Body).Decode(&req); err != nil || req.ID == "" || req.Text == "" {
+			writeError(w, http.StatusBadRequest, "expected a JSON body with id and text")
+			return
+		}
+		chunks, err := engine.Index(r.Context(), req.ID, req.Text)
+		if err != nil {
+			log.Printf("index %s: %v", req.ID, err)
+			writeError(w, http.StatusBadGateway, err.Error())
+			return
+		}
+		writeJSON(w, http.StatusCreated, map[string]any{"id": req.ID, "chunks": chunks})
+	})
+
+	r.Post("/ask", func(w http.ResponseWriter, r *http.Request) {
+		var req askRequest
+		if err := json.NewDecoder(r.Body).Decode(&req); err != nil || req.Question == "" {
+			writeError(w, http.StatusBadRequest, "expected a JSON body with a question")
+			return
+		}
+		answer, err := engine.Ask(r.Context(), req.Question)
+		if err != nil {
+			log.Printf("ask: %v", err)
+			writeError(w, http.StatusBadGateway, err.Error())
+			return
+		}
+		writeJSON(w, http.StatusOK, answer)
+	})
+
+	return r
+}
+
+func writeJSON(w http.ResponseWriter, status int, body any) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	if err := json.NewEncoder(w).Encode(body); err != nil {
+		log.Printf("write response: %v", err)
+	}
+}
+
+func writeError(w http.ResponseWriter, status int, message string) {
+	writeJSON(w, status, map[string]string{"error": message})
+}
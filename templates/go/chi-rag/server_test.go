@@ -0,0 +1,68 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"chi-rag/rag"
+)
+
+// mockProvider returns constant embeddings and a canned answer so tests run without an API key.
+type mockProvider struct{}
+
+func (mockProvider) Embed(_ context.Context, texts []string) ([][]float32, error) {
+	embeddings := make([][]float32, len(texts))
+	for i := range texts {
+		embeddings[i] = []float32{1, 0}
+	}
+	return embeddings, nil
+}
+
+func (mockProvider) Complete(_ context.Context, _ []rag.Message) (string, error) {
+	return "Fridays", nil
+}
+
+func TestIndexAndAsk(t *testing.T) {
+	store, err := rag.OpenStore("")
+	if err != nil {
+		t.Fatal(err)
+	}
+	router := newRouter(&rag.Engine{Provider: mockProvider{}, Store: store, TopK: 4})
+
+	index := httptest.NewRecorder()
+	router.ServeHTTP(index, httptest.NewRequest(http.MethodPost, "/documents",
+		strings.NewReader(`{"id": "handbook", "text": "The office is closed on Fridays."}`)))
+	if index.Code != http.StatusCreated {
+		t.Fatalf("index status = %d, body = %s", index.Code, index.Body)
+	}
+
+	ask := httptest.NewRecorder()
+	router.ServeHTTP(ask, httptest.NewRequest(http.MethodPost, "/ask",
+		strings.NewReader(`{"question": "When is the office closed?"}`)))
+	if ask.Code != http.StatusOK {
+		t.Fatalf("ask status = %d, body = %s", ask.Code, ask.Body)
+	}
+
+	var answer rag.Answer
+	if err := json.NewDecoder(ask.Body).Decode(&answer); err != nil {
+		t.Fatal(err)
+	}
+	if answer.Answer != "Fridays" || len(answer.Sources) != 1 || answer.Sources[0] != "handbook" {
+		t.Errorf("answer = %+v", answer)
+	}
+}
+
+func TestAskRequiresQuestion(t *testing.T) {
+	store, _ := rag.OpenStore("")
+	router := newRouter(&rag.Engine{Provider: mockProvider{}, Store: store, TopK: 4})
+
+	rec := httptest.NewRecorder()
+	router.ServeHTTP(rec, httptest.NewRequest(http.MethodPost, "/ask", strings.NewReader(`{}`)))
+	if rec.Code != http.StatusBadRequest {
+		t.Errorf("status = %d, want %d", rec.Code, http.StatusBadRequest)
+	}
+}
@@ -0,0 +1,30 @@
+package cmd
+
+import (
+	"bufio"
+	"fmt"
+	"strings"
+
+	"github.com/spf13/cobra"
+)
+
+func newCountCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:   "count",
+		Short: "Count the lines and words read from stdin",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, _ []string) error {
+			lines, words := 0, 0
+			scanner := bufio.NewScanner(cmd.InOrStdin())
+			for scanner.Scan() {
+				lines++
+				words += len(strings.Fields(scanner.Text()))
+			}
+			if err := scanner.Err(); err != nil {
+				return err
+			}
+			_, err := fmt.Fprintf(cmd.OutOrStdout(), "%d lines, %d words\n", lines, words)
+			return err
+		},
+	}
+}
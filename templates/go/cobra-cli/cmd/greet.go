@@ -0,0 +1,34 @@
+package cmd
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/spf13/cobra"
+)
+
+func newGreetCmd() *cobra.Command {
+	var shout bool
+
+	cmd := &cobra.Command{
+		Use:   "greet [name]",
+		Short: "Print a greeting",
+		Args:  cobra.MaximumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			name := "World"
+			if len(args) == 1 {
+				name = args[0]
+			}
+
+			greeting := fmt.Sprintf("Hello, %s!", name)
+			if shout {
+				greeting = strings.ToUpper(greeting)
+			}
+			_, err := fmt.Fprintln(cmd.OutOrStdout(), greeting)
+			return err
+		},
+	}
+
+	cmd.Flags().BoolVarP(&shout, "shout", "s", false, "print the greeting in uppercase")
+	return cmd
+}
@@ -0,0 +1,26 @@
+// Package cmd defines the commands of the CLI.
+package cmd
+
+import (
+	"os"
+	"path/filepath"
+
+	"github.com/spf13/cobra"
+)
+
+// version is set at build time with -ldflags "-X <module>/cmd.version=1.2.3".
+var version = "dev"
+
+// NewRootCmd builds the command tree, a fresh one per call so tests do not share flags.
+func NewRootCmd() *cobra.Command {
+	root := &cobra.Command{
+		Use:          filepath.Base(os.Args[0]),
+		Short:        "A command line tool built with Cobra",
+		Version:      version,
+		SilenceUsage: true,
+	}
+
+	root.AddCommand(newGreetCmd())
+	root.AddCommand(newCountCmd())
+	return root
+}
@@ -0,0 +1,60 @@
+package cmd
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+)
+
+// run executes the CLI with args and stdin, returning what it printed.
+func run(t *testing.T, stdin string, args ...string) (string, error) {
+	t.Helper()
+	var out bytes.Buffer
+	root := NewRootCmd()
+	root.SetArgs(args)
+	root.SetIn(strings.NewReader(stdin))
+	root.SetOut(&out)
+	root.SetErr(&out)
+	err := root.Execute()
+	return out.String(), err
+}
+
+func TestGreet(t *testing.T) {
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{"default name", []string{"greet"}, "Hello, World!\n"},
+		{"given name", []string{"greet", "Gopher"}, "Hello, Gopher!\n"},
+		{"shout", []string{"greet", "--shout", "Gopher"}, "HELLO, GOPHER!\n"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := run(t, "", tt.args...)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if got != tt.want {
+				t.Errorf("output = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestGreetRejectsExtraArguments(t *testing.T) {
+	if _, err := run(t, "", "greet", "a", "b"); err == nil {
+		t.Fatal("expected an error")
+	}
+}
+
+func TestCount(t *testing.T) {
+	got, err := run(t, "one two\nthree\n", "count")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if want := "2 lines, 3 words\n"; got != want {
+		t.Errorf("output = %q, want %q", got, want)
+	}
+}
@@ -0,0 +1,5 @@
+module cobra-cli
+
+go 1.24.3
+
+require github.com/spf13/cobra v1.9.1
@@ -0,0 +1,8 @@
+module connect-grpc
+
+go 1.24.3
+
+require (
+	connectrpc.com/connect v1.18.1
+	google.golang.org/protobuf v1.36.6
+)
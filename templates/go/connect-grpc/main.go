@@ -0,0 +1,35 @@
+package main
+
+import (
+	"log"
+	"net/http"
+	"os"
+
+	"connect-grpc/gen/greet/v1/greetv1connect"
+)
+
+func main() {
+	mux := http.NewServeMux()
+	mux.Handle(greetv1connect.NewGreetServiceHandler(&GreetServer{}))
+
+	// gRPC needs HTTP/2, served without TLS here so plaintext gRPC clients can connect.
+	protocols := new(http.Protocols)
+	protocols.SetHTTP1(true)
+	protocols.SetUnencryptedHTTP2(true)
+
+	server := &http.Server{
+		Addr:      ":" + env("PORT", "8080"),
+		Handler:   mux,
+		Protocols: protocols,
+	}
+	log.Printf("Server running at %s", server.Addr)
+	log.Fatal(server.ListenAndServe())
+}
+
+// env returns the environment variable, or fallback when it is unset or empty.
+func env(key, fallback string) string {
+	if value := os.Getenv(key); value != "" {
+		return value
+	}
+	return fallback
+}
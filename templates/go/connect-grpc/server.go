@@ -0,0 +1,24 @@
+package main
+
+import (
+	"context"
+	"errors"
+
+	"connectrpc.com/connect"
+
+	greetv1 "connect-grpc/gen/greet/v1"
+)
+
+// GreetServer implements greetv1connect.GreetServiceHandler.
+type GreetServer struct{}
+
+func (s *GreetServer) Greet(
+	_ context.Context,
+	req *connect.Request[greetv1.GreetRequest],
+) (*connect.Response[greetv1.GreetResponse], error) {
+	name := req.Msg.GetName()
+	if name == "" {
+		return nil, connect.NewError(connect.CodeInvalidArgument, errors.New("name is required"))
+	}
+	return connect.NewResponse(&greetv1.GreetResponse{Greeting: "Hello, " + name + "!"}), nil
+}
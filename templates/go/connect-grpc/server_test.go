@@ -0,0 +1,62 @@
+package main
+
+import (
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"connectrpc.com/connect"
+
+	greetv1 "connect-grpc/gen/greet/v1"
+	"connect-grpc/gen/greet/v1/greetv1connect"
+)
+
+func newTestServer(t *testing.T) *httptest.Server {
+	t.Helper()
+	mux := http.NewServeMux()
+	mux.Handle(greetv1connect.NewGreetServiceHandler(&GreetServer{}))
+
+	server := httptest.NewUnstartedServer(mux)
+	server.EnableHTTP2 = true
+	server.StartTLS()
+	t.Cleanup(server.Close)
+	return server
+}
+
+func TestGreet(t *testing.T) {
+	server := newTestServer(t)
+
+	newClient := func(opts ...connect.ClientOption) greetv1connect.GreetServiceClient {
+		return greetv1connect.NewGreetServiceClient(server.Client(), server.URL, opts...)
+	}
+
+	// The same handler serves the Connect, gRPC and gRPC-Web protocols.
+	clients := map[string]greetv1connect.GreetServiceClient{
+		"connect":  newClient(),
+		"grpc":     newClient(connect.WithGRPC()),
+		"grpc-web": newClient(connect.WithGRPCWeb()),
+	}
+
+	for protocol, client := range clients {
+		t.Run(protocol, func(t *testing.T) {
+			res, err := client.Greet(context.Background(), connect.NewRequest(&greetv1.GreetRequest{Name: "Gopher"}))
+			if err != nil {
+				t.Fatal(err)
+			}
+			if got, want := res.Msg.GetGreeting(), "Hello, Gopher!"; got != want {
+				t.Errorf("greeting = %q, want %q", got, want)
+			}
+		})
+	}
+}
+
+func TestGreetRequiresName(t *testing.T) {
+	server := newTestServer(t)
+	client := greetv1connect.NewGreetServiceClient(server.Client(), server.URL, connect.WithGRPC())
+
+	_, err := client.Greet(context.Background(), connect.NewRequest(&greetv1.GreetRequest{}))
+	if connect.CodeOf(err) != connect.CodeInvalidArgument {
+		t.Errorf("code = %v, want %v", connect.CodeOf(err), connect.CodeInvalidArgument)
+	}
+}
@@ -0,0 +1,4 @@
+// Package library provides a generic set that is safe for concurrent use.
+//
+// Rename the package clause to match the last element of your module path.
+package library
@@ -0,0 +1,34 @@
+package library_test
+
+import (
+	"fmt"
+	"slices"
+
+	"library"
+)
+
+func Example() {
+	languages := library.New("go", "rust")
+	languages.Add("typescript", "go")
+
+	fmt.Println(languages.Len())
+	fmt.Println(languages.Contains("rust"))
+	// Output:
+	// 3
+	// true
+}
+
+func ExampleSet_All() {
+	s := library.New(3, 1, 2)
+
+	fmt.Println(slices.Sorted(s.All()))
+	// Output: [1 2 3]
+}
+
+func ExampleSet_Intersection() {
+	backend := library.New("go", "rust", "sql")
+	systems := library.New("c", "rust", "go")
+
+	fmt.Println(slices.Sorted(backend.Intersection(systems).All()))
+	// Output: [go rust]
+}
@@ -0,0 +1,99 @@
+package library
+
+import (
+	"iter"
+	"maps"
+	"sync"
+)
+
+// Set is a collection of unique values, safe for concurrent use.
+//
+// The zero value is an empty set ready to use.
+type Set[T comparable] struct {
+	mu     sync.RWMutex
+	values map[T]struct{}
+}
+
+// New returns a set holding the given values.
+func New[T comparable](values ...T) *Set[T] {
+	s := &Set[T]{}
+	s.Add(values...)
+	return s
+}
+
+// Add inserts values into the set and reports how many were not already present.
+func (s *Set[T]) Add(values ...T) int {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	if s.values == nil {
+		s.values = make(map[T]struct{}, len(values))
+	}
+	added := 0
+	for _, value := range values {
+		if _, ok := s.values[value]; !ok {
+			s.values[value] = struct{}{}
+			added++
+		}
+	}
+	return added
+}
+
+// Remove deletes value from the set and reports whether it was present.
+func (s *Set[T]) Remove(value T) bool {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	_, ok := s.values[value]
+	delete(s.values, value)
+	return ok
+}
+
+// Contains reports whether value is in the set.
+func (s *Set[T]) Contains(value T) bool {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+
+	_, ok := s.values[value]
+	return ok
+}
+
+// Len returns the number of values in the set.
+func (s *Set[T]) Len() int {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+
+	return len(s.values)
+}
+
+// All returns an iterator over a snapshot of the values, in no particular order.
+func (s *Set[T]) All() iter.Seq[T] {
+	s.mu.RLock()
+	snapshot := maps.Clone(s.values)
+	s.mu.RUnlock()
+
+	return maps.Keys(snapshot)
+}
+
+// Union returns a new set holding the values present in s or other.
+func (s *Set[T]) Union(other *Set[T]) *Set[T] {
+	union := New[T]()
+	for value := range s.All() {
+		union.Add(value)
+	}
+	for value := range other.All() {
+		union.Add(value)
+	}
+	return union
+}
+
+// Intersection returns a new set holding the values present in both s and other.
+func (s *Set[T]) Intersection(other *Set[T]) *Set[T] {
+	intersection := New[T]()
+	for value := range s.All() {
+		if other.Contains(value) {
+			intersection.Add(value)
+		}
+	}
+	return intersection
+}
@@ -0,0 +1,82 @@
+package library
+
+import (
+	"sync"
+	"testing"
+)
+
+func TestAdd(t *testing.T) {
+	var s Set[string]
+
+	if added := s.Add("a", "b", "a"); added != 2 {
+		t.Errorf("Add() = %d, want 2", added)
+	}
+	if added := s.Add("b"); added != 0 {
+		t.Errorf("Add() = %d, want 0", added)
+	}
+	if s.Len() != 2 {
+		t.Errorf("Len() = %d, want 2", s.Len())
+	}
+}
+
+func TestRemove(t *testing.T) {
+	s := New(1, 2)
+
+	if !s.Remove(1) {
+		t.Error("Remove(1) = false, want true")
+	}
+	if s.Remove(1) {
+		t.Error("Remove(1) = true, want false once removed")
+	}
+	if s.Contains(1) || !s.Contains(2) {
+		t.Error("expected the set to only contain 2")
+	}
+}
+
+func TestZeroValueIsEmpty(t *testing.T) {
+	var s Set[int]
+
+	if s.Len() != 0 || s.Contains(0) || s.Remove(0) {
+		t.Error("expected the zero value to behave as an empty set")
+	}
+	for range s.All() {
+		t.Error("expected no values")
+	}
+}
+
+func TestSetOperations(t *testing.T) {
+	a, b := New(1, 2, 3), New(2, 3, 4)
+
+	if got := a.Union(b).Len(); got != 4 {
+		t.Errorf("Union().Len() = %d, want 4", got)
+	}
+	intersection := a.Intersection(b)
+	if intersection.Len() != 2 || !intersection.Contains(2) || !intersection.Contains(3) {
+		t.Errorf("Intersection() has %d values, want 2 and 3", intersection.Len())
+	}
+}
+
+// TestConcurrentUse is meant to run with the race detector, `go test -race ./...`.
+func TestConcurrentUse(t *testing.T) {
+	var s Set[int]
+	var wg sync.WaitGroup
+
+	for i := range 8 {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			for j := range 100 {
+				s.Add(i*100 + j)
+				s.Contains(j)
+				for range s.All() {
+					break
+				}
+			}
+		}()
+	}
+	wg.Wait()
+
+	if s.Len() != 800 {
+		t.Errorf("Len() = %d, want 800", s.Len())
+	}
+}
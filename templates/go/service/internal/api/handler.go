@@ -0,0 +1,83 @@
+// Package api exposes the todo store over HTTP.
+package api
+
+import (
+	"encoding/json"
+	"log/slog"
+	"net/http"
+	"strconv"
+
+	"service/internal/todo"
+)
+
+type handler struct {
+	store  *todo.Store
+	logger *slog.Logger
+}
+
+// NewHandler returns the HTTP routes of the service.
+func NewHandler(store *todo.Store, logger *slog.Logger) http.Handler {
+	h := &handler{store: store, logger: logger}
+
+	mux := http.NewServeMux()
+	mux.HandleFunc("GET /health", func(w http.ResponseWriter, _ *http.Request) {
+		writeJSON(w, http.StatusOK, map[string]string{"status": "ok"})
+	})
+	mux.HandleFunc("GET /todos", h.listTodos)
+	mux.HandleFunc("POST /todos", h.addTodo)
+	mux.HandleFunc("POST /todos/{id}/done", h.completeTodo)
+	return h.logRequests(mux)
+}
+
+func (h *handler) listTodos(w http.ResponseWriter, _ *http.Request) {
+	writeJSON(w, http.StatusOK, h.store.List())
+}
+
+func (h *handler) addTodo(w http.ResponseWriter, r *http.Request) {
+	var body struct {
+		Title string `json:"title"`
+	}
+	if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
+		writeError(w, http.StatusBadRequest, "expected a JSON body with a title")
+		return
+	}
+
+	created, err := h.store.Add(body.Title)
+	if err != nil {
+		writeError(w, http.StatusUnprocessableEntity, err.Error())
+		return
+	}
+	writeJSON(w, http.StatusCreated, created)
+}
+
+func (h *handler) completeTodo(w http.ResponseWriter, r *http.Request) {
+	id, err := strconv.Atoi(r.PathValue("id"))
+	if err != nil {
+		writeError(w, http.StatusBadRequest, "invalid todo id")
+		return
+	}
+
+	done, err := h.store.Complete(id)
+	if err != nil {
+		writeError(w, http.StatusNotFound, err.Error())
+		return
+	}
+	writeJSON(w, http.StatusOK, done)
+}
+
+func (h *handler) logRequests(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		next.ServeHTTP(w, r)
+		h.logger.Info("request", "method", r.Method, "path", r.URL.Path)
+	})
+}
+
+func writeJSON(w http.ResponseWriter, status int, value any) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	_ = json.NewEncoder(w).Encode(value)
+}
+
+func writeError(w http.ResponseWriter, status int, message string) {
+	writeJSON(w, status, map[string]string{"error": message})
+}
@@ -0,0 +1,77 @@
+package api
+
+import (
+	"encoding/json"
+	"io"
+	"log/slog"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"service/internal/todo"
+)
+
+func newTestServer(t *testing.T) *httptest.Server {
+	t.Helper()
+	logger := slog.New(slog.NewTextHandler(io.Discard, nil))
+	server := httptest.NewServer(NewHandler(todo.NewStore(), logger))
+	t.Cleanup(server.Close)
+	return server
+}
+
+func TestAddAndListTodos(t *testing.T) {
+	server := newTestServer(t)
+
+	res, err := http.Post(server.URL+"/todos", "application/json", strings.NewReader(`{"title": "Write code"}`))
+	if err != nil {
+		t.Fatal(err)
+	}
+	res.Body.Close()
+	if res.StatusCode != http.StatusCreated {
+		t.Fatalf("POST /todos status = %d, want %d", res.StatusCode, http.StatusCreated)
+	}
+
+	res, err = http.Get(server.URL + "/todos")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer res.Body.Close()
+
+	var todos []todo.Todo
+	if err := json.NewDecoder(res.Body).Decode(&todos); err != nil {
+		t.Fatal(err)
+	}
+	if len(todos) != 1 || todos[0].Title != "Write code" {
+		t.Errorf("GET /todos = %+v", todos)
+	}
+}
+
+func TestErrorStatuses(t *testing.T) {
+	server := newTestServer(t)
+
+	tests := []struct {
+		name string
+		path string
+		body string
+		want int
+	}{
+		{"malformed body", "/todos", `{`, http.StatusBadRequest},
+		{"empty title", "/todos", `{"title": ""}`, http.StatusUnprocessableEntity},
+		{"unknown todo", "/todos/42/done", ``, http.StatusNotFound},
+		{"invalid id", "/todos/first/done", ``, http.StatusBadRequest},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			res, err := http.Post(server.URL+tt.path, "application/json", strings.NewReader(tt.body))
+			if err != nil {
+				t.Fatal(err)
+			}
+			res.Body.Close()
+			if res.StatusCode != tt.want {
+				t.Errorf("status = %d, want %d", res.StatusCode, tt.want)
+			}
+		})
+	}
+}
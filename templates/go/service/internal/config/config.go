@@ -0,0 +1,47 @@
+// Package config reads the service settings from the environment.
+package config
+
+import (
+	"fmt"
+	"strconv"
+	"time"
+)
+
+// Config holds the service settings.
+type Config struct {
+	Port            int
+	ReadTimeout     time.Duration
+	ShutdownTimeout time.Duration
+}
+
+// Load builds a Config from environment variables looked up with getenv, usually os.Getenv.
+func Load(getenv func(string) string) (Config, error) {
+	cfg := Config{
+		Port:            8080,
+		ReadTimeout:     5 * time.Second,
+		ShutdownTimeout: 10 * time.Second,
+	}
+
+	if value := getenv("PORT"); value != "" {
+		port, err := strconv.Atoi(value)
+		if err != nil || port <= 0 || port > 65535 {
+			return Config{}, fmt.Errorf("invalid PORT %q", value)
+		}
+		cfg.Port = port
+	}
+
+	if value := getenv("SHUTDOWN_TIMEOUT"); value != "" {
+		timeout, err := time.ParseDuration(value)
+		if err != nil {
+			return Config{}, fmt.Errorf("invalid SHUTDOWN_TIMEOUT %q: %w", value, err)
+		}
+		cfg.ShutdownTimeout = timeout
+	}
+
+	return cfg, nil
+}
+
+// Addr returns the address the server listens on.
+func (c Config) Addr() string {
+	return fmt.Sprintf(":%d", c.Port)
+}
@@ -0,0 +1,42 @@
+package config
+
+import (
+	"testing"
+	"time"
+)
+
+func env(values map[string]string) func(string) string {
+	return func(key string) string { return values[key] }
+}
+
+func TestLoadDefaults(t *testing.T) {
+	cfg, err := Load(env(nil))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if cfg.Addr() != ":8080" || cfg.ShutdownTimeout != 10*time.Second {
+		t.Errorf("unexpected defaults %+v", cfg)
+	}
+}
+
+func TestLoadFromEnv(t *testing.T) {
+	cfg, err := Load(env(map[string]string{"PORT": "3000", "SHUTDOWN_TIMEOUT": "2s"}))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if cfg.Port != 3000 || cfg.ShutdownTimeout != 2*time.Second {
+		t.Errorf("unexpected config %+v", cfg)
+	}
+}
+
+func TestLoadRejectsInvalidValues(t *testing.T) {
+	for _, values := range []map[string]string{
+		{"PORT": "http"},
+		{"PORT": "70000"},
+		{"SHUTDOWN_TIMEOUT": "soon"},
+	} {
+		if _, err := Load(env(values)); err == nil {
+			t.Errorf("Load(%v) succeeded, want an error", values)
+		}
+	}
+}
@@ -0,0 +1,72 @@
+// Package todo holds the domain model and its storage.
+package todo
+
+import (
+	"errors"
+	"strings"
+	"sync"
+)
+
+var (
+	// ErrNotFound is returned when no todo has the requested id.
+	ErrNotFound = errors.New("todo not found")
+	// ErrEmptyTitle is returned when creating a todo without a title.
+	ErrEmptyTitle = errors.New("todo needs a title")
+)
+
+// Todo is a single item of the list.
+type Todo struct {
+	ID    int    `json:"id"`
+	Title string `json:"title"`
+	Done  bool   `json:"done"`
+}
+
+// Store keeps todos in memory, safe for concurrent use.
+type Store struct {
+	mu     sync.Mutex
+	todos  []Todo
+	nextID int
+}
+
+// NewStore returns an empty store.
+func NewStore() *Store {
+	return &Store{nextID: 1}
+}
+
+// Add creates a todo with the given title.
+func (s *Store) Add(title string) (Todo, error) {
+	title = strings.TrimSpace(title)
+	if title == "" {
+		return Todo{}, ErrEmptyTitle
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	todo := Todo{ID: s.nextID, Title: title}
+	s.nextID++
+	s.todos = append(s.todos, todo)
+	return todo, nil
+}
+
+// Complete marks the todo with the given id as done.
+func (s *Store) Complete(id int) (Todo, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	for i := range s.todos {
+		if s.todos[i].ID == id {
+			s.todos[i].Done = true
+			return s.todos[i], nil
+		}
+	}
+	return Todo{}, ErrNotFound
+}
+
+// List returns a copy of every todo, in creation order.
+func (s *Store) List() []Todo {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	return append([]Todo(nil), s.todos...)
+}
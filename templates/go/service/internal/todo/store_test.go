@@ -0,0 +1,42 @@
+package todo
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestAddAndComplete(t *testing.T) {
+	store := NewStore()
+
+	first, err := store.Add("Write code")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if _, err := store.Add("Ship it"); err != nil {
+		t.Fatal(err)
+	}
+
+	done, err := store.Complete(first.ID)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !done.Done {
+		t.Error("expected the todo to be done")
+	}
+
+	todos := store.List()
+	if len(todos) != 2 || !todos[0].Done || todos[1].Done {
+		t.Errorf("unexpected todos %+v", todos)
+	}
+}
+
+func TestErrors(t *testing.T) {
+	store := NewStore()
+
+	if _, err := store.Add("  "); !errors.Is(err, ErrEmptyTitle) {
+		t.Errorf("Add() error = %v, want %v", err, ErrEmptyTitle)
+	}
+	if _, err := store.Complete(42); !errors.Is(err, ErrNotFound) {
+		t.Errorf("Complete() error = %v, want %v", err, ErrNotFound)
+	}
+}
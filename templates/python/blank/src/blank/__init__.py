@@ -0,0 +1,6 @@
+def greet(name: str) -> str:
+    return f"Hello {name}!"
+
+
+def main() -> None:
+    print(greet("World"))
@@ -0,0 +1,3 @@
+from blank import main
+
+main()
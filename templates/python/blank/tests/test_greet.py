@@ -0,0 +1,5 @@
+from blank import greet
+
+
+def test_greet() -> None:
+    assert greet("World") == "Hello World!"
@@ -0,0 +1,23 @@
+mod config;
+mod provider;
+mod routes;
+
+use config::Config;
+use provider::OpenAiClient;
+
+#[tokio::main]
+async fn main() {
+    // load variables from `.env` when present
+    dotenvy::dotenv().ok();
+    tracing_subscriber::fmt::init();
+
+    let config = Config::from_env();
+    let port = config.port;
+    let app = routes::app(OpenAiClient::new(config));
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port))
+        .await
+        .unwrap();
+    tracing::info!("Server running at {}", listener.local_addr().unwrap());
+    axum::serve(listener, app).await.unwrap();
+}
@@ -0,0 +1,106 @@
+use std::future::Future;
+
+use eventsource_stream::Eventsource;
+use futures::{StreamExt, stream::BoxStream};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::config::Config;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProviderError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+
+    #[error("invalid stream: {0}")]
+    Stream(String),
+}
+
+/// Tokens of a completion, in the order they are generated.
+pub type TokenStream = BoxStream<'static, Result<String, ProviderError>>;
+
+/// A chat model, implemented by the OpenAI client and by mocks in tests.
+pub trait ChatProvider: Send + Sync + 'static {
+    fn stream_chat(
+        &self,
+        messages: Vec<ChatMessage>,
+    ) -> impl Future<Output = Result<TokenStream, ProviderError>> + Send;
+}
+
+/// Client for any server implementing the OpenAI chat completions API.
+#[derive(Clone)]
+pub struct OpenAiClient {
+    http: reqwest::Client,
+    config: Config,
+}
+
+impl OpenAiClient {
+    pub fn new(config: Config) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            config,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ChunkResponse {
+    choices: Vec<ChunkChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChunkChoice {
+    delta: Delta,
+}
+
+#[derive(Deserialize)]
+struct Delta {
+    content: Option<String>,
+}
+
+impl ChatProvider for OpenAiClient {
+    async fn stream_chat(&self, messages: Vec<ChatMessage>) -> Result<TokenStream, ProviderError> {
+        let url = format!(
+            "{}/chat/completions",
+            self.config.base_url.trim_end_matches('/')
+        );
+        let mut request = self.http.post(url).json(&json!({
+            "model": self.config.model,
+            "messages": messages,
+            "stream": true,
+        }));
+        if let Some(api_key) = &self.config.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        let response = request.send().await?.error_for_status()?;
+
+        let tokens = response
+            .bytes_stream()
+            .eventsource()
+            .take_while(|event| {
+                let done = matches!(event, Ok(event) if event.data == "[DONE]");
+                futures::future::ready(!done)
+            })
+            .filter_map(|event| async move {
+                match event {
+                    Ok(event) => match serde_json::from_str::<ChunkResponse>(&event.data) {
+                        Ok(chunk) => chunk
+                            .choices
+                            .into_iter()
+                            .next()
+                            .and_then(|choice| choice.delta.content)
+                            .map(Ok),
+                        Err(err) => Some(Err(ProviderError::Stream(err.to_string()))),
+                    },
+                    Err(err) => Some(Err(ProviderError::Stream(err.to_string()))),
+                }
+            });
+        Ok(tokens.boxed())
+    }
+}
@@ -0,0 +1,120 @@
+use std::{convert::Infallible, sync::Arc};
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, Sse},
+    },
+    routing::{get, post},
+};
+use futures::StreamExt;
+use serde::Deserialize;
+
+use crate::provider::{ChatMessage, ChatProvider};
+
+#[derive(Deserialize)]
+pub struct ChatRequest {
+    messages: Vec<ChatMessage>,
+}
+
+/// Builds the application routes around a chat provider.
+pub fn app<P: ChatProvider>(provider: P) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/chat", post(chat::<P>))
+        .with_state(Arc::new(provider))
+}
+
+async fn health() -> &'static str {
+    "ok"
+}
+
+/// Streams the completion as server-sent events, ending with a `done` event.
+async fn chat<P: ChatProvider>(
+    State(provider): State<Arc<P>>,
+    Json(request): Json<ChatRequest>,
+) -> Response {
+    let tokens = match provider.stream_chat(request.messages).await {
+        Ok(tokens) => tokens,
+        Err(err) => {
+            tracing::error!("chat request failed: {err}");
+            return (StatusCode::BAD_GATEWAY, err.to_string()).into_response();
+        }
+    };
+
+    let events = tokens
+        .map(|token| {
+            Ok::<_, Infallible>(match token {
+                Ok(token) => Event::default().data(token),
+                Err(err) => Event::default().event("error").data(err.to_string()),
+            })
+        })
+        .chain(futures::stream::once(async {
+            Ok(Event::default().event("done").data(""))
+        }));
+    Sse::new(events).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::provider::{ProviderError, TokenStream};
+
+    /// Replies with fixed tokens so tests run without an API key.
+    struct MockProvider {
+        tokens: Vec<&'static str>,
+    }
+
+    impl ChatProvider for MockProvider {
+        async fn stream_chat(
+            &self,
+            messages: Vec<ChatMessage>,
+        ) -> Result<TokenStream, ProviderError> {
+            assert_eq!(messages[0].content, "Hi");
+            let tokens: Vec<Result<String, ProviderError>> = self
+                .tokens
+                .iter()
+                .map(|token| Ok(token.to_string()))
+                .collect();
+            Ok(futures::stream::iter(tokens).boxed())
+        }
+    }
+
+    #[tokio::test]
+    async fn streams_tokens_as_events() {
+        let app = app(MockProvider {
+            tokens: vec!["Hello", " there"],
+        });
+        let request = Request::post("/chat")
+            .header("Content-Type", "application/json")
+            .body(Body::from(
+                r#"{"messages":[{"role":"user","content":"Hi"}]}"#,
+            ))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("data: Hello\n"));
+        assert!(body.contains("data:  there\n"));
+        assert!(body.contains("event: done\n"));
+    }
+
+    #[tokio::test]
+    async fn reports_health() {
+        let app = app(MockProvider { tokens: vec![] });
+        let request = Request::get("/health").body(Body::empty()).unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
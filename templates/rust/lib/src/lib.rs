@@ -0,0 +1,112 @@
+//! Turns arbitrary text into URL-friendly slugs.
+//!
+//! ```
+//! assert_eq!(library::slugify("Hello, World!"), "hello-world");
+//! ```
+//!
+//! Use a [`Slugger`] to change how slugs are built:
+//!
+//! ```
+//! use library::Slugger;
+//!
+//! let slugger = Slugger::new().separator('_').max_len(11);
+//! assert_eq!(slugger.slugify("Release notes for 1.0"), "release_not");
+//! ```
+#![deny(missing_docs)]
+
+/// Builds slugs out of text, keeping ASCII letters and digits only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slugger {
+    separator: char,
+    max_len: Option<usize>,
+}
+
+impl Slugger {
+    /// Creates a slugger separating words with `-`, without length limit.
+    pub fn new() -> Self {
+        Self {
+            separator: '-',
+            max_len: None,
+        }
+    }
+
+    /// Sets the character placed between words.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Truncates slugs to at most `max_len` characters, without a trailing separator.
+    ///
+    /// ```
+    /// let slugger = library::Slugger::new().max_len(6);
+    /// assert_eq!(slugger.slugify("Hello World"), "hello");
+    /// ```
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Lowercases `text` and joins its alphanumeric words with the separator.
+    pub fn slugify(&self, text: &str) -> String {
+        let mut slug = text
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join(&self.separator.to_string());
+
+        if let Some(max_len) = self.max_len {
+            if let Some((index, _)) = slug.char_indices().nth(max_len) {
+                slug.truncate(index);
+            }
+            while slug.ends_with(self.separator) {
+                slug.pop();
+            }
+        }
+        slug
+    }
+}
+
+impl Default for Slugger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Slugifies `text` with the default [`Slugger`].
+///
+/// ```
+/// assert_eq!(library::slugify("  Rust & WebAssembly  "), "rust-webassembly");
+/// ```
+pub fn slugify(text: &str) -> String {
+    Slugger::new().slugify(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_punctuation_and_whitespace() {
+        assert_eq!(slugify("--Hello,   World!--"), "hello-world");
+    }
+
+    #[test]
+    fn returns_an_empty_slug_without_words() {
+        assert_eq!(slugify("!?"), "");
+    }
+
+    #[test]
+    fn uses_the_configured_separator() {
+        let slugger = Slugger::new().separator('_');
+        assert_eq!(slugger.slugify("Hello World"), "hello_world");
+    }
+
+    #[test]
+    fn truncates_without_trailing_separator() {
+        let slugger = Slugger::new().max_len(6);
+        assert_eq!(slugger.slugify("Hello World"), "hello");
+        assert_eq!(slugger.slugify("Hi"), "hi");
+    }
+}
@@ -0,0 +1,101 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields, ext::IdentExt};
+
+/// Expands `#[derive(FieldNames)]`, kept on `proc_macro2` types so it can be unit tested.
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FieldNames can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "FieldNames requires named fields",
+        ));
+    };
+
+    let mut names = Vec::new();
+    for field in &fields.named {
+        if !is_skipped(field)? {
+            let ident = field.ident.as_ref().expect("named fields have an ident");
+            names.push(ident.unraw().to_string());
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            /// Names of the fields of this struct, in declaration order.
+            pub const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+        }
+    })
+}
+
+fn is_skipped(field: &Field) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("field_names") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported field_names attribute, expected `skip`"))
+            }
+        })?;
+    }
+    Ok(skip)
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn lists_named_fields() {
+        let input: DeriveInput = parse_quote! {
+            struct Point { x: i32, r#type: String, #[field_names(skip)] cache: u8 }
+        };
+
+        let output = expand(input).unwrap().to_string();
+
+        assert!(output.contains(r#"& ["x" , "type"]"#), "{output}");
+    }
+
+    #[test]
+    fn rejects_enums() {
+        let input: DeriveInput = parse_quote! {
+            enum Shape { Circle }
+        };
+
+        let error = expand(input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "FieldNames can only be derived for structs"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_attributes() {
+        let input: DeriveInput = parse_quote! {
+            struct Point { #[field_names(rename = "y")] x: i32 }
+        };
+
+        let error = expand(input).unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("unsupported field_names attribute")
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! Derive macros generated by Nub.
+//!
+//! ```
+//! use derive_macro::FieldNames;
+//!
+//! #[derive(FieldNames)]
+//! struct User {
+//!     id: u64,
+//!     name: String,
+//!     #[field_names(skip)]
+//!     password: String,
+//! }
+//!
+//! assert_eq!(User::FIELD_NAMES, ["id", "name"]);
+//! ```
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+mod field_names;
+
+/// Adds a `FIELD_NAMES` constant listing the names of the fields of a struct.
+///
+/// Fields marked with `#[field_names(skip)]` are left out.
+#[proc_macro_derive(FieldNames, attributes(field_names))]
+pub fn derive_field_names(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    field_names::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
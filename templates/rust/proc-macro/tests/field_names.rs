@@ -0,0 +1,24 @@
+use derive_macro::FieldNames;
+
+#[allow(dead_code)]
+#[derive(FieldNames)]
+struct Empty {}
+
+#[allow(dead_code)]
+#[derive(FieldNames)]
+struct Wrapper<T: Clone> {
+    value: T,
+    #[field_names(skip)]
+    previous: Option<T>,
+    r#type: &'static str,
+}
+
+#[test]
+fn lists_fields_of_empty_structs() {
+    assert!(Empty::FIELD_NAMES.is_empty());
+}
+
+#[test]
+fn lists_fields_of_generic_structs() {
+    assert_eq!(Wrapper::<u8>::FIELD_NAMES, ["value", "type"]);
+}
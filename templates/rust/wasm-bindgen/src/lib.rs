@@ -0,0 +1,63 @@
+use wasm_bindgen::prelude::*;
+
+/// Returns a greeting, exported to JavaScript as `greet`.
+#[wasm_bindgen]
+pub fn greet(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+/// Counts the whitespace separated words of `text`.
+#[wasm_bindgen(js_name = wordCount)]
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// A counter living in WebAssembly memory, exported to JavaScript as a class.
+#[wasm_bindgen]
+#[derive(Debug, Default)]
+pub struct Counter {
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl Counter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn increment(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+// Native tests run with a plain `cargo test`, see `tests/web.rs` for tests running in WebAssembly.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greets_by_name() {
+        assert_eq!(greet("Ferris"), "Hello, Ferris!");
+    }
+
+    #[test]
+    fn counts_words() {
+        assert_eq!(word_count("  one two\tthree\n"), 3);
+        assert_eq!(word_count(""), 0);
+    }
+
+    #[test]
+    fn increments_the_counter() {
+        let mut counter = Counter::new();
+        counter.increment();
+        assert_eq!(counter.increment(), 2);
+        assert_eq!(counter.count(), 2);
+    }
+}
@@ -0,0 +1,22 @@
+//! Tests running inside a JavaScript runtime, with `wasm-pack test --node`.
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_lib::{Counter, greet, word_count};
+
+#[wasm_bindgen_test]
+fn greets_from_wasm() {
+    assert_eq!(greet("JavaScript"), "Hello, JavaScript!");
+}
+
+#[wasm_bindgen_test]
+fn counts_words_from_wasm() {
+    assert_eq!(word_count("hello wasm"), 2);
+}
+
+#[wasm_bindgen_test]
+fn keeps_counter_state() {
+    let mut counter = Counter::new();
+    counter.increment();
+    assert_eq!(counter.count(), 1);
+}
@@ -0,0 +1,65 @@
+use std::{fs, io, path::PathBuf, process::ExitCode};
+
+use app_core::TodoList;
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(version, about = "Manage a todo list stored in a JSON file")]
+struct Cli {
+    /// File the todo list is stored in
+    #[arg(short, long, default_value = "todos.json")]
+    file: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Add a todo
+    Add { title: String },
+    /// Mark a todo as done
+    Done { id: u64 },
+    /// List all todos
+    List,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+    let mut list = match fs::read_to_string(&cli.file) {
+        Ok(json) => TodoList::from_json(&json)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => TodoList::default(),
+        Err(err) => return Err(err.into()),
+    };
+
+    match cli.command {
+        Command::Add { title } => {
+            let todo = list.add(&title)?;
+            println!("Added #{} {}", todo.id, todo.title);
+        }
+        Command::Done { id } => {
+            let todo = list.complete(id)?;
+            println!("Completed #{} {}", todo.id, todo.title);
+        }
+        Command::List => {
+            for todo in list.todos() {
+                let mark = if todo.done { "x" } else { " " };
+                println!("[{mark}] #{} {}", todo.id, todo.title);
+            }
+            return Ok(());
+        }
+    }
+
+    fs::write(&cli.file, list.to_json()?)?;
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! Domain logic shared by the CLI and the server.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("No todo with id {0}")]
+    NotFound(u64),
+    #[error("A todo needs a title")]
+    EmptyTitle,
+    #[error("Invalid todo list: {0}")]
+    Malformed(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Todo {
+    pub id: u64,
+    pub title: String,
+    pub done: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TodoList {
+    todos: Vec<Todo>,
+}
+
+impl TodoList {
+    /// Parses a list saved with [`TodoList::to_json`].
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn add(&mut self, title: &str) -> Result<&Todo, Error> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(Error::EmptyTitle);
+        }
+
+        let id = self.todos.iter().map(|todo| todo.id).max().unwrap_or(0) + 1;
+        self.todos.push(Todo {
+            id,
+            title: title.to_string(),
+            done: false,
+        });
+        Ok(&self.todos[self.todos.len() - 1])
+    }
+
+    pub fn complete(&mut self, id: u64) -> Result<&Todo, Error> {
+        let todo = self
+            .todos
+            .iter_mut()
+            .find(|todo| todo.id == id)
+            .ok_or(Error::NotFound(id))?;
+        todo.done = true;
+        Ok(todo)
+    }
+
+    pub fn todos(&self) -> &[Todo] {
+        &self.todos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_todos_with_increasing_ids() {
+        let mut list = TodoList::default();
+        list.add("Write code").unwrap();
+
+        assert_eq!(list.add("Ship it").unwrap().id, 2);
+        assert_eq!(list.todos().len(), 2);
+    }
+
+    #[test]
+    fn rejects_empty_titles() {
+        let mut list = TodoList::default();
+
+        assert!(matches!(list.add("  "), Err(Error::EmptyTitle)));
+    }
+
+    #[test]
+    fn completes_todos() {
+        let mut list = TodoList::default();
+        let id = list.add("Write code").unwrap().id;
+
+        assert!(list.complete(id).unwrap().done);
+        assert!(matches!(list.complete(42), Err(Error::NotFound(42))));
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut list = TodoList::default();
+        list.add("Write code").unwrap();
+
+        let parsed = TodoList::from_json(&list.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed.todos(), list.todos());
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::{Arc, Mutex};
+
+use app_core::{Error, Todo, TodoList};
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use serde::Deserialize;
+
+type SharedList = Arc<Mutex<TodoList>>;
+
+#[derive(Deserialize)]
+struct NewTodo {
+    title: String,
+}
+
+/// Builds the application routes around an in-memory todo list.
+pub fn app() -> Router {
+    Router::new()
+        .route("/todos", get(list_todos).post(add_todo))
+        .route("/todos/{id}/done", post(complete_todo))
+        .with_state(SharedList::default())
+}
+
+async fn list_todos(State(list): State<SharedList>) -> Json<Vec<Todo>> {
+    Json(list.lock().unwrap().todos().to_vec())
+}
+
+async fn add_todo(State(list): State<SharedList>, Json(todo): Json<NewTodo>) -> Response {
+    match list.lock().unwrap().add(&todo.title) {
+        Ok(todo) => (StatusCode::CREATED, Json(todo.clone())).into_response(),
+        Err(err) => error_response(err),
+    }
+}
+
+async fn complete_todo(State(list): State<SharedList>, Path(id): Path<u64>) -> Response {
+    match list.lock().unwrap().complete(id) {
+        Ok(todo) => Json(todo.clone()).into_response(),
+        Err(err) => error_response(err),
+    }
+}
+
+fn error_response(err: Error) -> Response {
+    let status = match err {
+        Error::NotFound(_) => StatusCode::NOT_FOUND,
+        Error::EmptyTitle | Error::Malformed(_) => StatusCode::UNPROCESSABLE_ENTITY,
+    };
+    (status, err.to_string()).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn adds_a_todo() {
+        let request = Request::post("/todos")
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{"title":"Write code"}"#))
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let todo: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(todo["title"], "Write code");
+    }
+
+    #[tokio::test]
+    async fn reports_unknown_todos() {
+        let request = Request::post("/todos/42/done").body(Body::empty()).unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,99 @@
+import { describe, expect, test } from "bun:test";
+
+import { runAgent } from "./agent";
+import type { AssistantMessage, ChatProvider, Message } from "./provider";
+import { createHandler } from "./server";
+import type { Tool } from "./tools";
+
+/** Replays scripted replies so tests run without an API key. */
+class MockProvider implements ChatProvider {
+  readonly received: Message[][] = [];
+
+  constructor(private readonly replies: AssistantMessage[]) {}
+
+  async complete(messages: Message[]): Promise<AssistantMessage> {
+    this.received.push([...messages]);
+    const reply = this.replies.shift();
+    if (!reply) {
+      throw new Error("No more scripted replies");
+    }
+    return reply;
+  }
+}
+
+const echo: Tool = {
+  definition: {
+    type: "function",
+    function: { name: "echo", description: "Echoes its text", parameters: { type: "object" } },
+  },
+  async run(args) {
+    return `echo: ${args.text}`;
+  },
+};
+
+const toolCall = (name: string, args: object): AssistantMessage => ({
+  role: "assistant",
+  content: null,
+  tool_calls: [{ id: "call_1", type: "function", function: { name, arguments: JSON.stringify(args) } }],
+});
+
+describe("runAgent", () => {
+  test("answers directly when no tool is needed", async () => {
+    const provider = new MockProvider([{ role: "assistant", content: "Hello!" }]);
+
+    const result = await runAgent(provider, [echo], "Hi");
+
+    expect(result).toEqual({ output: "Hello!", toolCalls: [] });
+  });
+
+  test("sends tool results back to the model", async () => {
+    const provider = new MockProvider([
+      toolCall("echo", { text: "ping" }),
+      { role: "assistant", content: "The tool said ping" },
+    ]);
+
+    const result = await runAgent(provider, [echo], "Echo ping");
+
+    expect(result).toEqual({ output: "The tool said ping", toolCalls: ["echo"] });
+    expect(provider.received[1]?.at(-1)).toEqual({
+      role: "tool",
+      tool_call_id: "call_1",
+      content: "echo: ping",
+    });
+  });
+
+  test("stops after the maximum number of steps", async () => {
+    const provider = new MockProvider([toolCall("echo", {}), toolCall("echo", {})]);
+
+    await expect(runAgent(provider, [echo], "Loop", 2)).rejects.toThrow("within 2 steps");
+  });
+});
+
+describe("server", () => {
+  test("runs the agent on POST /agent", async () => {
+    const handler = createHandler(
+      new MockProvider([{ role: "assistant", content: "Done" }]),
+      [echo],
+    );
+
+    const response = await handler(
+      new Request("http://localhost/agent", {
+        method: "POST",
+        body: JSON.stringify({ input: "Do it" }),
+      }),
+    );
+
+    expect(response.status).toBe(200);
+    expect(await response.json()).toEqual({ output: "Done", toolCalls: [] });
+  });
+
+  test("rejects requests without input", async () => {
+    const handler = createHandler(new MockProvider([]), [echo]);
+
+    const response = await handler(
+      new Request("http://localhost/agent", { method: "POST", body: "{}" }),
+    );
+
+    expect(response.status).toBe(400);
+  });
+});
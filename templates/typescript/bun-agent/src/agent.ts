@@ -0,0 +1,58 @@
+import type { ChatProvider, Message } from "./provider";
+import type { Tool } from "./tools";
+
+export type AgentResult = {
+  output: string;
+  /** Names of the tools called, in order. */
+  toolCalls: string[];
+};
+
+const SYSTEM_PROMPT =
+  "You are a helpful assistant. Use the available tools when they help answer the request.";
+
+/**
+ * Runs the tool calling loop: the model either answers or asks for tools,
+ * whose results are sent back until it answers or `maxSteps` is reached.
+ */
+export async function runAgent(
+  provider: ChatProvider,
+  tools: Tool[],
+  input: string,
+  maxSteps = 5,
+): Promise<AgentResult> {
+  const messages: Message[] = [
+    { role: "system", content: SYSTEM_PROMPT },
+    { role: "user", content: input },
+  ];
+  const definitions = tools.map((tool) => tool.definition);
+  const toolCalls: string[] = [];
+
+  for (let step = 0; step < maxSteps; step++) {
+    const reply = await provider.complete(messages, definitions);
+    messages.push(reply);
+
+    if (!reply.tool_calls?.length) {
+      return { output: reply.content ?? "", toolCalls };
+    }
+
+    for (const call of reply.tool_calls) {
+      toolCalls.push(call.function.name);
+      const tool = tools.find((tool) => tool.definition.function.name === call.function.name);
+      const content = tool
+        ? await runTool(tool, call.function.arguments)
+        : `Error: unknown tool ${call.function.name}`;
+      messages.push({ role: "tool", tool_call_id: call.id, content });
+    }
+  }
+
+  throw new Error(`Agent did not answer within ${maxSteps} steps`);
+}
+
+async function runTool(tool: Tool, rawArguments: string): Promise<string> {
+  try {
+    const args = rawArguments ? (JSON.parse(rawArguments) as Record<string, unknown>) : {};
+    return await tool.run(args);
+  } catch (error) {
+    return `Error: ${error instanceof Error ? error.message : String(error)}`;
+  }
+}
@@ -0,0 +1,16 @@
+/** Settings read from the environment, see `.env.example`. Bun loads `.env` automatically. */
+export type Config = {
+  baseUrl: string;
+  apiKey?: string;
+  model: string;
+  port: number;
+};
+
+export function loadConfig(env: Record<string, string | undefined> = Bun.env): Config {
+  return {
+    baseUrl: (env.OPENAI_BASE_URL ?? "https://api.openai.com/v1").replace(/\/+$/, ""),
+    apiKey: env.OPENAI_API_KEY || undefined,
+    model: env.OPENAI_MODEL ?? "gpt-4o-mini",
+    port: Number(env.PORT ?? 3000),
+  };
+}
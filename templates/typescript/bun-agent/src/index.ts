@@ -0,0 +1,13 @@
+import { loadConfig } from "./config";
+import { OpenAIProvider } from "./provider";
+import { createHandler } from "./server";
+import { defaultTools } from "./tools";
+
+const config = loadConfig();
+
+const server = Bun.serve({
+  port: config.port,
+  fetch: createHandler(new OpenAIProvider(config), defaultTools),
+});
+
+console.log(`Server running at ${server.url}`);
@@ -0,0 +1,60 @@
+import type { Config } from "./config";
+
+export type ToolCall = {
+  id: string;
+  type: "function";
+  function: { name: string; arguments: string };
+};
+
+export type Message =
+  | { role: "system" | "user"; content: string }
+  | { role: "assistant"; content: string | null; tool_calls?: ToolCall[] }
+  | { role: "tool"; tool_call_id: string; content: string };
+
+export type AssistantMessage = Extract<Message, { role: "assistant" }>;
+
+export type ToolDefinition = {
+  type: "function";
+  function: {
+    name: string;
+    description: string;
+    parameters: Record<string, unknown>;
+  };
+};
+
+/** A chat model able to call tools, implemented by OpenAI and by mocks in tests. */
+export interface ChatProvider {
+  complete(messages: Message[], tools: ToolDefinition[]): Promise<AssistantMessage>;
+}
+
+/** Client for any server implementing the OpenAI chat completions API. */
+export class OpenAIProvider implements ChatProvider {
+  constructor(private readonly config: Config) {}
+
+  async complete(messages: Message[], tools: ToolDefinition[]): Promise<AssistantMessage> {
+    const headers: Record<string, string> = { "Content-Type": "application/json" };
+    if (this.config.apiKey) {
+      headers.Authorization = `Bearer ${this.config.apiKey}`;
+    }
+
+    const response = await fetch(`${this.config.baseUrl}/chat/completions`, {
+      method: "POST",
+      headers,
+      body: JSON.stringify({
+        model: this.config.model,
+        messages,
+        tools: tools.length > 0 ? tools : undefined,
+      }),
+    });
+    if (!response.ok) {
+      throw new Error(`Chat completion failed: ${response.status} ${await response.text()}`);
+    }
+
+    const body = (await response.json()) as { choices: { message: AssistantMessage }[] };
+    const choice = body.choices[0];
+    if (!choice) {
+      throw new Error("Chat completion has no choices");
+    }
+    return choice.message;
+  }
+}
@@ -0,0 +1,31 @@
+import { runAgent } from "./agent";
+import type { ChatProvider } from "./provider";
+import type { Tool } from "./tools";
+
+/** Builds the HTTP handler, kept separate from `Bun.serve` so tests can call it directly. */
+export function createHandler(provider: ChatProvider, tools: Tool[]) {
+  return async (request: Request): Promise<Response> => {
+    const { pathname } = new URL(request.url);
+
+    if (request.method === "GET" && pathname === "/health") {
+      return new Response("ok");
+    }
+
+    if (request.method === "POST" && pathname === "/agent") {
+      const body = (await request.json().catch(() => null)) as { input?: unknown } | null;
+      if (typeof body?.input !== "string" || body.input === "") {
+        return Response.json({ error: "expected a JSON body with an input" }, { status: 400 });
+      }
+
+      try {
+        return Response.json(await runAgent(provider, tools, body.input));
+      } catch (error) {
+        console.error(error);
+        const message = error instanceof Error ? error.message : String(error);
+        return Response.json({ error: message }, { status: 502 });
+      }
+    }
+
+    return new Response("Not found", { status: 404 });
+  };
+}
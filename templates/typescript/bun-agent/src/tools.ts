@@ -0,0 +1,49 @@
+import type { ToolDefinition } from "./provider";
+
+export type Tool = {
+  definition: ToolDefinition;
+  run(args: Record<string, unknown>): Promise<string>;
+};
+
+/** Maximum number of characters of a fetched page returned to the model. */
+const MAX_PAGE_LENGTH = 4000;
+
+export const currentTime: Tool = {
+  definition: {
+    type: "function",
+    function: {
+      name: "current_time",
+      description: "Returns the current date and time in ISO 8601 format, in UTC.",
+      parameters: { type: "object", properties: {} },
+    },
+  },
+  async run() {
+    return new Date().toISOString();
+  },
+};
+
+export const httpGet: Tool = {
+  definition: {
+    type: "function",
+    function: {
+      name: "http_get",
+      description: "Fetches a web page and returns the beginning of its text content.",
+      parameters: {
+        type: "object",
+        properties: { url: { type: "string", description: "The URL to fetch" } },
+        required: ["url"],
+      },
+    },
+  },
+  async run(args) {
+    const url = String(args.url ?? "");
+    if (!/^https?:\/\//.test(url)) {
+      return "Error: only http and https URLs are supported";
+    }
+    const response = await fetch(url);
+    const text = await response.text();
+    return `${response.status}\n${text.slice(0, MAX_PAGE_LENGTH)}`;
+  },
+};
+
+export const defaultTools: Tool[] = [currentTime, httpGet];
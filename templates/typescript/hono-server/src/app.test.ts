@@ -0,0 +1,29 @@
+import { describe, expect, test } from "bun:test";
+
+import { app } from "./app";
+
+describe("app", () => {
+  test("GET / greets", async () => {
+    const response = await app.request("/");
+
+    expect(response.status).toBe(200);
+    expect(await response.text()).toBe("Hello, World!");
+  });
+
+  test("POST /users creates a user", async () => {
+    const response = await app.request("/users", {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ username: "nub" }),
+    });
+
+    expect(response.status).toBe(201);
+    expect(await response.json()).toEqual({ id: 1337, username: "nub" });
+  });
+
+  test("POST /users requires a username", async () => {
+    const response = await app.request("/users", { method: "POST", body: "{}" });
+
+    expect(response.status).toBe(400);
+  });
+});
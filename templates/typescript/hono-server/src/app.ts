@@ -0,0 +1,23 @@
+import { Hono } from "hono";
+import { logger } from "hono/logger";
+
+type User = {
+  id: number;
+  username: string;
+};
+
+export const app = new Hono();
+
+app.use(logger());
+
+app.get("/", (c) => c.text("Hello, World!"));
+
+app.post("/users", async (c) => {
+  const body = await c.req.json<{ username?: unknown }>().catch(() => ({}));
+  if (typeof body.username !== "string" || body.username === "") {
+    return c.json({ error: "username is required" }, 400);
+  }
+
+  const user: User = { id: 1337, username: body.username };
+  return c.json(user, 201);
+});
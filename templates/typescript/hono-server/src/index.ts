@@ -0,0 +1,8 @@
+import { app } from "./app";
+
+const server = Bun.serve({
+  port: Number(Bun.env.PORT ?? 3000),
+  fetch: app.fetch,
+});
+
+console.log(`Server running at ${server.url}`);
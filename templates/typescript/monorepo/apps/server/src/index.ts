@@ -0,0 +1,22 @@
+import { TodoList } from "@repo/core";
+
+const todos = new TodoList();
+
+const server = Bun.serve({
+  port: Number(Bun.env.PORT ?? 3000),
+  routes: {
+    "/todos": {
+      GET: () => Response.json(todos.all()),
+      POST: async (request) => {
+        const body = (await request.json().catch(() => null)) as { title?: unknown } | null;
+        if (typeof body?.title !== "string" || body.title === "") {
+          return Response.json({ error: "title is required" }, { status: 400 });
+        }
+        return Response.json(todos.add(body.title), { status: 201 });
+      },
+    },
+  },
+  fetch: () => new Response("Not found", { status: 404 }),
+});
+
+console.log(`Server running at ${server.url}`);
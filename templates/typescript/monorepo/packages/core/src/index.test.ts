@@ -0,0 +1,21 @@
+import { describe, expect, test } from "bun:test";
+
+import { TodoList } from "./index";
+
+describe("TodoList", () => {
+  test("adds todos with increasing ids", () => {
+    const list = new TodoList();
+
+    expect(list.add("Write code").id).toBe(1);
+    expect(list.add("Ship it").id).toBe(2);
+    expect(list.all()).toHaveLength(2);
+  });
+
+  test("completes a todo", () => {
+    const list = new TodoList();
+    const todo = list.add("Write code");
+
+    expect(list.complete(todo.id)?.done).toBe(true);
+    expect(list.complete(42)).toBeUndefined();
+  });
+});
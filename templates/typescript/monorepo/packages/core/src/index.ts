@@ -0,0 +1,29 @@
+export type Todo = {
+  id: number;
+  title: string;
+  done: boolean;
+};
+
+/** In-memory todo list shared by the apps of the workspace. */
+export class TodoList {
+  private todos: Todo[] = [];
+  private nextId = 1;
+
+  add(title: string): Todo {
+    const todo = { id: this.nextId++, title, done: false };
+    this.todos.push(todo);
+    return todo;
+  }
+
+  complete(id: number): Todo | undefined {
+    const todo = this.todos.find((todo) => todo.id === id);
+    if (todo) {
+      todo.done = true;
+    }
+    return todo;
+  }
+
+  all(): Todo[] {
+    return [...this.todos];
+  }
+}
@@ -0,0 +1,10 @@
+import { run } from "./commands";
+
+const output = run(process.argv.slice(2));
+for (const line of output.stdout) {
+  console.log(line);
+}
+for (const line of output.stderr) {
+  console.error(line);
+}
+process.exitCode = output.code;
@@ -0,0 +1,41 @@
+import { describe, expect, test } from "vitest";
+
+import { greet, run } from "./commands";
+
+describe("greet", () => {
+  test("greets by name", () => {
+    expect(greet("World")).toBe("Hello, World!");
+  });
+
+  test("shouts", () => {
+    expect(greet("World", { shout: true })).toBe("HELLO, WORLD!");
+  });
+});
+
+describe("run", () => {
+  test("runs the greet command", () => {
+    expect(run(["greet", "Nub", "--shout"])).toEqual({
+      code: 0,
+      stdout: ["HELLO, NUB!"],
+      stderr: [],
+    });
+  });
+
+  test("prints usage without a command", () => {
+    const output = run([]);
+
+    expect(output.code).toBe(0);
+    expect(output.stdout[0]).toContain("Usage:");
+  });
+
+  test("fails on unknown commands", () => {
+    const output = run(["unknown"]);
+
+    expect(output.code).toBe(2);
+    expect(output.stderr[0]).toBe("Unknown command: unknown");
+  });
+
+  test("fails on unknown options", () => {
+    expect(run(["greet", "Nub", "--loud"]).code).toBe(2);
+  });
+});
@@ -0,0 +1,69 @@
+import { parseArgs } from "node:util";
+
+export type Output = {
+  code: number;
+  stdout: string[];
+  stderr: string[];
+};
+
+const USAGE = `Usage: <command> [options]
+
+Commands:
+  greet <name>   Print a greeting
+    --shout      Print it in uppercase
+
+Options:
+  -h, --help     Print this help`;
+
+/** Runs the CLI with the given arguments, returning its output instead of printing it. */
+export function run(args: string[]): Output {
+  const output: Output = { code: 0, stdout: [], stderr: [] };
+
+  let parsed: ReturnType<typeof parse>;
+  try {
+    parsed = parse(args);
+  } catch (error) {
+    output.code = 2;
+    output.stderr.push(error instanceof Error ? error.message : String(error), "", USAGE);
+    return output;
+  }
+
+  const [command, ...rest] = parsed.positionals;
+  if (parsed.values.help || command === undefined) {
+    output.stdout.push(USAGE);
+    return output;
+  }
+
+  switch (command) {
+    case "greet": {
+      const name = rest[0];
+      if (!name) {
+        output.code = 2;
+        output.stderr.push("greet: missing <name>");
+        break;
+      }
+      output.stdout.push(greet(name, { shout: parsed.values.shout }));
+      break;
+    }
+    default:
+      output.code = 2;
+      output.stderr.push(`Unknown command: ${command}`, "", USAGE);
+  }
+  return output;
+}
+
+export function greet(name: string, options: { shout?: boolean } = {}): string {
+  const greeting = `Hello, ${name}!`;
+  return options.shout ? greeting.toUpperCase() : greeting;
+}
+
+function parse(args: string[]) {
+  return parseArgs({
+    args,
+    allowPositionals: true,
+    options: {
+      help: { type: "boolean", short: "h" },
+      shout: { type: "boolean" },
+    },
+  });
+}
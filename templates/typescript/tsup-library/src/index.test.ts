@@ -0,0 +1,17 @@
+import { describe, expect, test } from "vitest";
+
+import { slugify } from "./index";
+
+describe("slugify", () => {
+  test("lowercases and joins words", () => {
+    expect(slugify("Hello, World!")).toBe("hello-world");
+  });
+
+  test("removes accents", () => {
+    expect(slugify("Crème brûlée")).toBe("creme-brulee");
+  });
+
+  test("uses a custom separator", () => {
+    expect(slugify("Hello World", { separator: "_" })).toBe("hello_world");
+  });
+});
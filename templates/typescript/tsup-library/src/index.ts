@@ -0,0 +1,24 @@
+export type SlugifyOptions = {
+  /** Character placed between words, `-` by default. */
+  separator?: string;
+};
+
+/**
+ * Turns text into a URL friendly slug.
+ *
+ * @example
+ * slugify("Hello, World!"); // "hello-world"
+ */
+export function slugify(text: string, options: SlugifyOptions = {}): string {
+  const separator = options.separator ?? "-";
+  return text
+    .normalize("NFKD")
+    .replace(/\p{Diacritic}/gu, "")
+    .toLowerCase()
+    .replace(/[^a-z0-9]+/g, separator)
+    .replace(new RegExp(`^${escapeRegExp(separator)}+|${escapeRegExp(separator)}+$`, "g"), "");
+}
+
+function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
+}
@@ -0,0 +1,14 @@
+const std = @import("std");
+
+pub fn main() !void {
+    const stdout = std.io.getStdOut().writer();
+    try stdout.print("{s}\n", .{greet()});
+}
+
+fn greet() []const u8 {
+    return "Hello World!";
+}
+
+test "greet" {
+    try std.testing.expectEqualStrings("Hello World!", greet());
+}